@@ -1,12 +1,56 @@
 pub use sea_orm_migration::prelude::*;
 
 mod m20241102_035205_create_tables;
+mod m20241103_000001_create_tags;
+mod m20241104_000001_add_post_status;
+mod m20241104_000002_create_search_index;
+mod m20241105_000001_create_media;
+mod m20241106_000001_add_post_body_format;
+mod m20241107_000001_add_soft_delete;
+mod m20241108_000001_add_timestamps;
+mod m20241109_000001_add_versions;
+mod m20241110_000001_create_post_view_stats;
+mod m20241111_000001_create_reactions;
+mod m20241112_000001_create_bookmarks;
+mod m20241113_000001_create_series;
+mod m20241114_000001_add_post_reading_stats;
+mod m20241115_000001_add_post_visibility;
+mod m20241116_000001_create_featured_posts;
+mod m20241117_000001_add_post_metadata;
+mod m20241118_000001_add_post_locale;
+mod m20241119_000001_create_post_rankings;
+mod m20241120_000001_create_post_transitions;
+mod m20241121_000001_create_post_locks;
+mod m20241122_000001_add_user_version;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20241102_035205_create_tables::Migration)]
+        vec![
+            Box::new(m20241102_035205_create_tables::Migration),
+            Box::new(m20241103_000001_create_tags::Migration),
+            Box::new(m20241104_000001_add_post_status::Migration),
+            Box::new(m20241104_000002_create_search_index::Migration),
+            Box::new(m20241105_000001_create_media::Migration),
+            Box::new(m20241106_000001_add_post_body_format::Migration),
+            Box::new(m20241107_000001_add_soft_delete::Migration),
+            Box::new(m20241108_000001_add_timestamps::Migration),
+            Box::new(m20241109_000001_add_versions::Migration),
+            Box::new(m20241110_000001_create_post_view_stats::Migration),
+            Box::new(m20241111_000001_create_reactions::Migration),
+            Box::new(m20241112_000001_create_bookmarks::Migration),
+            Box::new(m20241113_000001_create_series::Migration),
+            Box::new(m20241114_000001_add_post_reading_stats::Migration),
+            Box::new(m20241115_000001_add_post_visibility::Migration),
+            Box::new(m20241116_000001_create_featured_posts::Migration),
+            Box::new(m20241117_000001_add_post_metadata::Migration),
+            Box::new(m20241118_000001_add_post_locale::Migration),
+            Box::new(m20241119_000001_create_post_rankings::Migration),
+            Box::new(m20241120_000001_create_post_transitions::Migration),
+            Box::new(m20241121_000001_create_post_locks::Migration),
+            Box::new(m20241122_000001_add_user_version::Migration),
+        ]
     }
 }
@@ -0,0 +1,117 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in [Posts::Table.into_iden(), Comments::Table.into_iden(), Categories::Table.into_iden()] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table.clone())
+                        .add_column(
+                            ColumnDef::new(SoftDelete::DeletedAt)
+                                .timestamp_with_time_zone()
+                                .null(),
+                        )
+                        .add_column(ColumnDef::new(SoftDelete::DeletedBy).integer().null())
+                        .to_owned(),
+                )
+                .await?;
+
+            manager
+                .create_index(
+                    Index::create()
+                        .name(format!("idx-{}-deleted_at", table.to_string()))
+                        .table(table)
+                        .col(SoftDelete::DeletedAt)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        // Removing a category must never wipe its posts, the purge job skips categories still in use
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name("fk-post-category_id")
+                    .table(Posts::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk-post-category_id")
+                    .from(Posts::Table, Posts::CategoryId)
+                    .to(Categories::Table, Categories::Id)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Restrict)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name("fk-post-category_id")
+                    .table(Posts::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk-post-category_id")
+                    .from(Posts::Table, Posts::CategoryId)
+                    .to(Categories::Table, Categories::Id)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await?;
+
+        for table in [Posts::Table.into_iden(), Comments::Table.into_iden(), Categories::Table.into_iden()] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(SoftDelete::DeletedAt)
+                        .drop_column(SoftDelete::DeletedBy)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum SoftDelete {
+    DeletedAt,
+    DeletedBy,
+}
+
+#[derive(Iden)]
+enum Posts {
+    Table,
+    CategoryId,
+}
+
+#[derive(Iden)]
+enum Comments {
+    Table,
+}
+
+#[derive(Iden)]
+enum Categories {
+    Table,
+    Id,
+}
@@ -0,0 +1,93 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in [
+            Users::Table.into_iden(),
+            Categories::Table.into_iden(),
+            Posts::Table.into_iden(),
+            Comments::Table.into_iden(),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .add_column(
+                            ColumnDef::new(Timestamps::CreatedAt)
+                                .timestamp_with_time_zone()
+                                .not_null()
+                                .default(Expr::current_timestamp()),
+                        )
+                        .add_column(
+                            ColumnDef::new(Timestamps::UpdatedAt)
+                                .timestamp_with_time_zone()
+                                .not_null()
+                                .default(Expr::current_timestamp()),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-posts-created_at")
+                    .table(Posts::Table)
+                    .col(Timestamps::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in [
+            Users::Table.into_iden(),
+            Categories::Table.into_iden(),
+            Posts::Table.into_iden(),
+            Comments::Table.into_iden(),
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column(Timestamps::CreatedAt)
+                        .drop_column(Timestamps::UpdatedAt)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Timestamps {
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+}
+
+#[derive(Iden)]
+enum Categories {
+    Table,
+}
+
+#[derive(Iden)]
+enum Posts {
+    Table,
+}
+
+#[derive(Iden)]
+enum Comments {
+    Table,
+}
@@ -0,0 +1,72 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create post_view_stats table, one row per post and day
+        manager
+            .create_table(
+                Table::create()
+                    .table(PostViewStats::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(PostViewStats::PostId).integer().not_null())
+                    .col(ColumnDef::new(PostViewStats::Day).date().not_null())
+                    .col(
+                        ColumnDef::new(PostViewStats::Views)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(PostViewStats::PostId)
+                            .col(PostViewStats::Day),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-post_view_stats-post_id")
+                            .from(PostViewStats::Table, PostViewStats::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-post_view_stats-day")
+                    .table(PostViewStats::Table)
+                    .col(PostViewStats::Day)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PostViewStats::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum PostViewStats {
+    Table,
+    PostId,
+    Day,
+    Views,
+}
+
+#[derive(Iden)]
+enum Posts {
+    Table,
+    Id,
+}
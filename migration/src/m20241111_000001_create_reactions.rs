@@ -0,0 +1,169 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create post_reactions table
+        manager
+            .create_table(
+                Table::create()
+                    .table(PostReactions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PostReactions::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(PostReactions::PostId).integer().not_null())
+                    .col(ColumnDef::new(PostReactions::UserId).integer().not_null())
+                    .col(ColumnDef::new(PostReactions::Kind).string_len(32).not_null())
+                    .col(
+                        ColumnDef::new(PostReactions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-post_reactions-post_id")
+                            .from(PostReactions::Table, PostReactions::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-post_reactions-user_id")
+                            .from(PostReactions::Table, PostReactions::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-post_reactions-post_id-user_id-kind")
+                    .table(PostReactions::Table)
+                    .col(PostReactions::PostId)
+                    .col(PostReactions::UserId)
+                    .col(PostReactions::Kind)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create comment_reactions table
+        manager
+            .create_table(
+                Table::create()
+                    .table(CommentReactions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CommentReactions::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(CommentReactions::CommentId).integer().not_null())
+                    .col(ColumnDef::new(CommentReactions::UserId).integer().not_null())
+                    .col(ColumnDef::new(CommentReactions::Kind).string_len(32).not_null())
+                    .col(
+                        ColumnDef::new(CommentReactions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-comment_reactions-comment_id")
+                            .from(CommentReactions::Table, CommentReactions::CommentId)
+                            .to(Comments::Table, Comments::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-comment_reactions-user_id")
+                            .from(CommentReactions::Table, CommentReactions::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-comment_reactions-comment_id-user_id-kind")
+                    .table(CommentReactions::Table)
+                    .col(CommentReactions::CommentId)
+                    .col(CommentReactions::UserId)
+                    .col(CommentReactions::Kind)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CommentReactions::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(PostReactions::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum PostReactions {
+    Table,
+    Id,
+    PostId,
+    UserId,
+    Kind,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum CommentReactions {
+    Table,
+    Id,
+    CommentId,
+    UserId,
+    Kind,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum Posts {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Comments {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
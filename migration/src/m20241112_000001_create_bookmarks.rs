@@ -0,0 +1,166 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create reading_lists table
+        manager
+            .create_table(
+                Table::create()
+                    .table(ReadingLists::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ReadingLists::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(ReadingLists::UserId).integer().not_null())
+                    .col(ColumnDef::new(ReadingLists::Name).string().not_null())
+                    .col(ColumnDef::new(ReadingLists::ShareToken).string().unique_key().null())
+                    .col(
+                        ColumnDef::new(ReadingLists::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(ReadingLists::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-reading_lists-user_id")
+                            .from(ReadingLists::Table, ReadingLists::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create bookmarks table
+        manager
+            .create_table(
+                Table::create()
+                    .table(Bookmarks::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Bookmarks::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Bookmarks::UserId).integer().not_null())
+                    .col(ColumnDef::new(Bookmarks::PostId).integer().not_null())
+                    .col(ColumnDef::new(Bookmarks::ReadingListId).integer().null())
+                    .col(
+                        ColumnDef::new(Bookmarks::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-bookmarks-user_id")
+                            .from(Bookmarks::Table, Bookmarks::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-bookmarks-post_id")
+                            .from(Bookmarks::Table, Bookmarks::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-bookmarks-reading_list_id")
+                            .from(Bookmarks::Table, Bookmarks::ReadingListId)
+                            .to(ReadingLists::Table, ReadingLists::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-bookmarks-user_id-post_id")
+                    .table(Bookmarks::Table)
+                    .col(Bookmarks::UserId)
+                    .col(Bookmarks::PostId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-bookmarks-reading_list_id")
+                    .table(Bookmarks::Table)
+                    .col(Bookmarks::ReadingListId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Bookmarks::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(ReadingLists::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum ReadingLists {
+    Table,
+    Id,
+    UserId,
+    Name,
+    ShareToken,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum Bookmarks {
+    Table,
+    Id,
+    UserId,
+    PostId,
+    ReadingListId,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum Posts {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
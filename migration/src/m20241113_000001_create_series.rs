@@ -0,0 +1,131 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create series table
+        manager
+            .create_table(
+                Table::create()
+                    .table(Series::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Series::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Series::Title).string().not_null())
+                    .col(ColumnDef::new(Series::Description).text().not_null().default(""))
+                    .col(ColumnDef::new(Series::UserId).integer().not_null())
+                    .col(
+                        ColumnDef::new(Series::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(Series::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-series-user_id")
+                            .from(Series::Table, Series::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Create series_posts table, a post belongs to at most one series
+        manager
+            .create_table(
+                Table::create()
+                    .table(SeriesPosts::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(SeriesPosts::SeriesId).integer().not_null())
+                    .col(
+                        ColumnDef::new(SeriesPosts::PostId)
+                            .integer()
+                            .unique_key()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SeriesPosts::Position).integer().not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(SeriesPosts::SeriesId)
+                            .col(SeriesPosts::PostId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-series_posts-series_id")
+                            .from(SeriesPosts::Table, SeriesPosts::SeriesId)
+                            .to(Series::Table, Series::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-series_posts-post_id")
+                            .from(SeriesPosts::Table, SeriesPosts::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SeriesPosts::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Series::Table).to_owned())
+            .await?;
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Series {
+    Table,
+    Id,
+    Title,
+    Description,
+    UserId,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum SeriesPosts {
+    Table,
+    SeriesId,
+    PostId,
+    Position,
+}
+
+#[derive(Iden)]
+enum Posts {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
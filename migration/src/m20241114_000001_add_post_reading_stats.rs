@@ -0,0 +1,73 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .add_column(ColumnDef::new(Posts::Excerpt).text().not_null().default(""))
+                    .add_column(
+                        ColumnDef::new(Posts::WordCount)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .add_column(
+                        ColumnDef::new(Posts::ReadingTimeMinutes)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Approximate the stats of existing posts from their rendered HTML until the next edit
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"WITH plain AS (
+                    SELECT id, btrim(regexp_replace(regexp_replace(body_html, '<[^>]*>', ' ', 'g'), '\s+', ' ', 'g')) AS text
+                    FROM posts
+                ), counted AS (
+                    SELECT id, text, COALESCE(array_length(regexp_split_to_array(NULLIF(text, ''), ' '), 1), 0) AS words
+                    FROM plain
+                )
+                UPDATE posts SET
+                    excerpt = left(counted.text, 280),
+                    word_count = counted.words,
+                    reading_time_minutes = CEIL(counted.words / 200.0)::integer
+                FROM counted
+                WHERE posts.id = counted.id"#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .drop_column(Posts::ReadingTimeMinutes)
+                    .drop_column(Posts::WordCount)
+                    .drop_column(Posts::Excerpt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Posts {
+    Table,
+    Excerpt,
+    WordCount,
+    ReadingTimeMinutes,
+}
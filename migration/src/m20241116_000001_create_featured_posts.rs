@@ -0,0 +1,104 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Create featured_posts table, ordered by position and optionally expiring
+        manager
+            .create_table(
+                Table::create()
+                    .table(FeaturedPosts::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(FeaturedPosts::PostId)
+                            .integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(FeaturedPosts::Position).integer().not_null().default(0))
+                    .col(ColumnDef::new(FeaturedPosts::ExpiresAt).timestamp_with_time_zone().null())
+                    .col(
+                        ColumnDef::new(FeaturedPosts::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-featured_posts-post_id")
+                            .from(FeaturedPosts::Table, FeaturedPosts::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-featured_posts-position")
+                    .table(FeaturedPosts::Table)
+                    .col(FeaturedPosts::Position)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Pinned posts go to the top of their category
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .add_column(ColumnDef::new(Posts::PinnedAt).timestamp_with_time_zone().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-posts-category_id-pinned_at")
+                    .table(Posts::Table)
+                    .col(Posts::CategoryId)
+                    .col(Posts::PinnedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .drop_column(Posts::PinnedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(FeaturedPosts::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum FeaturedPosts {
+    Table,
+    PostId,
+    Position,
+    ExpiresAt,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum Posts {
+    Table,
+    Id,
+    CategoryId,
+    PinnedAt,
+}
@@ -0,0 +1,71 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .add_column(
+                        ColumnDef::new(Posts::Locale)
+                            .string_len(35)
+                            .not_null()
+                            .default("en"),
+                    )
+                    // ID of the post the group was started from, shared by all its translations
+                    .add_column(ColumnDef::new(Posts::TranslationGroupId).integer().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-posts-locale")
+                    .table(Posts::Table)
+                    .col(Posts::Locale)
+                    .to_owned(),
+            )
+            .await?;
+
+        // A group holds at most one live post per locale
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"CREATE UNIQUE INDEX "idx-posts-translation_group_id-locale"
+                ON posts (translation_group_id, locale)
+                WHERE translation_group_id IS NOT NULL AND deleted_at IS NULL"#,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(r#"DROP INDEX IF EXISTS "idx-posts-translation_group_id-locale""#)
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Posts::Table)
+                    .drop_column(Posts::TranslationGroupId)
+                    .drop_column(Posts::Locale)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Posts {
+    Table,
+    Locale,
+    TranslationGroupId,
+}
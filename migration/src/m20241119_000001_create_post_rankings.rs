@@ -0,0 +1,123 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Trending scores, rebuilt by the ranking job
+        manager
+            .create_table(
+                Table::create()
+                    .table(TrendingPosts::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(TrendingPosts::PostId)
+                            .integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(TrendingPosts::Score).double().not_null())
+                    .col(
+                        ColumnDef::new(TrendingPosts::ComputedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-trending_posts-post_id")
+                            .from(TrendingPosts::Table, TrendingPosts::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-trending_posts-score")
+                    .table(TrendingPosts::Table)
+                    .col(TrendingPosts::Score)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Most related posts of each post, rebuilt by the ranking job
+        manager
+            .create_table(
+                Table::create()
+                    .table(RelatedPosts::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(RelatedPosts::PostId).integer().not_null())
+                    .col(ColumnDef::new(RelatedPosts::RelatedPostId).integer().not_null())
+                    .col(ColumnDef::new(RelatedPosts::Score).double().not_null())
+                    .col(
+                        ColumnDef::new(RelatedPosts::ComputedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(RelatedPosts::PostId)
+                            .col(RelatedPosts::RelatedPostId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-related_posts-post_id")
+                            .from(RelatedPosts::Table, RelatedPosts::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-related_posts-related_post_id")
+                            .from(RelatedPosts::Table, RelatedPosts::RelatedPostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RelatedPosts::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(TrendingPosts::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum TrendingPosts {
+    Table,
+    PostId,
+    Score,
+    ComputedAt,
+}
+
+#[derive(Iden)]
+enum RelatedPosts {
+    Table,
+    PostId,
+    RelatedPostId,
+    Score,
+    ComputedAt,
+}
+
+#[derive(Iden)]
+enum Posts {
+    Table,
+    Id,
+}
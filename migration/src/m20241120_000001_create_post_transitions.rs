@@ -0,0 +1,101 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Status changes of posts, with the user who made them
+        manager
+            .create_table(
+                Table::create()
+                    .table(PostTransitions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PostTransitions::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(PostTransitions::PostId).integer().not_null())
+                    .col(ColumnDef::new(PostTransitions::FromStatus).string_len(20).not_null())
+                    .col(ColumnDef::new(PostTransitions::ToStatus).string_len(20).not_null())
+                    .col(ColumnDef::new(PostTransitions::ActorId).integer().null())
+                    .col(ColumnDef::new(PostTransitions::Note).text().null())
+                    .col(
+                        ColumnDef::new(PostTransitions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-post_transitions-post_id")
+                            .from(PostTransitions::Table, PostTransitions::PostId)
+                            .to(Posts::Table, Posts::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-post_transitions-actor_id")
+                            .from(PostTransitions::Table, PostTransitions::ActorId)
+                            .to(Users::Table, Users::Id)
+                            .on_update(ForeignKeyAction::Cascade)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-post_transitions-post_id")
+                    .table(PostTransitions::Table)
+                    .col(PostTransitions::PostId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PostTransitions::Table).to_owned())
+            .await?;
+
+        // Pending posts fall back to drafts
+        manager
+            .get_connection()
+            .execute_unprepared("UPDATE posts SET status = 'draft' WHERE status = 'pending_review'")
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum PostTransitions {
+    Table,
+    Id,
+    PostId,
+    FromStatus,
+    ToStatus,
+    ActorId,
+    Note,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum Posts {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::DbErr;
+
+use crate::{
+    domain::{
+        ApiResponse, BookmarkQuery, BookmarkResponse, CreateBookmarkRequest, ErrorResponse,
+        PaginatedResponse, Pagination, ReadingListRequest, ReadingListResponse,
+        SharedReadingListResponse, VisibilityScope,
+    },
+    entities::{bookmarks, posts, reading_lists},
+};
+
+
+pub type DynBookmarkRepository = Arc<dyn BookmarkRepositoryTrait + Send + Sync>;
+pub type DynBookmarkService = Arc<dyn BookmarkServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait BookmarkRepositoryTrait {
+    /// Bookmarks the post, or moves an existing bookmark to `reading_list_id`.
+    async fn add_bookmark(&self, user_id: i32, post_id: i32, reading_list_id: Option<i32>) -> Result<(bookmarks::Model, posts::Model), DbErr>;
+    async fn remove_bookmark(&self, user_id: i32, post_id: i32) -> Result<bool, DbErr>;
+    async fn find_bookmarks(
+        &self,
+        user_id: i32,
+        reading_list_id: Option<i32>,
+        scope: &VisibilityScope,
+        pagination: &Pagination,
+    ) -> Result<(Vec<(bookmarks::Model, posts::Model)>, u64), DbErr>;
+    /// The subset of `post_ids` the user has bookmarked.
+    async fn find_bookmarked(&self, user_id: i32, post_ids: &[i32]) -> Result<Vec<i32>, DbErr>;
+    async fn create_reading_list(&self, user_id: i32, name: &str) -> Result<reading_lists::Model, DbErr>;
+    /// Reading lists of the user with their bookmark counts.
+    async fn find_reading_lists(&self, user_id: i32) -> Result<Vec<(reading_lists::Model, i64)>, DbErr>;
+    async fn find_reading_list(&self, id: i32, user_id: i32) -> Result<Option<(reading_lists::Model, i64)>, DbErr>;
+    async fn find_reading_list_by_token(&self, token: &str) -> Result<Option<reading_lists::Model>, DbErr>;
+    async fn update_reading_list(&self, id: i32, user_id: i32, name: &str) -> Result<bool, DbErr>;
+    async fn set_share_token(&self, id: i32, user_id: i32, token: Option<String>) -> Result<bool, DbErr>;
+    async fn delete_reading_list(&self, id: i32, user_id: i32) -> Result<bool, DbErr>;
+    /// Published posts in a reading list, for its public link.
+    async fn find_reading_list_posts(&self, id: i32, scope: &VisibilityScope, pagination: &Pagination) -> Result<(Vec<posts::Model>, u64), DbErr>;
+}
+
+#[async_trait]
+pub trait BookmarkServiceTrait {
+    async fn add_bookmark(&self, user_id: i32, post_id: i32, input: &CreateBookmarkRequest, token: Option<&str>) -> Result<ApiResponse<BookmarkResponse>, ErrorResponse>;
+    async fn remove_bookmark(&self, user_id: i32, post_id: i32) -> Result<Option<ApiResponse<()>>, ErrorResponse>;
+    async fn get_bookmarks(
+        &self,
+        user_id: i32,
+        query: &BookmarkQuery,
+        pagination: &Pagination,
+    ) -> Result<ApiResponse<PaginatedResponse<BookmarkResponse>>, ErrorResponse>;
+    async fn create_reading_list(&self, user_id: i32, input: &ReadingListRequest) -> Result<ApiResponse<ReadingListResponse>, ErrorResponse>;
+    async fn get_reading_lists(&self, user_id: i32) -> Result<ApiResponse<Vec<ReadingListResponse>>, ErrorResponse>;
+    async fn update_reading_list(&self, id: i32, user_id: i32, input: &ReadingListRequest) -> Result<Option<ApiResponse<ReadingListResponse>>, ErrorResponse>;
+    async fn delete_reading_list(&self, id: i32, user_id: i32) -> Result<Option<ApiResponse<()>>, ErrorResponse>;
+    /// Creates a share token for the list, or revokes it when `shared` is false.
+    async fn share_reading_list(&self, id: i32, user_id: i32, shared: bool) -> Result<Option<ApiResponse<ReadingListResponse>>, ErrorResponse>;
+    async fn get_shared_reading_list(
+        &self,
+        token: &str,
+        pagination: &Pagination,
+        viewer: Option<i32>,
+    ) -> Result<Option<ApiResponse<SharedReadingListResponse>>, ErrorResponse>;
+}
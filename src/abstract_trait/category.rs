@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use sea_orm::DbErr;
 use async_trait::async_trait;
+use serde_json::Value;
 
 use crate::{domain::{ApiResponse, CategoryResponse, CreateCategoryRequest, ErrorResponse, UpdateCategoryRequest}, entities::categories};
 
@@ -17,7 +18,8 @@ pub trait CategoryRepositoryTrait {
     async fn find_by_id(&self, id: i32) -> Result<Option<categories::Model>, DbErr>;
     async fn create(&self, input: &CreateCategoryRequest) -> Result<categories::Model, DbErr>;
     async fn update(&self, input: &UpdateCategoryRequest) -> Result<categories::Model, DbErr>;
-    async fn delete(&self, id: i32) -> Result<(), DbErr>;
+    async fn set_metadata_schema(&self, id: i32, schema: Option<&Value>, expected_version: Option<i32>) -> Result<categories::Model, DbErr>;
+    async fn delete(&self, id: i32, deleted_by: i32, expected_version: Option<i32>) -> Result<(), DbErr>;
 }
 
 #[async_trait]
@@ -26,5 +28,6 @@ pub trait CategoryServiceTrait {
     async fn get_category(&self, id: i32) -> Result<Option<ApiResponse<CategoryResponse>>, ErrorResponse>;
     async fn create_category(&self, input: &CreateCategoryRequest) -> Result<ApiResponse<CategoryResponse>, ErrorResponse>;
     async fn update_category(&self, input: &UpdateCategoryRequest) -> Result<Option<ApiResponse<CategoryResponse>>, ErrorResponse>;
-    async fn delete_category(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn set_metadata_schema(&self, id: i32, schema: Option<&Value>, user_id: i32, expected_version: Option<i32>) -> Result<ApiResponse<CategoryResponse>, ErrorResponse>;
+    async fn delete_category(&self, id: i32, user_id: i32, expected_version: Option<i32>) -> Result<ApiResponse<()>, ErrorResponse>;
 }
\ No newline at end of file
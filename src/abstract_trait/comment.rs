@@ -3,7 +3,7 @@ use sea_orm::DbErr;
 use async_trait::async_trait;
 
 use crate::{
-    domain::{ApiResponse, CommentResponse, CreateCommentRequest, ErrorResponse, UpdateCommentRequest},
+    domain::{ApiResponse, CommentResponse, CreateCommentRequest, ErrorResponse, Pagination, PatchCommentRequest, PatchDocument, UpdateCommentRequest, VisibilityScope},
     entities::comments,
     
 };
@@ -14,21 +14,39 @@ pub type DynCommentService = Arc<dyn CommentServiceTrait + Send + Sync>;
 
 #[async_trait]
 pub trait CommentRepositoryTrait {
-    async fn find_all(&self) -> Result<Vec<comments::Model>, DbErr>;
+    /// Live comments on the posts listed by `scope`.
+    async fn find_all(&self, scope: &VisibilityScope) -> Result<Vec<comments::Model>, DbErr>;
     async fn find_by_id(&self, id: i32) -> Result<Option<comments::Model>, DbErr>;
+    /// Comments of a post, oldest first, with their total count.
+    async fn find_by_post(&self, post_id: i32, pagination: &Pagination) -> Result<(Vec<comments::Model>, u64), DbErr>;
     async fn create(&self, input: &CreateCommentRequest) -> Result<comments::Model, DbErr>;
     async fn update(&self, input: &UpdateCommentRequest) -> Result<comments::Model, DbErr>;
-    async fn delete(&self, id: i32) -> Result<(), DbErr>;
+    async fn patch(&self, input: &PatchCommentRequest) -> Result<comments::Model, DbErr>;
+    async fn delete(&self, id: i32, deleted_by: i32, expected_version: Option<i32>) -> Result<(), DbErr>;
 }
 
 #[async_trait]
 pub trait CommentServiceTrait {
-    async fn get_comments(&self) -> Result<ApiResponse<Vec<CommentResponse>>, ErrorResponse>;
-    async fn get_comment(&self, id: i32) -> Result<Option<ApiResponse<CommentResponse>>, ErrorResponse> ;
-    async fn create_comment(&self, input: &CreateCommentRequest) -> Result<ApiResponse<CommentResponse>, ErrorResponse>;
+    async fn get_comments(&self, viewer: Option<i32>) -> Result<ApiResponse<Vec<CommentResponse>>, ErrorResponse>;
+    async fn get_comment(&self, id: i32, viewer: Option<i32>, token: Option<&str>) -> Result<Option<ApiResponse<CommentResponse>>, ErrorResponse> ;
+    /// Only on posts the viewer can read; `token` unlocks a password-protected post.
+    async fn create_comment(&self, input: &CreateCommentRequest, viewer: Option<i32>, token: Option<&str>) -> Result<ApiResponse<CommentResponse>, ErrorResponse>;
+    /// The author of the post and editors only.
     async fn update_comment(
         &self,
-        input: &UpdateCommentRequest
+        input: &UpdateCommentRequest,
+        viewer: Option<i32>,
+        token: Option<&str>
     ) -> Result<Option<ApiResponse<CommentResponse>>, ErrorResponse>;
-    async fn delete_comment(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse>;
+    /// The author of the post and editors only.
+    async fn patch_comment(
+        &self,
+        id: i32,
+        patch: &PatchDocument,
+        expected_version: Option<i32>,
+        viewer: Option<i32>,
+        token: Option<&str>
+    ) -> Result<ApiResponse<CommentResponse>, ErrorResponse>;
+    /// The author of the post and editors only.
+    async fn delete_comment(&self, id: i32, user_id: i32, expected_version: Option<i32>, token: Option<&str>) -> Result<ApiResponse<()>, ErrorResponse>;
 }
@@ -0,0 +1,14 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::{ErrorResponse, XmlDocument, FeedFormat, FeedScope};
+
+
+pub type DynFeedService = Arc<dyn FeedServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait FeedServiceTrait {
+    /// Renders the feed, or `None` when its category or author does not exist.
+    async fn get_feed(&self, scope: FeedScope, format: FeedFormat) -> Result<Option<XmlDocument>, ErrorResponse>;
+}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::DbErr;
+
+use crate::{domain::{ApiResponse, CreateMediaRequest, ErrorResponse, MediaResponse, UploadMediaRequest}, entities::media};
+
+
+pub type DynMediaRepository = Arc<dyn MediaRepositoryTrait + Send + Sync>;
+pub type DynMediaService = Arc<dyn MediaServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait MediaRepositoryTrait {
+    async fn find_by_id(&self, id: i32) -> Result<Option<media::Model>, DbErr>;
+    async fn find_by_key(&self, key: &str) -> Result<Option<media::Model>, DbErr>;
+    async fn create(&self, input: &CreateMediaRequest) -> Result<media::Model, DbErr>;
+}
+
+#[async_trait]
+pub trait MediaServiceTrait {
+    async fn upload(&self, input: &UploadMediaRequest) -> Result<ApiResponse<MediaResponse>, ErrorResponse>;
+    fn max_upload_size(&self) -> usize;
+}
@@ -3,6 +3,19 @@ mod post;
 mod comment;
 mod user;
 mod auth;
+mod tag;
+mod search;
+mod media;
+mod storage;
+mod trash;
+mod views;
+mod reaction;
+mod bookmark;
+mod series;
+mod feed;
+mod sitemap;
+mod ranking;
+mod post_lock;
 
 pub use self::category::{
     CategoryRepositoryTrait, CategoryServiceTrait, DynCategoryRepository, DynCategoryService,
@@ -25,4 +38,56 @@ pub use self::user::{
 pub use self::auth::{
     DynAuthService,
     AuthServiceTrait
+};
+
+pub use self::tag::{
+    TagRepositoryTrait, TagServiceTrait, DynTagRepository, DynTagService
+};
+
+pub use self::search::{
+    SearchRepositoryTrait, SearchServiceTrait, DynSearchRepository, DynSearchService
+};
+
+pub use self::media::{
+    MediaRepositoryTrait, MediaServiceTrait, DynMediaRepository, DynMediaService
+};
+
+pub use self::storage::{
+    StorageTrait, DynStorage
+};
+
+pub use self::trash::{
+    TrashRepositoryTrait, TrashServiceTrait, DynTrashRepository, DynTrashService
+};
+
+pub use self::views::{
+    ViewRepositoryTrait, ViewServiceTrait, DynViewRepository, DynViewService
+};
+
+pub use self::reaction::{
+    ReactionRepositoryTrait, ReactionServiceTrait, DynReactionRepository, DynReactionService
+};
+
+pub use self::bookmark::{
+    BookmarkRepositoryTrait, BookmarkServiceTrait, DynBookmarkRepository, DynBookmarkService
+};
+
+pub use self::series::{
+    SeriesRepositoryTrait, SeriesServiceTrait, DynSeriesRepository, DynSeriesService
+};
+
+pub use self::feed::{
+    FeedServiceTrait, DynFeedService
+};
+
+pub use self::sitemap::{
+    SitemapRepositoryTrait, SitemapServiceTrait, DynSitemapRepository, DynSitemapService
+};
+
+pub use self::ranking::{
+    RankingRepositoryTrait, RankingServiceTrait, DynRankingRepository, DynRankingService
+};
+
+pub use self::post_lock::{
+    PostLockRepositoryTrait, PostLockServiceTrait, DynPostLockRepository, DynPostLockService
 };
\ No newline at end of file
@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
-use crate::{domain::{ApiResponse, CreatePostRequest, ErrorResponse, PostRelationResponse, PostResponse, UpdatePostRequest}, entities::posts};
+use crate::{domain::{ApiResponse, ArchiveMonthResponse, ArchivePeriod, ArchiveYearResponse, BulkPostOutcome, BulkPostRequest, BulkPostResponse, CreatePostRequest, CreateTranslationRequest, ErrorResponse, FeaturePostRequest, FeaturedPostResponse, FeedScope, PatchDocument, PatchPostRequest, PaginatedResponse, Pagination, PostDetailResponse, PostFields, PostIncludes, PostQuery, PostResponse, PostTransitionResponse, PostWrite, ReviewAction, ReviewRequest, UnlockPostRequest, UnlockPostResponse, UpdatePostRequest, VisibilityScope}, entities::{featured_posts, post_transitions, posts, sea_orm_active_enums::PostStatus, tags}};
 use async_trait::async_trait;
-use sea_orm::DbErr;
+use sea_orm::{prelude::DateTimeWithTimeZone, DbErr};
 
 
 
@@ -11,32 +11,107 @@ pub type DynPostsService = Arc<dyn PostsServiceTrait + Send + Sync>;
 
 #[async_trait]
 pub trait PostsRepositoryTrait {
-    async fn get_all_posts(&self) -> Result<Vec<posts::Model>, DbErr>;
+    async fn get_all_posts(&self, query: &PostQuery, scope: &VisibilityScope) -> Result<Vec<posts::Model>, DbErr>;
     async fn get_post(&self, post_id: i32) -> Result<Option<posts::Model>, DbErr>;
-    async fn get_post_relation(&self, post_id: i32) -> Result<Vec<PostRelationResponse>, DbErr>;
+    /// Published, unexpired featured posts by position.
+    async fn get_featured_posts(&self, scope: &VisibilityScope) -> Result<Vec<(featured_posts::Model, posts::Model)>, DbErr>;
+    /// Features the post, or updates the position and expiry of an already featured post.
+    async fn feature_post(&self, post_id: i32, input: &FeaturePostRequest) -> Result<(featured_posts::Model, posts::Model), DbErr>;
+    async fn unfeature_post(&self, post_id: i32) -> Result<bool, DbErr>;
+    async fn set_pinned(&self, post_id: i32, pinned: bool) -> Result<posts::Model, DbErr>;
+    /// The most recent published posts in the feed scope, newest first.
+    async fn get_feed_posts(&self, scope: &FeedScope, visibility: &VisibilityScope, limit: u64) -> Result<Vec<posts::Model>, DbErr>;
+    /// Published post counts per month, newest first.
+    async fn get_archive(&self, scope: &VisibilityScope) -> Result<Vec<ArchiveMonthResponse>, DbErr>;
+    /// Published posts created in `[start, end)`, newest first.
+    async fn get_archive_posts(&self, start: DateTimeWithTimeZone, end: DateTimeWithTimeZone, scope: &VisibilityScope, pagination: &Pagination) -> Result<(Vec<posts::Model>, u64), DbErr>;
+    /// Posts awaiting review, longest waiting first.
+    async fn get_review_queue(&self, pagination: &Pagination) -> Result<(Vec<posts::Model>, u64), DbErr>;
+    /// Moves the post from `from` to `to` and logs the change; fails with `RecordNotUpdated`
+    /// when the post is no longer in `from`.
+    async fn transition_post(&self, post_id: i32, from: PostStatus, to: PostStatus, actor_id: i32, note: Option<String>) -> Result<posts::Model, DbErr>;
+    /// Status changes of the post, oldest first.
+    async fn get_transitions(&self, post_id: i32) -> Result<Vec<post_transitions::Model>, DbErr>;
+    async fn get_post_tags(&self, posts: &[posts::Model]) -> Result<Vec<Vec<tags::Model>>, DbErr>;
     async fn create_post(
         &self,
         input: &CreatePostRequest
     ) -> Result<posts::Model, DbErr>;
+    /// Creates `input` as a translation of the source post, starting a translation group when needed.
+    /// Adds `input` to the translation group of `source_id`; `None` when the group already has its locale.
+    async fn create_translation(&self, source_id: i32, input: &CreatePostRequest) -> Result<Option<posts::Model>, DbErr>;
+    /// Live posts of the translation group visible in the scope, by locale.
+    async fn get_translations(&self, group_id: i32, scope: &VisibilityScope) -> Result<Vec<posts::Model>, DbErr>;
+    /// Distinct locales of live posts.
+    async fn get_locales(&self) -> Result<Vec<String>, DbErr>;
+    /// Saves the post unless a user other than `actor_id` holds its edit lock.
     async fn update_post(
         &self,
         input: &UpdatePostRequest
-    ) -> Result<posts::Model, DbErr>;
-    async fn delete_post(&self, post_id: i32) -> Result<(), DbErr>;
+    ) -> Result<PostWrite<posts::Model>, DbErr>;
+    /// Saves the post unless a user other than `actor_id` holds its edit lock.
+    async fn patch_post(&self, input: &PatchPostRequest) -> Result<PostWrite<posts::Model>, DbErr>;
+    /// Moves the post to the trash unless a user other than `deleted_by` holds its edit lock.
+    async fn delete_post(&self, post_id: i32, deleted_by: i32, expected_version: Option<i32>) -> Result<PostWrite<()>, DbErr>;
+    /// Applies the operation in one transaction to the posts `actor_id` may change, which are their
+    /// own or, when `is_editor`, any post; returns the outcome for each requested post in order.
+    async fn bulk_update(&self, actor_id: i32, is_editor: bool, input: &BulkPostRequest) -> Result<Vec<(i32, BulkPostOutcome)>, DbErr>;
 }
 
 #[async_trait]
 pub trait PostsServiceTrait {
-    async fn get_all_posts(&self) -> Result<ApiResponse<Vec<PostResponse>>, ErrorResponse>;
-    async fn get_post(&self, post_id: i32) -> Result<Option<ApiResponse<PostResponse>>, ErrorResponse>  ;
-    async fn get_post_relation(&self, post_id: i32) -> Result<ApiResponse<PostRelationResponse>, ErrorResponse>;
+    async fn get_all_posts(&self, query: &PostQuery, fields: &PostFields, viewer: Option<i32>) -> Result<ApiResponse<Vec<PostResponse>>, ErrorResponse>;
+    async fn get_featured_posts(&self, viewer: Option<i32>) -> Result<ApiResponse<Vec<FeaturedPostResponse>>, ErrorResponse>;
+    async fn get_archive(&self, viewer: Option<i32>) -> Result<ApiResponse<Vec<ArchiveYearResponse>>, ErrorResponse>;
+    async fn get_archive_posts(&self, period: &ArchivePeriod, pagination: &Pagination, viewer: Option<i32>) -> Result<ApiResponse<PaginatedResponse<PostResponse>>, ErrorResponse>;
+    /// Editors only.
+    async fn feature_post(&self, post_id: i32, input: &FeaturePostRequest, user_id: i32) -> Result<ApiResponse<FeaturedPostResponse>, ErrorResponse>;
+    /// Editors only; `None` when the post was not featured.
+    async fn unfeature_post(&self, post_id: i32, user_id: i32) -> Result<Option<ApiResponse<()>>, ErrorResponse>;
+    /// Editors only.
+    async fn pin_post(&self, post_id: i32, pinned: bool, user_id: i32) -> Result<ApiResponse<PostResponse>, ErrorResponse>;
+    /// The post with the related resources in `includes`; `comments` pages its comments.
+    /// `token` unlocks a password-protected post.
+    async fn get_post(
+        &self,
+        post_id: i32,
+        includes: &PostIncludes,
+        comments: &Pagination,
+        viewer: Option<i32>,
+        token: Option<&str>
+    ) -> Result<Option<ApiResponse<PostDetailResponse>>, ErrorResponse>;
+    async fn unlock_post(&self, post_id: i32, input: &UnlockPostRequest, viewer: Option<i32>) -> Result<ApiResponse<UnlockPostResponse>, ErrorResponse>;
+    /// The post is created for `user_id`; only editors may create published posts.
     async fn create_post(
         &self,
-        input: &CreatePostRequest
+        input: &CreatePostRequest,
+        user_id: i32
     ) -> Result<ApiResponse<PostResponse>, ErrorResponse>;
+    /// Editors only; the translation starts as a draft copy of the post.
+    async fn create_translation(&self, post_id: i32, input: &CreateTranslationRequest, user_id: i32) -> Result<ApiResponse<PostResponse>, ErrorResponse>;
+    /// The author and editors only; `token` unlocks a password-protected post.
     async fn update_post(
         &self,
-        input: &UpdatePostRequest
+        input: &UpdatePostRequest,
+        viewer: Option<i32>,
+        token: Option<&str>
+    ) -> Result<ApiResponse<PostResponse>, ErrorResponse>;
+    /// The author and editors only; `token` unlocks a password-protected post.
+    async fn patch_post(
+        &self,
+        post_id: i32,
+        patch: &PatchDocument,
+        expected_version: Option<i32>,
+        viewer: Option<i32>,
+        token: Option<&str>
     ) -> Result<ApiResponse<PostResponse>, ErrorResponse>;
-    async fn delete_post(&self, post_id: i32) -> Result<ApiResponse<()>, ErrorResponse>;
+    /// The author and editors only; `token` unlocks a password-protected post.
+    async fn delete_post(&self, post_id: i32, user_id: i32, expected_version: Option<i32>, token: Option<&str>) -> Result<ApiResponse<()>, ErrorResponse>;
+    async fn bulk_update_posts(&self, user_id: i32, input: &BulkPostRequest) -> Result<ApiResponse<BulkPostResponse>, ErrorResponse>;
+    /// Submits, approves or sends back the post; approving and requesting changes is for editors.
+    async fn review_post(&self, post_id: i32, action: ReviewAction, input: &ReviewRequest, user_id: i32) -> Result<ApiResponse<PostResponse>, ErrorResponse>;
+    /// Editors only.
+    async fn get_review_queue(&self, pagination: &Pagination, user_id: i32) -> Result<ApiResponse<PaginatedResponse<PostResponse>>, ErrorResponse>;
+    /// The author and editors only.
+    async fn get_transitions(&self, post_id: i32, user_id: i32) -> Result<ApiResponse<Vec<PostTransitionResponse>>, ErrorResponse>;
 }
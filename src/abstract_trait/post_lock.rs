@@ -0,0 +1,37 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use sea_orm::DbErr;
+
+use crate::{
+    domain::{ApiResponse, ErrorResponse, PostLockResponse},
+    entities::post_locks,
+};
+
+
+pub type DynPostLockRepository = Arc<dyn PostLockRepositoryTrait + Send + Sync>;
+pub type DynPostLockService = Arc<dyn PostLockServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait PostLockRepositoryTrait {
+    /// The unexpired lock on the post.
+    async fn find_lock(&self, post_id: i32) -> Result<Option<post_locks::Model>, DbErr>;
+    /// Locks the post for `ttl` unless another user holds an unexpired lock; `force` takes it over
+    /// regardless. Returns `None` when the post is locked by someone else.
+    async fn acquire_lock(&self, post_id: i32, user_id: i32, ttl: Duration, force: bool) -> Result<Option<post_locks::Model>, DbErr>;
+    /// Extends the user's unexpired lock by `ttl`; `None` when the user does not hold it.
+    async fn refresh_lock(&self, post_id: i32, user_id: i32, ttl: Duration) -> Result<Option<post_locks::Model>, DbErr>;
+    async fn release_lock(&self, post_id: i32, user_id: i32) -> Result<bool, DbErr>;
+}
+
+#[async_trait]
+pub trait PostLockServiceTrait {
+    /// `None` when nobody is editing the post; only for posts the user can read, `token` unlocks
+    /// a password-protected post.
+    async fn get_lock(&self, post_id: i32, user_id: i32, token: Option<&str>) -> Result<ApiResponse<Option<PostLockResponse>>, ErrorResponse>;
+    async fn acquire(&self, post_id: i32, user_id: i32) -> Result<ApiResponse<PostLockResponse>, ErrorResponse>;
+    async fn heartbeat(&self, post_id: i32, user_id: i32) -> Result<ApiResponse<PostLockResponse>, ErrorResponse>;
+    async fn release(&self, post_id: i32, user_id: i32) -> Result<ApiResponse<()>, ErrorResponse>;
+    /// Admins only; takes the lock over from its current holder.
+    async fn steal(&self, post_id: i32, user_id: i32) -> Result<ApiResponse<PostLockResponse>, ErrorResponse>;
+}
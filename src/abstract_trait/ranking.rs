@@ -0,0 +1,33 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use sea_orm::DbErr;
+
+use crate::{
+    domain::{ApiResponse, ErrorResponse, PaginatedResponse, Pagination, RankedPostResponse, VisibilityScope},
+    entities::{posts, related_posts, trending_posts},
+};
+
+
+pub type DynRankingRepository = Arc<dyn RankingRepositoryTrait + Send + Sync>;
+pub type DynRankingService = Arc<dyn RankingServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait RankingRepositoryTrait {
+    /// Rescores published posts from their views, reactions and comments within `window`,
+    /// halving the weight of an interaction every `half_life`. Returns the number of posts scored.
+    async fn refresh_trending(&self, window: Duration, half_life: Duration) -> Result<u64, DbErr>;
+    /// Keeps the `limit` most related published posts of each published post.
+    async fn refresh_related(&self, limit: u64) -> Result<u64, DbErr>;
+    async fn find_trending(&self, scope: &VisibilityScope, pagination: &Pagination) -> Result<(Vec<(trending_posts::Model, posts::Model)>, u64), DbErr>;
+    async fn find_related(&self, post_id: i32, scope: &VisibilityScope) -> Result<Vec<(related_posts::Model, posts::Model)>, DbErr>;
+}
+
+#[async_trait]
+pub trait RankingServiceTrait {
+    /// Recomputes the trending and related posts and returns how many rows were written to each.
+    async fn refresh(&self) -> Result<(u64, u64), ErrorResponse>;
+    async fn get_trending(&self, pagination: &Pagination, viewer: Option<i32>) -> Result<ApiResponse<PaginatedResponse<RankedPostResponse>>, ErrorResponse>;
+    /// `token` unlocks a password-protected post.
+    async fn get_related(&self, post_id: i32, viewer: Option<i32>, token: Option<&str>) -> Result<ApiResponse<Vec<RankedPostResponse>>, ErrorResponse>;
+}
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::DbErr;
+
+use crate::domain::{ApiResponse, ErrorResponse, ReactionResponse};
+
+
+pub type DynReactionRepository = Arc<dyn ReactionRepositoryTrait + Send + Sync>;
+pub type DynReactionService = Arc<dyn ReactionServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait ReactionRepositoryTrait {
+    /// Adds the reaction, or removes it if the user already has it. Returns whether it was added.
+    async fn toggle_post_reaction(&self, post_id: i32, user_id: i32, kind: &str) -> Result<bool, DbErr>;
+    async fn toggle_comment_reaction(&self, comment_id: i32, user_id: i32, kind: &str) -> Result<bool, DbErr>;
+    /// Reaction counts per post, in the order of `post_ids`.
+    async fn find_for_posts(&self, post_ids: &[i32], viewer: Option<i32>) -> Result<Vec<Vec<ReactionResponse>>, DbErr>;
+    /// Reaction counts per comment, in the order of `comment_ids`.
+    async fn find_for_comments(&self, comment_ids: &[i32], viewer: Option<i32>) -> Result<Vec<Vec<ReactionResponse>>, DbErr>;
+}
+
+#[async_trait]
+pub trait ReactionServiceTrait {
+    /// Only on posts the user can read; `token` unlocks a password-protected post.
+    async fn toggle_post_reaction(&self, post_id: i32, user_id: i32, kind: &str, token: Option<&str>) -> Result<ApiResponse<Vec<ReactionResponse>>, ErrorResponse>;
+    /// Only on comments of posts the user can read.
+    async fn toggle_comment_reaction(&self, comment_id: i32, user_id: i32, kind: &str, token: Option<&str>) -> Result<ApiResponse<Vec<ReactionResponse>>, ErrorResponse>;
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::DbErr;
+
+use crate::domain::{ApiResponse, ErrorResponse, PaginatedResponse, Pagination, SearchHitResponse, SearchQuery, VisibilityScope};
+
+
+pub type DynSearchRepository = Arc<dyn SearchRepositoryTrait + Send + Sync>;
+pub type DynSearchService = Arc<dyn SearchServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait SearchRepositoryTrait {
+    async fn search(
+        &self,
+        tsquery: &str,
+        include_comments: bool,
+        scope: &VisibilityScope,
+        pagination: &Pagination
+    ) -> Result<(Vec<SearchHitResponse>, u64), DbErr>;
+}
+
+#[async_trait]
+pub trait SearchServiceTrait {
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        pagination: &Pagination,
+        viewer: Option<i32>
+    ) -> Result<ApiResponse<PaginatedResponse<SearchHitResponse>>, ErrorResponse>;
+}
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::DbErr;
+
+use crate::{
+    domain::{
+        ApiResponse, CreateSeriesRequest, ErrorResponse, PaginatedResponse, Pagination,
+        ReorderSeriesRequest, SeriesPartResponse, SeriesResponse, VisibilityScope,
+    },
+    entities::series,
+};
+
+
+pub type DynSeriesRepository = Arc<dyn SeriesRepositoryTrait + Send + Sync>;
+pub type DynSeriesService = Arc<dyn SeriesServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait SeriesRepositoryTrait {
+    /// Creates the series with `post_ids` as its parts, all of which must be live posts of `user_id`.
+    async fn create_series(&self, user_id: i32, input: &CreateSeriesRequest) -> Result<series::Model, DbErr>;
+    /// Parts are limited to the posts listed by `scope`.
+    async fn find_series(&self, id: i32, scope: &VisibilityScope) -> Result<Option<(series::Model, Vec<SeriesPartResponse>)>, DbErr>;
+    async fn find_all_series(&self, scope: &VisibilityScope, pagination: &Pagination) -> Result<(Vec<(series::Model, Vec<SeriesPartResponse>)>, u64), DbErr>;
+    /// Replaces the parts of a series owned by `user_id`; returns false when there is no such series.
+    async fn set_parts(&self, id: i32, user_id: i32, post_ids: &[i32]) -> Result<bool, DbErr>;
+    async fn delete_series(&self, id: i32, user_id: i32) -> Result<bool, DbErr>;
+    /// The series containing any of `post_ids`, with their live parts listed by `scope`.
+    async fn find_for_posts(&self, post_ids: &[i32], scope: &VisibilityScope) -> Result<Vec<(series::Model, Vec<SeriesPartResponse>)>, DbErr>;
+}
+
+#[async_trait]
+pub trait SeriesServiceTrait {
+    async fn create_series(&self, user_id: i32, input: &CreateSeriesRequest) -> Result<ApiResponse<SeriesResponse>, ErrorResponse>;
+    async fn get_series(&self, id: i32, viewer: Option<i32>) -> Result<Option<ApiResponse<SeriesResponse>>, ErrorResponse>;
+    async fn get_all_series(&self, pagination: &Pagination, viewer: Option<i32>) -> Result<ApiResponse<PaginatedResponse<SeriesResponse>>, ErrorResponse>;
+    async fn reorder_series(&self, id: i32, user_id: i32, input: &ReorderSeriesRequest) -> Result<Option<ApiResponse<SeriesResponse>>, ErrorResponse>;
+    async fn delete_series(&self, id: i32, user_id: i32) -> Result<Option<ApiResponse<()>>, ErrorResponse>;
+}
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::DbErr;
+
+use crate::domain::{ErrorResponse, SitemapEntry, SitemapFingerprint, XmlDocument};
+
+
+pub type DynSitemapRepository = Arc<dyn SitemapRepositoryTrait + Send + Sync>;
+pub type DynSitemapService = Arc<dyn SitemapServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait SitemapRepositoryTrait {
+    async fn fingerprint(&self) -> Result<SitemapFingerprint, DbErr>;
+    /// Published public posts, by id.
+    async fn find_posts(&self) -> Result<Vec<SitemapEntry>, DbErr>;
+    /// Categories, modified when they or any of their published public posts were.
+    async fn find_categories(&self) -> Result<Vec<SitemapEntry>, DbErr>;
+    /// Users with published public posts, modified when their latest post was.
+    async fn find_authors(&self) -> Result<Vec<SitemapEntry>, DbErr>;
+}
+
+#[async_trait]
+pub trait SitemapServiceTrait {
+    /// The sitemap, or a sitemap index when the site has more URLs than fit in one sitemap.
+    async fn get_sitemap(&self) -> Result<XmlDocument, ErrorResponse>;
+    /// One of the sitemaps listed in the index, numbered from 1.
+    async fn get_sitemap_page(&self, page: usize) -> Result<Option<XmlDocument>, ErrorResponse>;
+}
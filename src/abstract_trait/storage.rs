@@ -0,0 +1,16 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::utils::AppError;
+
+
+pub type DynStorage = Arc<dyn StorageTrait + Send + Sync>;
+
+#[async_trait]
+pub trait StorageTrait {
+    async fn put(&self, key: &str, bytes: &[u8]) -> Result<(), AppError>;
+    async fn exists(&self, key: &str) -> Result<bool, AppError>;
+    async fn delete(&self, key: &str) -> Result<(), AppError>;
+    fn url(&self, key: &str) -> String;
+}
@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::DbErr;
+
+use crate::{domain::{ApiResponse, ErrorResponse, PostResponse, TagResponse, VisibilityScope}, entities::tags};
+
+
+pub type DynTagRepository = Arc<dyn TagRepositoryTrait + Send + Sync>;
+pub type DynTagService = Arc<dyn TagServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait TagRepositoryTrait {
+    /// Every tag with the number of its live posts listed by `scope`.
+    async fn find_all_with_post_count(&self, scope: &VisibilityScope) -> Result<Vec<TagResponse>, DbErr>;
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<tags::Model>, DbErr>;
+}
+
+#[async_trait]
+pub trait TagServiceTrait {
+    /// Post counts only include the posts listed to the viewer.
+    async fn get_tags(&self, viewer: Option<i32>) -> Result<ApiResponse<Vec<TagResponse>>, ErrorResponse>;
+    async fn get_tag_posts(&self, slug: &str, viewer: Option<i32>) -> Result<Option<ApiResponse<Vec<PostResponse>>>, ErrorResponse>;
+}
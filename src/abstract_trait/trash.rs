@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use sea_orm::{prelude::DateTimeWithTimeZone, DbErr};
+
+use crate::domain::{ApiResponse, ErrorResponse, TrashItemResponse, TrashKind};
+
+
+pub type DynTrashRepository = Arc<dyn TrashRepositoryTrait + Send + Sync>;
+pub type DynTrashService = Arc<dyn TrashServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait TrashRepositoryTrait {
+    async fn find_deleted_by(&self, user_id: i32) -> Result<Vec<TrashItemResponse>, DbErr>;
+    async fn restore(&self, kind: TrashKind, id: i32, user_id: i32) -> Result<bool, DbErr>;
+    async fn purge(&self, deleted_before: DateTimeWithTimeZone) -> Result<u64, DbErr>;
+}
+
+#[async_trait]
+pub trait TrashServiceTrait {
+    async fn get_trash(&self, user_id: i32) -> Result<ApiResponse<Vec<TrashItemResponse>>, ErrorResponse>;
+    async fn restore(&self, kind: TrashKind, id: i32, user_id: i32) -> Result<Option<ApiResponse<()>>, ErrorResponse>;
+    async fn purge_expired(&self) -> Result<u64, ErrorResponse>;
+}
@@ -3,7 +3,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 
-use crate::{domain::{ApiResponse, CreateUserRequest, ErrorResponse, UpdateUserRequest, UserResponse}, entities::users};
+use crate::{domain::{ApiResponse, CreateUserRequest, ErrorResponse, PatchDocument, UpdateUserRequest, UserResponse}, entities::users};
 
 pub type DynUserRepository = Arc<dyn UserRepositoryTrait + Send + Sync>;
 pub type DynUserService = Arc<dyn UserServiceTrait + Send + Sync>;
@@ -37,5 +37,6 @@ pub trait UserServiceTrait {
         &self,
         input: &UpdateUserRequest
     ) -> Result<Option<ApiResponse<UserResponse>>, ErrorResponse>;
+    async fn patch_user(&self, id: i32, patch: &PatchDocument, expected_version: Option<i32>, actor_id: i32) -> Result<ApiResponse<UserResponse>, ErrorResponse>;
     async fn delete_user(&self, email: &str) -> Result<ApiResponse<()>, ErrorResponse>;
 }
\ No newline at end of file
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sea_orm::DbErr;
+
+use crate::domain::{ApiResponse, DailyViewsResponse, ErrorResponse, ViewStatsQuery, ViewStatsResponse};
+
+
+pub type DynViewRepository = Arc<dyn ViewRepositoryTrait + Send + Sync>;
+pub type DynViewService = Arc<dyn ViewServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait ViewRepositoryTrait {
+    /// Adds `(post_id, day, views)` counts to the daily aggregates.
+    async fn add_views(&self, counts: &[(i32, NaiveDate, i64)]) -> Result<u64, DbErr>;
+    async fn find_by_post(&self, post_id: i32, from: NaiveDate, to: NaiveDate) -> Result<Vec<DailyViewsResponse>, DbErr>;
+    async fn find_by_author(&self, user_id: i32, from: NaiveDate, to: NaiveDate) -> Result<Vec<DailyViewsResponse>, DbErr>;
+}
+
+#[async_trait]
+pub trait ViewServiceTrait {
+    /// Buffers a view of a post, ignoring repeats from the same visitor within the dedupe window.
+    fn record_view(&self, post_id: i32, visitor: &str);
+    /// Writes the buffered counts to the database and returns the number of views written.
+    async fn flush(&self) -> Result<i64, ErrorResponse>;
+    async fn get_post_views(&self, post_id: i32, query: &ViewStatsQuery, user_id: i32) -> Result<ApiResponse<ViewStatsResponse>, ErrorResponse>;
+    async fn get_author_views(&self, user_id: i32, query: &ViewStatsQuery, viewer_id: i32) -> Result<ApiResponse<ViewStatsResponse>, ErrorResponse>;
+}
@@ -1,11 +1,14 @@
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use jsonwebtoken::{
     decode, encode, errors::ErrorKind as JwtError, DecodingKey, EncodingKey, Header, Validation,
 };
 use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
 
 use crate::utils::AppError;
 
+/// Lifetime of the token that unlocks a password-protected post.
+pub const POST_TOKEN_MINUTES: i64 = 30;
 
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -21,6 +24,15 @@ impl Claims {
     }
 }
 
+/// Claims of a post unlock token; `pwd` ties it to the password it was issued for.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PostClaims {
+    pub post_id: i32,
+    pub pwd: String,
+    pub exp: usize,
+    pub iat: usize,
+}
+
 #[derive(Clone)]
 pub struct JwtConfig{
     pub jwt_secret: String,
@@ -46,7 +58,7 @@ impl JwtConfig {
             &EncodingKey::from_secret("YOUR_SECRET_KEY".as_ref()),
         ) {
             Ok(token) => Ok(token),
-            Err(err) => Err(AppError::TokenGenerationError(err.into())),
+            Err(err) => Err(AppError::TokenGenerationError(err)),
         }
     }
 
@@ -73,5 +85,41 @@ impl JwtConfig {
             }
         }
     }
-    
-}
\ No newline at end of file
+
+    /// Token granting read access to a password-protected post until it expires or the password changes.
+    pub fn generate_post_token(&self, post_id: i32, password_hash: &str) -> Result<(String, DateTime<Utc>), AppError> {
+        let now = Utc::now();
+        let expires_at = now + Duration::minutes(POST_TOKEN_MINUTES);
+
+        let claims = PostClaims {
+            post_id,
+            pwd: password_fingerprint(password_hash),
+            exp: expires_at.timestamp() as usize,
+            iat: now.timestamp() as usize,
+        };
+
+        let token = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(self.jwt_secret.as_ref()),
+        )?;
+
+        Ok((token, expires_at))
+    }
+
+    pub fn verify_post_token(&self, token: &str, post_id: i32, password_hash: &str) -> bool {
+        let decoding_key = DecodingKey::from_secret(self.jwt_secret.as_ref());
+
+        match decode::<PostClaims>(token, &decoding_key, &Validation::default()) {
+            Ok(token_data) => {
+                token_data.claims.post_id == post_id
+                    && token_data.claims.pwd == password_fingerprint(password_hash)
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+fn password_fingerprint(password_hash: &str) -> String {
+    format!("{:x}", Sha256::digest(password_hash.as_bytes()))
+}
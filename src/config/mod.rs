@@ -1,5 +1,19 @@
 mod hashing;
 mod jwt;
+mod upload;
+mod trash;
+mod views;
+mod reactions;
+mod site;
+mod ranking;
+mod post_lock;
 
 pub use self::jwt::JwtConfig;
-pub use self::hashing::Hashing;
\ No newline at end of file
+pub use self::hashing::Hashing;
+pub use self::upload::UploadConfig;
+pub use self::trash::TrashConfig;
+pub use self::views::ViewConfig;
+pub use self::reactions::ReactionConfig;
+pub use self::site::SiteConfig;
+pub use self::ranking::RankingConfig;
+pub use self::post_lock::PostLockConfig;
\ No newline at end of file
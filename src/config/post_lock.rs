@@ -0,0 +1,24 @@
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct PostLockConfig {
+    /// How long an edit lock lasts without a heartbeat.
+    pub ttl: Duration,
+}
+
+impl PostLockConfig {
+    pub fn new(ttl_secs: u64) -> Self {
+        PostLockConfig {
+            ttl: Duration::from_secs(ttl_secs),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let ttl_secs = std::env::var("POST_LOCK_TTL_SECS")
+            .ok()
+            .and_then(|secs| secs.parse().ok())
+            .unwrap_or(120);
+
+        PostLockConfig::new(ttl_secs)
+    }
+}
@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct RankingConfig {
+    /// Views, reactions and comments older than this do not count towards trending scores.
+    pub trending_window: Duration,
+    /// Age at which an interaction counts half as much towards the trending score.
+    pub trending_half_life: Duration,
+    /// Number of related posts kept for each post.
+    pub related_limit: u64,
+    /// How often trending and related posts are recomputed.
+    pub refresh_interval: Duration,
+}
+
+impl RankingConfig {
+    pub fn new(trending_window_days: u64, trending_half_life_hours: u64, related_limit: u64, refresh_interval_secs: u64) -> Self {
+        RankingConfig {
+            trending_window: Duration::from_secs(trending_window_days.saturating_mul(24 * 60 * 60)),
+            trending_half_life: Duration::from_secs(trending_half_life_hours.max(1).saturating_mul(60 * 60)),
+            related_limit,
+            refresh_interval: Duration::from_secs(refresh_interval_secs.max(1)),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let trending_window_days = std::env::var("TRENDING_WINDOW_DAYS")
+            .ok()
+            .and_then(|days| days.parse().ok())
+            .unwrap_or(7);
+        let trending_half_life_hours = std::env::var("TRENDING_HALF_LIFE_HOURS")
+            .ok()
+            .and_then(|hours| hours.parse().ok())
+            .unwrap_or(24);
+        let related_limit = std::env::var("RELATED_POSTS_LIMIT")
+            .ok()
+            .and_then(|limit| limit.parse().ok())
+            .unwrap_or(5);
+        let refresh_interval_secs = std::env::var("RANKING_REFRESH_INTERVAL_SECS")
+            .ok()
+            .and_then(|secs| secs.parse().ok())
+            .unwrap_or(15 * 60);
+
+        RankingConfig::new(trending_window_days, trending_half_life_hours, related_limit, refresh_interval_secs)
+    }
+}
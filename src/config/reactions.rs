@@ -0,0 +1,30 @@
+#[derive(Clone)]
+pub struct ReactionConfig {
+    /// Reaction kinds users may add to posts and comments, e.g. `like`.
+    pub kinds: Vec<String>,
+}
+
+impl ReactionConfig {
+    pub fn new(kinds: &[&str]) -> Self {
+        ReactionConfig {
+            kinds: kinds.iter().map(|kind| kind.to_string()).collect(),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let kinds = std::env::var("REACTION_KINDS")
+            .unwrap_or_else(|_| "like,love,laugh,wow,sad,angry".to_string());
+
+        ReactionConfig::new(
+            &kinds
+                .split(',')
+                .map(str::trim)
+                .filter(|kind| !kind.is_empty())
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    pub fn is_allowed(&self, kind: &str) -> bool {
+        self.kinds.iter().any(|allowed| allowed == kind)
+    }
+}
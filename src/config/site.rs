@@ -0,0 +1,38 @@
+#[derive(Clone)]
+pub struct SiteConfig {
+    /// Public URL of the blog, used for absolute links in feeds and sitemaps, without a trailing slash.
+    pub base_url: String,
+    pub title: String,
+    /// Number of most recent posts in each feed.
+    pub feed_limit: u64,
+}
+
+impl SiteConfig {
+    pub fn new(base_url: &str, title: &str, feed_limit: u64) -> Self {
+        SiteConfig {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            title: title.to_string(),
+            feed_limit,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let base_url = std::env::var("SITE_BASE_URL")
+            .unwrap_or_else(|_| "http://localhost:8000".to_string());
+        let title = std::env::var("SITE_TITLE").unwrap_or_else(|_| "Blog".to_string());
+        let feed_limit = std::env::var("FEED_LIMIT")
+            .ok()
+            .and_then(|limit| limit.parse().ok())
+            .unwrap_or(20);
+
+        SiteConfig::new(&base_url, &title, feed_limit)
+    }
+
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    pub fn post_url(&self, post_id: i32) -> String {
+        self.url(&format!("/posts/{}", post_id))
+    }
+}
@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct TrashConfig {
+    /// How long soft-deleted items stay in the trash before they are purged.
+    pub retention: Duration,
+    /// How often the purge job runs.
+    pub purge_interval: Duration,
+}
+
+impl TrashConfig {
+    pub fn new(retention_days: u64, purge_interval_secs: u64) -> Self {
+        TrashConfig {
+            retention: Duration::from_secs(retention_days.saturating_mul(24 * 60 * 60)),
+            purge_interval: Duration::from_secs(purge_interval_secs.max(1)),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let retention_days = std::env::var("TRASH_RETENTION_DAYS")
+            .ok()
+            .and_then(|days| days.parse().ok())
+            .unwrap_or(30);
+        let purge_interval_secs = std::env::var("TRASH_PURGE_INTERVAL_SECS")
+            .ok()
+            .and_then(|secs| secs.parse().ok())
+            .unwrap_or(60 * 60);
+
+        TrashConfig::new(retention_days, purge_interval_secs)
+    }
+}
@@ -0,0 +1,29 @@
+#[derive(Clone)]
+pub struct UploadConfig {
+    /// Directory the local storage backend writes files to.
+    pub dir: String,
+    /// URL prefix the stored files are served from.
+    pub public_path: String,
+    /// Maximum accepted upload size in bytes.
+    pub max_size: usize,
+}
+
+impl UploadConfig {
+    pub fn new(dir: &str, public_path: &str, max_size: usize) -> Self {
+        UploadConfig {
+            dir: dir.to_string(),
+            public_path: public_path.trim_end_matches('/').to_string(),
+            max_size,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let dir = std::env::var("UPLOAD_DIR").unwrap_or_else(|_| "uploads".to_string());
+        let max_size = std::env::var("UPLOAD_MAX_SIZE")
+            .ok()
+            .and_then(|size| size.parse().ok())
+            .unwrap_or(5 * 1024 * 1024);
+
+        UploadConfig::new(&dir, "/uploads", max_size)
+    }
+}
@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct ViewConfig {
+    /// Repeat views of a post by the same visitor within this window count once.
+    pub dedupe_window: Duration,
+    /// How often buffered view counts are written to the database.
+    pub flush_interval: Duration,
+}
+
+impl ViewConfig {
+    pub fn new(dedupe_window_secs: u64, flush_interval_secs: u64) -> Self {
+        ViewConfig {
+            dedupe_window: Duration::from_secs(dedupe_window_secs),
+            flush_interval: Duration::from_secs(flush_interval_secs.max(1)),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let dedupe_window_secs = std::env::var("VIEW_DEDUPE_WINDOW_SECS")
+            .ok()
+            .and_then(|secs| secs.parse().ok())
+            .unwrap_or(30 * 60);
+        let flush_interval_secs = std::env::var("VIEW_FLUSH_INTERVAL_SECS")
+            .ok()
+            .and_then(|secs| secs.parse().ok())
+            .unwrap_or(60);
+
+        ViewConfig::new(dedupe_window_secs, flush_interval_secs)
+    }
+}
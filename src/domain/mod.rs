@@ -6,10 +6,44 @@ pub use self::request::{
     UpdateCategoryRequest,
     CreatePostRequest,
     UpdatePostRequest,
+    PatchPostRequest,
+    PostDocument,
+    PostQuery,
+    PostFields,
+    UnlockPostRequest,
+    FeaturePostRequest,
+    CreateTranslationRequest,
+    VisibilityScope,
+    PostDetailQuery,
+    PostIncludes,
+    BulkPostRequest,
+    BulkPostOperation,
+    TagMatch,
+    Pagination,
+    SearchQuery,
+    UploadMediaRequest,
+    CreateMediaRequest,
+    TrashKind,
     CreateCommentRequest,
     UpdateCommentRequest,
+    PatchCommentRequest,
+    CommentDocument,
     CreateUserRequest,
     UpdateUserRequest,
+    UserDocument,
+    PatchDocument,
+    changed,
+    ViewStatsQuery,
+    BookmarkQuery,
+    CreateBookmarkRequest,
+    ReadingListRequest,
+    CreateSeriesRequest,
+    ReorderSeriesRequest,
+    FeedFormat,
+    FeedScope,
+    ArchivePeriod,
+    ReviewAction,
+    ReviewRequest,
     LoginRequest,
     RegisterRequest
 };
@@ -19,7 +53,36 @@ pub use self::response::{
     ErrorResponse,
     CategoryResponse,
     PostResponse,
-    PostRelationResponse,
+    PostDetailResponse,
+    PostTranslationResponse,
+    BulkPostResult,
+    BulkPostOutcome,
+    PostWrite,
+    BulkPostResponse,
+    UnlockPostResponse,
+    FeaturedPostResponse,
+    RankedPostResponse,
     CommentResponse,
-    UserResponse
+    UserResponse,
+    TagResponse,
+    PaginatedResponse,
+    SearchHitResponse,
+    MediaResponse,
+    TrashItemResponse,
+    DailyViewsResponse,
+    ViewStatsResponse,
+    ReactionResponse,
+    BookmarkResponse,
+    ReadingListResponse,
+    SharedReadingListResponse,
+    SeriesResponse,
+    SeriesPartResponse,
+    SeriesNavigationResponse,
+    XmlDocument,
+    SitemapEntry,
+    SitemapFingerprint,
+    ArchiveMonthResponse,
+    ArchiveYearResponse,
+    PostTransitionResponse,
+    PostLockResponse
 };
\ No newline at end of file
@@ -0,0 +1,111 @@
+use chrono::{NaiveDate, TimeZone, Utc};
+use sea_orm::prelude::DateTimeWithTimeZone;
+
+use crate::utils::AppError;
+
+const MIN_YEAR: i32 = 1;
+const MAX_YEAR: i32 = 9999;
+
+/// A year, or a month of a year, of the post archive. Periods are in UTC.
+///
+/// Posts are filed under the month they were created in; posts do not record when they were
+/// published, so a draft published later still appears under its creation month.
+#[derive(Debug, Clone, Copy)]
+pub struct ArchivePeriod {
+    pub year: i32,
+    pub month: Option<u32>,
+}
+
+impl ArchivePeriod {
+    pub fn new(year: i32, month: Option<u32>) -> Self {
+        Self { year, month }
+    }
+
+    /// The half-open range `[start, end)` of creation times in the period.
+    pub fn range(&self) -> Result<(DateTimeWithTimeZone, DateTimeWithTimeZone), AppError> {
+        if !(MIN_YEAR..=MAX_YEAR).contains(&self.year) {
+            return Err(AppError::ValidationError(format!("Invalid archive year {}", self.year)));
+        }
+
+        if let Some(month) = self.month.filter(|month| !(1..=12).contains(month)) {
+            return Err(AppError::ValidationError(format!("Invalid archive month {}-{:02}", self.year, month)));
+        }
+
+        let (start, end) = match self.month {
+            Some(month) => {
+                let (next_year, next_month) = if month == 12 { (self.year + 1, 1) } else { (self.year, month + 1) };
+                (
+                    NaiveDate::from_ymd_opt(self.year, month, 1),
+                    NaiveDate::from_ymd_opt(next_year, next_month, 1),
+                )
+            }
+            None => (
+                NaiveDate::from_ymd_opt(self.year, 1, 1),
+                NaiveDate::from_ymd_opt(self.year + 1, 1, 1),
+            ),
+        };
+
+        match (start, end) {
+            (Some(start), Some(end)) => Ok((start_of(start), start_of(end))),
+            _ => Err(AppError::ValidationError(format!(
+                "Invalid archive month {}-{:02}",
+                self.year,
+                self.month.unwrap_or_default()
+            ))),
+        }
+    }
+}
+
+fn start_of(day: NaiveDate) -> DateTimeWithTimeZone {
+    Utc.from_utc_datetime(&day.and_hms_opt(0, 0, 0).unwrap_or_default()).into()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(year: i32, month: u32) -> DateTimeWithTimeZone {
+        start_of(NaiveDate::from_ymd_opt(year, month, 1).unwrap())
+    }
+
+    #[test]
+    fn month_covers_the_month() {
+        let range = ArchivePeriod::new(2024, Some(2)).range().ok();
+
+        assert_eq!(range, Some((at(2024, 2), at(2024, 3))));
+    }
+
+    #[test]
+    fn december_ends_next_year() {
+        let range = ArchivePeriod::new(2024, Some(12)).range().ok();
+
+        assert_eq!(range, Some((at(2024, 12), at(2025, 1))));
+    }
+
+    #[test]
+    fn year_covers_the_year() {
+        let range = ArchivePeriod::new(2024, None).range().ok();
+
+        assert_eq!(range, Some((at(2024, 1), at(2025, 1))));
+    }
+
+    #[test]
+    fn rejects_invalid_periods() {
+        for period in [
+            ArchivePeriod::new(2024, Some(0)),
+            ArchivePeriod::new(2024, Some(13)),
+            ArchivePeriod::new(0, None),
+            ArchivePeriod::new(10_000, Some(1)),
+        ] {
+            assert!(matches!(period.range(), Err(AppError::ValidationError(_))), "{:?}", period);
+        }
+    }
+
+    #[test]
+    fn accepts_the_last_supported_month() {
+        let range = ArchivePeriod::new(MAX_YEAR, Some(12)).range().ok();
+
+        assert_eq!(range, Some((at(MAX_YEAR, 12), at(MAX_YEAR + 1, 1))));
+    }
+}
@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
 
+use crate::utils::AppError;
+
+use super::user::validate_email;
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RegisterRequest {
     pub firstname: String,
@@ -8,6 +12,16 @@ pub struct RegisterRequest {
     pub password: String,
 }
 
+impl RegisterRequest {
+    pub fn validate(&self) -> Result<(), AppError> {
+        if self.firstname.trim().is_empty() || self.lastname.trim().is_empty() {
+            return Err(AppError::ValidationError("firstname and lastname must not be empty".to_string()));
+        }
+
+        validate_email(&self.email)
+    }
+}
+
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LoginRequest {
@@ -0,0 +1,37 @@
+use serde::Deserialize;
+
+use crate::utils::AppError;
+
+const MAX_READING_LIST_NAME: usize = 100;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CreateBookmarkRequest {
+    /// Reading list to file the bookmark under; `None` leaves it unfiled.
+    pub reading_list_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct BookmarkQuery {
+    /// Only list bookmarks in this reading list, e.g. `?list=3`.
+    pub list: Option<i32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReadingListRequest {
+    pub name: String,
+}
+
+impl ReadingListRequest {
+    pub fn validate(&self) -> Result<(), AppError> {
+        let name = self.name.trim();
+
+        if name.is_empty() || name.chars().count() > MAX_READING_LIST_NAME {
+            return Err(AppError::ValidationError(format!(
+                "name must be between 1 and {} characters",
+                MAX_READING_LIST_NAME
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -11,4 +11,7 @@ pub struct CreateCategoryRequest {
 pub struct UpdateCategoryRequest {
     pub id: Option<i32>,
     pub name: Option<String>,
+    /// Expected version from the `If-Match` header.
+    #[serde(skip)]
+    pub version: Option<i32>,
 }
\ No newline at end of file
@@ -1,4 +1,6 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use crate::{entities::comments, utils::AppError};
 
 #[derive(Debug, Deserialize)]
 pub struct CreateCommentRequest {
@@ -9,7 +11,52 @@ pub struct CreateCommentRequest {
 
 #[derive(Debug, Deserialize)]
 pub struct UpdateCommentRequest {
+    #[serde(skip)]
+    pub id: Option<i32>,
     pub id_post_comment: i32,
     pub user_name_comment: String,
     pub comment: String,
-}
\ No newline at end of file
+    /// Expected version from the `If-Match` header.
+    #[serde(skip)]
+    pub version: Option<i32>,
+}
+
+/// Patchable representation of a comment that `PATCH` bodies are applied to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CommentDocument {
+    pub user_name_comment: String,
+    pub comment: String,
+}
+
+impl CommentDocument {
+    pub fn validate(&self) -> Result<(), AppError> {
+        if self.user_name_comment.trim().is_empty() {
+            return Err(AppError::ValidationError("user_name_comment must not be empty".to_string()));
+        }
+
+        if self.comment.trim().is_empty() {
+            return Err(AppError::ValidationError("comment must not be empty".to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&comments::Model> for CommentDocument {
+    fn from(comment: &comments::Model) -> Self {
+        CommentDocument {
+            user_name_comment: comment.user_name_comment.clone(),
+            comment: comment.comment.clone(),
+        }
+    }
+}
+
+/// Partial comment update; `None` fields are left untouched.
+#[derive(Debug, Clone, Default)]
+pub struct PatchCommentRequest {
+    pub id: i32,
+    pub user_name_comment: Option<String>,
+    pub comment: Option<String>,
+    pub version: Option<i32>,
+}
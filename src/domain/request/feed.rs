@@ -0,0 +1,40 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedFormat {
+    Atom,
+    Rss,
+}
+
+impl FeedFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            FeedFormat::Atom => "application/atom+xml; charset=utf-8",
+            FeedFormat::Rss => "application/rss+xml; charset=utf-8",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            FeedFormat::Atom => "xml",
+            FeedFormat::Rss => "rss",
+        }
+    }
+}
+
+/// Which published posts a feed contains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedScope {
+    All,
+    Category(i32),
+    Author(i32),
+}
+
+impl FeedScope {
+    /// Path of the feed, without the extension.
+    pub fn path(&self) -> String {
+        match self {
+            FeedScope::All => "/feeds/posts".to_string(),
+            FeedScope::Category(id) => format!("/feeds/categories/{}", id),
+            FeedScope::Author(id) => format!("/feeds/authors/{}", id),
+        }
+    }
+}
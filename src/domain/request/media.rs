@@ -0,0 +1,15 @@
+#[derive(Debug)]
+pub struct UploadMediaRequest {
+    pub user_id: i32,
+    /// Content type declared by the client for the multipart field.
+    pub content_type: Option<String>,
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub struct CreateMediaRequest {
+    pub key: String,
+    pub content_type: String,
+    pub size: i64,
+    pub user_id: i32,
+}
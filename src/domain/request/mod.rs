@@ -3,17 +3,43 @@ mod post;
 mod comment;
 mod user;
 mod auth;
+mod pagination;
+mod search;
+mod media;
+mod trash;
+mod patch;
+mod views;
+mod bookmark;
+mod series;
+mod feed;
+mod archive;
+mod review;
 
 pub use self::category::{CreateCategoryRequest, UpdateCategoryRequest};
 pub use self::post::{
     CreatePostRequest,
-    UpdatePostRequest
+    UpdatePostRequest,
+    PatchPostRequest,
+    PostDocument,
+    PostQuery,
+    PostFields,
+    UnlockPostRequest,
+    FeaturePostRequest,
+    CreateTranslationRequest,
+    VisibilityScope,
+    PostDetailQuery,
+    PostIncludes,
+    BulkPostRequest,
+    BulkPostOperation,
+    TagMatch
 };
 
 
 pub use self::comment::{
     CreateCommentRequest,
-    UpdateCommentRequest
+    UpdateCommentRequest,
+    PatchCommentRequest,
+    CommentDocument
 };
 
 pub use self::auth::{
@@ -23,5 +49,18 @@ pub use self::auth::{
 
 pub use self::user::{
     CreateUserRequest,
-    UpdateUserRequest
-};
\ No newline at end of file
+    UpdateUserRequest,
+    UserDocument
+};
+
+pub use self::pagination::Pagination;
+pub use self::search::SearchQuery;
+pub use self::media::{CreateMediaRequest, UploadMediaRequest};
+pub use self::trash::TrashKind;
+pub use self::patch::{changed, PatchDocument};
+pub use self::views::ViewStatsQuery;
+pub use self::bookmark::{BookmarkQuery, CreateBookmarkRequest, ReadingListRequest};
+pub use self::series::{CreateSeriesRequest, ReorderSeriesRequest};
+pub use self::feed::{FeedFormat, FeedScope};
+pub use self::archive::ArchivePeriod;
+pub use self::review::{ReviewAction, ReviewRequest};
@@ -0,0 +1,43 @@
+use serde::Deserialize;
+
+const DEFAULT_PER_PAGE: u64 = 10;
+const MAX_PER_PAGE: u64 = 100;
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Pagination {
+    #[serde(default = "default_page")]
+    pub page: u64,
+    #[serde(default = "default_per_page")]
+    pub per_page: u64,
+}
+
+fn default_page() -> u64 {
+    1
+}
+
+fn default_per_page() -> u64 {
+    DEFAULT_PER_PAGE
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self {
+            page: default_page(),
+            per_page: default_per_page(),
+        }
+    }
+}
+
+impl Pagination {
+    pub fn page(&self) -> u64 {
+        self.page.max(1)
+    }
+
+    pub fn limit(&self) -> u64 {
+        self.per_page.clamp(1, MAX_PER_PAGE)
+    }
+
+    pub fn offset(&self) -> u64 {
+        (self.page() - 1) * self.limit()
+    }
+}
@@ -0,0 +1,60 @@
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::utils::AppError;
+
+pub const MERGE_PATCH_CONTENT_TYPE: &str = "application/merge-patch+json";
+pub const JSON_PATCH_CONTENT_TYPE: &str = "application/json-patch+json";
+
+/// Body of a `PATCH` request, selected by its `Content-Type`.
+#[derive(Debug, Clone)]
+pub enum PatchDocument {
+    /// JSON Merge Patch (RFC 7396).
+    Merge(serde_json::Value),
+    /// JSON Patch (RFC 6902).
+    Json(json_patch::Patch),
+}
+
+impl PatchDocument {
+    pub fn parse(content_type: &str, body: &[u8]) -> Result<Self, AppError> {
+        let mime = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+
+        match mime.as_str() {
+            MERGE_PATCH_CONTENT_TYPE => serde_json::from_slice(body)
+                .map(PatchDocument::Merge)
+                .map_err(|e| AppError::ValidationError(format!("Invalid merge patch: {}", e))),
+            JSON_PATCH_CONTENT_TYPE => serde_json::from_slice(body)
+                .map(PatchDocument::Json)
+                .map_err(|e| AppError::ValidationError(format!("Invalid JSON patch: {}", e))),
+            _ => Err(AppError::UnsupportedMediaType(format!(
+                "Expected {} or {}",
+                MERGE_PATCH_CONTENT_TYPE, JSON_PATCH_CONTENT_TYPE
+            ))),
+        }
+    }
+
+    /// Applies the patch to the JSON representation of `current` and
+    /// deserializes the result back, so type errors surface as validation errors.
+    pub fn apply<T: Serialize + DeserializeOwned>(&self, current: &T) -> Result<T, AppError> {
+        let mut document = serde_json::to_value(current)
+            .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+        match self {
+            PatchDocument::Merge(patch) => json_patch::merge(&mut document, patch),
+            PatchDocument::Json(patch) => json_patch::patch(&mut document, patch)
+                .map_err(|e| AppError::ValidationError(format!("Failed to apply patch: {}", e)))?,
+        }
+
+        serde_json::from_value(document)
+            .map_err(|e| AppError::ValidationError(format!("Invalid patched document: {}", e)))
+    }
+}
+
+/// Returns `after` when it differs from `before`, for writing only changed columns.
+pub fn changed<T: PartialEq + Clone>(before: &T, after: &T) -> Option<T> {
+    (before != after).then(|| after.clone())
+}
@@ -1,22 +1,474 @@
-use serde::Deserialize;
+use std::collections::HashMap;
 
-#[derive(Debug, Deserialize)]
+use sea_orm::prelude::DateTimeWithTimeZone;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{entities::{posts, sea_orm_active_enums::{BodyFormat, PostStatus, PostVisibility}, tags}, utils::{accepted_locales, generate_slug, AppError}};
+
+#[derive(Debug, Clone, Deserialize)]
 pub struct CreatePostRequest {
     pub title: String,
     pub body: String,
+    #[serde(default)]
+    pub body_format: BodyFormat,
+    /// Cover image URL, ignored when `media_id` is set.
+    #[serde(default)]
     pub img: String,
+    pub media_id: Option<i32>,
     pub category_id: i32,
+    /// Author of the post, taken from the token.
+    #[serde(skip)]
     pub user_id: i32,
     pub user_name: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub status: PostStatus,
+    #[serde(default)]
+    pub visibility: PostVisibility,
+    /// Required when `visibility` is `password`.
+    pub password: Option<String>,
+    #[serde(skip)]
+    pub password_hash: Option<String>,
+    /// JSON object checked against the category's metadata schema; defaults to `{}`.
+    pub metadata: Option<Value>,
+    /// BCP 47 language tag, e.g. `en` or `pt-BR`; defaults to `en`.
+    pub locale: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 pub struct UpdatePostRequest {
     pub post_id: Option<i32>,
     pub title: String,
     pub body: String,
+    pub body_format: Option<BodyFormat>,
+    /// Cover image URL, ignored when `media_id` is set.
+    #[serde(default)]
+    pub img: String,
+    pub media_id: Option<i32>,
+    pub category_id: i32,
+    /// New author of the post; only editors may hand a post over to another author.
+    pub user_id: Option<i32>,
+    pub user_name: String,
+    pub tags: Option<Vec<String>>,
+    pub status: Option<PostStatus>,
+    pub visibility: Option<PostVisibility>,
+    /// New password, required when switching to `password` visibility.
+    pub password: Option<String>,
+    #[serde(skip)]
+    pub password_hash: Option<String>,
+    pub metadata: Option<Value>,
+    pub locale: Option<String>,
+    /// Expected version from the `If-Match` header.
+    #[serde(skip)]
+    pub version: Option<i32>,
+    /// User recorded in the transition log when the status changes.
+    #[serde(skip)]
+    pub actor_id: Option<i32>,
+}
+
+/// Patchable representation of a post that `PATCH` bodies are applied to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PostDocument {
+    pub title: String,
+    pub body: String,
+    pub body_format: BodyFormat,
     pub img: String,
+    pub media_id: Option<i32>,
     pub category_id: i32,
     pub user_id: i32,
     pub user_name: String,
-}
\ No newline at end of file
+    pub tags: Vec<String>,
+    pub status: PostStatus,
+    pub visibility: PostVisibility,
+    /// Write-only; set to change the password of a password-protected post.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    pub metadata: Value,
+    pub locale: String,
+}
+
+impl PostDocument {
+    pub fn from_post_and_tags(post: &posts::Model, tags: Vec<tags::Model>) -> Self {
+        PostDocument {
+            title: post.title.clone(),
+            body: post.body.clone(),
+            body_format: post.body_format,
+            img: post.img.clone(),
+            media_id: None,
+            category_id: post.category_id,
+            user_id: post.user_id,
+            user_name: post.user_name.clone(),
+            tags: tags.into_iter().map(|tag| tag.name).collect(),
+            status: post.status,
+            visibility: post.visibility,
+            password: None,
+            metadata: post.metadata.clone(),
+            locale: post.locale.clone(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), AppError> {
+        if self.title.trim().is_empty() {
+            return Err(AppError::ValidationError("title must not be empty".to_string()));
+        }
+
+        if self.body.trim().is_empty() {
+            return Err(AppError::ValidationError("body must not be empty".to_string()));
+        }
+
+        if self.user_name.trim().is_empty() {
+            return Err(AppError::ValidationError("user_name must not be empty".to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Partial post update; `None` fields are left untouched.
+#[derive(Debug, Clone, Default)]
+pub struct PatchPostRequest {
+    pub post_id: i32,
+    pub title: Option<String>,
+    pub body: Option<String>,
+    pub body_format: Option<BodyFormat>,
+    pub img: Option<String>,
+    pub category_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub user_name: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub status: Option<PostStatus>,
+    pub visibility: Option<PostVisibility>,
+    /// Replaces the stored password hash; switching away from `password` visibility clears it.
+    pub password_hash: Option<String>,
+    pub metadata: Option<Value>,
+    pub locale: Option<String>,
+    pub version: Option<i32>,
+    /// User recorded in the transition log when the status changes.
+    pub actor_id: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FeaturePostRequest {
+    /// Featured posts are listed by ascending position.
+    #[serde(default)]
+    pub position: i32,
+    pub expires_at: Option<DateTimeWithTimeZone>,
+}
+
+impl FeaturePostRequest {
+    pub fn validate(&self, now: DateTimeWithTimeZone) -> Result<(), AppError> {
+        if self.position < 0 {
+            return Err(AppError::ValidationError("position must not be negative".to_string()));
+        }
+
+        if self.expires_at.is_some_and(|expires_at| expires_at <= now) {
+            return Err(AppError::ValidationError("expires_at must be in the future".to_string()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Starts a draft translation of a post, copied from the original.
+#[derive(Debug, Deserialize)]
+pub struct CreateTranslationRequest {
+    pub locale: String,
+    /// Defaults to the title of the original post.
+    pub title: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UnlockPostRequest {
+    pub password: String,
+}
+
+/// Posts a viewer may see in listings, decided by the service layer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VisibilityScope {
+    /// `None` when posts of every visibility and status are listed; otherwise only published
+    /// posts with one of these visibilities are.
+    pub visibilities: Option<Vec<PostVisibility>>,
+    /// Author whose own posts are listed whatever their visibility.
+    pub author_id: Option<i32>,
+}
+
+impl VisibilityScope {
+    pub fn public() -> Self {
+        VisibilityScope {
+            visibilities: Some(vec![PostVisibility::Public]),
+            author_id: None,
+        }
+    }
+
+    pub fn unrestricted() -> Self {
+        VisibilityScope {
+            visibilities: None,
+            author_id: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TagMatch {
+    #[default]
+    Any,
+    All,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PostQuery {
+    /// Comma separated list of tag names or slugs, e.g. `?tag=rust,web`.
+    pub tag: Option<String>,
+    #[serde(rename = "match", default)]
+    pub tag_match: TagMatch,
+    /// Lists one category, with its pinned posts first; other listings ignore pins.
+    pub category: Option<i32>,
+    /// Comma separated list of optional fields to return, e.g. `?fields=body,body_html`.
+    pub fields: Option<String>,
+    /// Top-level metadata values to match, from `?meta.<key>=<value>`.
+    #[serde(skip)]
+    pub meta: Vec<(String, String)>,
+    /// Lists posts in one locale, e.g. `?locale=fr`.
+    pub locale: Option<String>,
+    /// Locales from `Accept-Language`, used when `locale` is not given.
+    #[serde(skip)]
+    pub accepted_locales: Vec<String>,
+}
+
+/// Optional post fields; list responses leave out the full body unless asked for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PostFields {
+    pub body: bool,
+    pub body_html: bool,
+}
+
+impl PostQuery {
+    pub fn tag_slugs(&self) -> Vec<String> {
+        let mut slugs: Vec<String> = self
+            .tag
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(generate_slug)
+            .filter(|slug| !slug.is_empty())
+            .collect();
+
+        slugs.sort();
+        slugs.dedup();
+        slugs
+    }
+
+    /// Collects the `meta.<key>=<value>` filters from the raw query parameters.
+    pub fn with_meta(mut self, params: &HashMap<String, String>) -> Result<Self, AppError> {
+        let mut meta = Vec::new();
+
+        for (name, value) in params {
+            let key = match name.strip_prefix("meta.") {
+                Some(key) => key,
+                None => continue,
+            };
+
+            if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+                return Err(AppError::ValidationError(format!("Invalid metadata filter {}", name)));
+            }
+
+            meta.push((key.to_string(), value.clone()));
+        }
+
+        meta.sort();
+        self.meta = meta;
+        Ok(self)
+    }
+
+    pub fn with_accept_language(mut self, header: Option<&str>) -> Self {
+        self.accepted_locales = header.map(accepted_locales).unwrap_or_default();
+        self
+    }
+
+    pub fn fields(&self) -> Result<PostFields, AppError> {
+        let mut fields = PostFields::default();
+
+        for field in self.fields.as_deref().unwrap_or_default().split(',').map(str::trim) {
+            match field {
+                "" => {}
+                "body" => fields.body = true,
+                "body_html" => fields.body_html = true,
+                other => {
+                    return Err(AppError::ValidationError(format!(
+                        "Unknown field {}, expected body or body_html",
+                        other
+                    )))
+                }
+            }
+        }
+
+        Ok(fields)
+    }
+}
+
+/// Related resources to embed in a single post, e.g. `?include=author,category,comments`.
+#[derive(Debug, Default, Deserialize)]
+pub struct PostDetailQuery {
+    pub include: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PostIncludes {
+    pub author: bool,
+    pub category: bool,
+    pub comments: bool,
+}
+
+impl PostDetailQuery {
+    pub fn includes(&self) -> Result<PostIncludes, AppError> {
+        let mut includes = PostIncludes::default();
+
+        for include in self.include.as_deref().unwrap_or_default().split(',').map(str::trim) {
+            match include {
+                "" => {}
+                "author" => includes.author = true,
+                "category" => includes.category = true,
+                "comments" => includes.comments = true,
+                other => {
+                    return Err(AppError::ValidationError(format!(
+                        "Unknown include {}, expected author, category or comments",
+                        other
+                    )))
+                }
+            }
+        }
+
+        Ok(includes)
+    }
+}
+
+const MAX_BULK_POSTS: usize = 100;
+
+/// One change applied to every post of a bulk request, e.g. `{"action": "move_category", "category_id": 2}`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum BulkPostOperation {
+    MoveCategory { category_id: i32 },
+    Publish,
+    Unpublish,
+    Delete,
+    AddTags { tags: Vec<String> },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BulkPostRequest {
+    pub post_ids: Vec<i32>,
+    pub operation: BulkPostOperation,
+}
+
+impl BulkPostRequest {
+    pub fn validate(&self) -> Result<(), AppError> {
+        if self.post_ids.is_empty() || self.post_ids.len() > MAX_BULK_POSTS {
+            return Err(AppError::ValidationError(format!(
+                "post_ids must contain between 1 and {} posts",
+                MAX_BULK_POSTS
+            )));
+        }
+
+        let mut post_ids = self.post_ids.clone();
+        post_ids.sort_unstable();
+        if post_ids.windows(2).any(|pair| pair[0] == pair[1]) {
+            return Err(AppError::ValidationError("post_ids must not contain duplicates".to_string()));
+        }
+
+        if let BulkPostOperation::AddTags { tags } = &self.operation {
+            if tags.iter().all(|tag| generate_slug(tag.trim()).is_empty()) {
+                return Err(AppError::ValidationError("tags must contain at least one tag".to_string()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bulk(post_ids: Vec<i32>, operation: BulkPostOperation) -> BulkPostRequest {
+        BulkPostRequest { post_ids, operation }
+    }
+
+    #[test]
+    fn bulk_accepts_distinct_posts() {
+        assert!(bulk(vec![1, 2, 3], BulkPostOperation::Publish).validate().is_ok());
+        assert!(bulk(vec![1], BulkPostOperation::AddTags { tags: vec!["rust".to_string()] }).validate().is_ok());
+    }
+
+    #[test]
+    fn bulk_rejects_empty_and_oversized_requests() {
+        assert!(matches!(bulk(vec![], BulkPostOperation::Delete).validate(), Err(AppError::ValidationError(_))));
+
+        let post_ids = (1..=MAX_BULK_POSTS as i32 + 1).collect();
+        assert!(matches!(bulk(post_ids, BulkPostOperation::Delete).validate(), Err(AppError::ValidationError(_))));
+    }
+
+    #[test]
+    fn bulk_rejects_duplicate_posts() {
+        assert!(matches!(bulk(vec![1, 2, 1], BulkPostOperation::Unpublish).validate(), Err(AppError::ValidationError(_))));
+    }
+
+    #[test]
+    fn bulk_rejects_blank_tags() {
+        let operation = BulkPostOperation::AddTags { tags: vec![" ".to_string(), "!!".to_string()] };
+
+        assert!(matches!(bulk(vec![1], operation).validate(), Err(AppError::ValidationError(_))));
+    }
+
+    fn query(fields: &str) -> PostQuery {
+        PostQuery {
+            fields: Some(fields.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn fields_default_to_none() {
+        assert_eq!(PostQuery::default().fields().ok(), Some(PostFields::default()));
+    }
+
+    #[test]
+    fn fields_parse_known_names() {
+        let fields = query(" body , body_html,").fields().ok();
+
+        assert_eq!(fields, Some(PostFields { body: true, body_html: true }));
+    }
+
+    #[test]
+    fn fields_reject_unknown_names() {
+        assert!(matches!(query("body,author").fields(), Err(AppError::ValidationError(_))));
+    }
+
+    fn params(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect()
+    }
+
+    #[test]
+    fn meta_collects_prefixed_params_in_order() {
+        let query = PostQuery::default()
+            .with_meta(&params(&[("meta.level", "beginner"), ("tag", "rust"), ("meta.duration_min", "10")]))
+            .ok()
+            .map(|query| query.meta);
+
+        assert_eq!(query, Some(vec![
+            ("duration_min".to_string(), "10".to_string()),
+            ("level".to_string(), "beginner".to_string()),
+        ]));
+    }
+
+    #[test]
+    fn meta_rejects_invalid_keys() {
+        assert!(matches!(PostQuery::default().with_meta(&params(&[("meta.", "x")])), Err(AppError::ValidationError(_))));
+        assert!(matches!(PostQuery::default().with_meta(&params(&[("meta.a'b", "x")])), Err(AppError::ValidationError(_))));
+    }
+}
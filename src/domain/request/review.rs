@@ -0,0 +1,35 @@
+use serde::Deserialize;
+
+use crate::utils::AppError;
+
+const MAX_REVIEW_NOTE: usize = 2000;
+
+/// Status changes made through the review endpoints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewAction {
+    Submit,
+    Approve,
+    RequestChanges,
+}
+
+/// Body of the review endpoints; requesting changes requires a note.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ReviewRequest {
+    pub note: Option<String>,
+}
+
+impl ReviewRequest {
+    /// The trimmed note, `None` when blank.
+    pub fn note(&self, required: bool) -> Result<Option<String>, AppError> {
+        let note = self.note.as_deref().map(str::trim).filter(|note| !note.is_empty());
+
+        match note {
+            None if required => Err(AppError::ValidationError("note is required".to_string())),
+            Some(note) if note.chars().count() > MAX_REVIEW_NOTE => Err(AppError::ValidationError(format!(
+                "note must not exceed {} characters",
+                MAX_REVIEW_NOTE
+            ))),
+            note => Ok(note.map(str::to_string)),
+        }
+    }
+}
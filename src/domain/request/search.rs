@@ -0,0 +1,56 @@
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct SearchQuery {
+    pub q: String,
+    /// Also search comment bodies, `?comments=true`.
+    #[serde(default)]
+    pub comments: bool,
+}
+
+impl SearchQuery {
+    /// Turns the user query into a `to_tsquery` expression.
+    ///
+    /// Quoted text becomes a phrase (`"actix web"` -> `actix <-> web`), a
+    /// trailing `*` enables prefix matching (`sea*` -> `sea:*`) and every
+    /// other word is AND-ed. Anything that is not alphanumeric is dropped so
+    /// the result is always a valid tsquery.
+    pub fn to_tsquery(&self) -> Option<String> {
+        let mut terms = Vec::new();
+
+        for (index, part) in self.q.split('"').enumerate() {
+            let in_phrase = index % 2 == 1;
+
+            if in_phrase {
+                let words: Vec<String> = part.split_whitespace().filter_map(clean_word).collect();
+                if !words.is_empty() {
+                    terms.push(format!("({})", words.join(" <-> ")));
+                }
+                continue;
+            }
+
+            for word in part.split_whitespace() {
+                let prefix = word.ends_with('*');
+                if let Some(word) = clean_word(word) {
+                    terms.push(if prefix { format!("{}:*", word) } else { word });
+                }
+            }
+        }
+
+        if terms.is_empty() {
+            None
+        } else {
+            Some(terms.join(" & "))
+        }
+    }
+}
+
+fn clean_word(word: &str) -> Option<String> {
+    let word: String = word.chars().filter(|c| c.is_alphanumeric()).collect();
+
+    if word.is_empty() {
+        None
+    } else {
+        Some(word.to_lowercase())
+    }
+}
@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+
+use serde::Deserialize;
+
+use crate::utils::AppError;
+
+const MAX_SERIES_TITLE: usize = 255;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateSeriesRequest {
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    /// Parts of the series, in reading order.
+    #[serde(default)]
+    pub post_ids: Vec<i32>,
+}
+
+impl CreateSeriesRequest {
+    pub fn validate(&self) -> Result<(), AppError> {
+        let title = self.title.trim();
+
+        if title.is_empty() || title.chars().count() > MAX_SERIES_TITLE {
+            return Err(AppError::ValidationError(format!(
+                "title must be between 1 and {} characters",
+                MAX_SERIES_TITLE
+            )));
+        }
+
+        validate_post_ids(&self.post_ids)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReorderSeriesRequest {
+    /// The full list of parts in their new order; posts left out are removed from the series.
+    pub post_ids: Vec<i32>,
+}
+
+impl ReorderSeriesRequest {
+    pub fn validate(&self) -> Result<(), AppError> {
+        validate_post_ids(&self.post_ids)
+    }
+}
+
+fn validate_post_ids(post_ids: &[i32]) -> Result<(), AppError> {
+    let mut seen = HashSet::new();
+
+    if let Some(post_id) = post_ids.iter().find(|post_id| !seen.insert(**post_id)) {
+        return Err(AppError::ValidationError(format!(
+            "post {} is listed more than once",
+            post_id
+        )));
+    }
+
+    Ok(())
+}
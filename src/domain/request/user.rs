@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::{entities::users, utils::AppError};
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CreateUserRequest {
     pub firstname: String,
@@ -16,5 +18,66 @@ pub struct UpdateUserRequest {
    
     pub email: Option<String>, // Option since it might not be changed
     pub password: Option<String>,
+    /// Expected version from the `If-Match` header.
+    #[serde(skip)]
+    pub version: Option<i32>,
+}
+
+/// Patchable representation of a user that `PATCH` bodies are applied to.
+///
+/// The password is deliberately not part of it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UserDocument {
+    pub firstname: String,
+    pub lastname: String,
+    pub email: String,
+}
+
+impl UserDocument {
+    pub fn validate(&self) -> Result<(), AppError> {
+        if self.firstname.trim().is_empty() || self.lastname.trim().is_empty() {
+            return Err(AppError::ValidationError("firstname and lastname must not be empty".to_string()));
+        }
+
+        validate_email(&self.email)
+    }
 }
 
+/// Checks the shape of an email address, shared by registration and profile updates.
+pub(super) fn validate_email(email: &str) -> Result<(), AppError> {
+    let invalid = || AppError::ValidationError("email must be a valid address".to_string());
+
+    if email.len() > 254 || email.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(invalid());
+    }
+
+    let (local, domain) = email.split_once('@').ok_or_else(invalid)?;
+
+    if local.is_empty() || local.len() > 64 || domain.contains('@') {
+        return Err(invalid());
+    }
+
+    let labels_valid = domain.split('.').all(|label| {
+        !label.is_empty()
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+    });
+
+    if !domain.contains('.') || !labels_valid {
+        return Err(invalid());
+    }
+
+    Ok(())
+}
+
+impl From<&users::Model> for UserDocument {
+    fn from(user: &users::Model) -> Self {
+        UserDocument {
+            firstname: user.firstname.clone(),
+            lastname: user.lastname.clone(),
+            email: user.email.clone(),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use chrono::{Duration, NaiveDate};
+use serde::Deserialize;
+
+use crate::utils::AppError;
+
+const DEFAULT_RANGE_DAYS: i64 = 30;
+const MAX_RANGE_DAYS: i64 = 366;
+
+/// Inclusive date range for view statistics, e.g. `?from=2024-11-01&to=2024-11-30`.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct ViewStatsQuery {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+impl ViewStatsQuery {
+    /// Resolves the range, defaulting to the last 30 days up to `today`.
+    pub fn range(&self, today: NaiveDate) -> Result<(NaiveDate, NaiveDate), AppError> {
+        let to = self.to.unwrap_or(today);
+        let from = self
+            .from
+            .unwrap_or(to - Duration::days(DEFAULT_RANGE_DAYS - 1));
+
+        if from > to {
+            return Err(AppError::ValidationError("from must not be after to".to_string()));
+        }
+
+        if (to - from).num_days() >= MAX_RANGE_DAYS {
+            return Err(AppError::ValidationError(format!(
+                "Date range must not exceed {} days",
+                MAX_RANGE_DAYS
+            )));
+        }
+
+        Ok((from, to))
+    }
+}
@@ -0,0 +1,40 @@
+use sea_orm::FromQueryResult;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize, FromQueryResult)]
+pub struct ArchiveMonthResponse {
+    #[serde(skip)]
+    pub year: i32,
+    pub month: i32,
+    pub count: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ArchiveYearResponse {
+    pub year: i32,
+    pub count: i64,
+    pub months: Vec<ArchiveMonthResponse>,
+}
+
+impl ArchiveYearResponse {
+    /// Groups month counts, ordered newest first, into years.
+    pub fn group(months: Vec<ArchiveMonthResponse>) -> Vec<Self> {
+        let mut years: Vec<ArchiveYearResponse> = Vec::new();
+
+        for month in months {
+            match years.last_mut() {
+                Some(year) if year.year == month.year => {
+                    year.count += month.count;
+                    year.months.push(month);
+                }
+                _ => years.push(ArchiveYearResponse {
+                    year: month.year,
+                    count: month.count,
+                    months: vec![month],
+                }),
+            }
+        }
+
+        years
+    }
+}
@@ -0,0 +1,52 @@
+use serde::Serialize;
+
+use crate::entities::{bookmarks, reading_lists};
+
+use super::{PaginatedResponse, PostResponse};
+
+#[derive(Debug, Serialize)]
+pub struct BookmarkResponse {
+    pub post: PostResponse,
+    pub reading_list_id: Option<i32>,
+    pub created_at: String,
+}
+
+impl BookmarkResponse {
+    pub fn new(bookmark: bookmarks::Model, post: PostResponse) -> Self {
+        BookmarkResponse {
+            post,
+            reading_list_id: bookmark.reading_list_id,
+            created_at: bookmark.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReadingListResponse {
+    pub id: i32,
+    pub name: String,
+    /// Token for the public `/api/shared/reading-lists/{token}` link, if shared.
+    pub share_token: Option<String>,
+    pub bookmark_count: i64,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl ReadingListResponse {
+    pub fn new(list: reading_lists::Model, bookmark_count: i64) -> Self {
+        ReadingListResponse {
+            id: list.id,
+            name: list.name,
+            share_token: list.share_token,
+            bookmark_count,
+            created_at: list.created_at.to_rfc3339(),
+            updated_at: list.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SharedReadingListResponse {
+    pub name: String,
+    pub posts: PaginatedResponse<PostResponse>,
+}
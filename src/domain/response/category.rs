@@ -1,11 +1,17 @@
 use crate::entities::categories;
 use serde::Serialize;
+use serde_json::Value;
 
 #[derive(Debug, Serialize)]
 #[allow(non_snake_case)]
 pub struct CategoryResponse {
     pub id: i32,
     pub name: String,
+    /// JSON Schema that the metadata of posts in this category must satisfy.
+    pub metadata_schema: Option<Value>,
+    pub version: i32,
+    pub created_at: String,
+    pub updated_at: String,
 }
 
 impl From<categories::Model> for CategoryResponse {
@@ -13,6 +19,10 @@ impl From<categories::Model> for CategoryResponse {
         CategoryResponse {
             id: category.id,
             name: category.name,
+            metadata_schema: category.metadata_schema,
+            version: category.version,
+            created_at: category.created_at.to_rfc3339(),
+            updated_at: category.updated_at.to_rfc3339(),
         }
     }
 }
@@ -1,14 +1,20 @@
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 
 
 use crate::entities::comments;
 
-#[derive(Debug, Deserialize, Serialize)]
+use super::ReactionResponse;
+
+#[derive(Debug, Serialize)]
 pub struct CommentResponse {
     pub id: i32,
     pub id_post_comment: i32,
     pub user_name_comment: String,
     pub comment: String,
+    pub reactions: Vec<ReactionResponse>,
+    pub version: i32,
+    pub created_at: String,
+    pub updated_at: String,
 }
 
 impl From<comments::Model> for CommentResponse {
@@ -18,6 +24,10 @@ impl From<comments::Model> for CommentResponse {
             id_post_comment: comment.id_post_comment,
             user_name_comment: comment.user_name_comment,
             comment: comment.comment,
+            reactions: Vec::new(),
+            version: comment.version,
+            created_at: comment.created_at.to_rfc3339(),
+            updated_at: comment.updated_at.to_rfc3339(),
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,22 @@
+use serde::Serialize;
+
+use crate::entities::media;
+
+#[derive(Debug, Serialize)]
+pub struct MediaResponse {
+    pub id: i32,
+    pub url: String,
+    pub content_type: String,
+    pub size: i64,
+}
+
+impl MediaResponse {
+    pub fn from_media(media: media::Model, url: String) -> Self {
+        MediaResponse {
+            id: media.id,
+            url,
+            content_type: media.content_type,
+            size: media.size,
+        }
+    }
+}
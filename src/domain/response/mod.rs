@@ -1,21 +1,59 @@
+use actix_web::{http::StatusCode, HttpResponse, ResponseError};
 use serde::Serialize;
 use core::fmt;
 use std::fmt::Formatter;
+use sea_orm::DbErr;
 
 mod category;
 mod post;
 mod comment;
 mod user;
+mod tag;
+mod pagination;
+mod search;
+mod media;
+mod trash;
+mod views;
+mod reaction;
+mod bookmark;
+mod series;
+mod xml;
+mod sitemap;
+mod archive;
+mod review;
+mod post_lock;
 
 use crate::utils::AppError;
 
 pub use self::category::CategoryResponse;
 pub use self::post::{
     PostResponse,
-    PostRelationResponse
+    PostDetailResponse,
+    PostTranslationResponse,
+    BulkPostResult,
+    BulkPostOutcome,
+    PostWrite,
+    BulkPostResponse,
+    UnlockPostResponse,
+    FeaturedPostResponse,
+    RankedPostResponse
 };
 pub use self::comment::CommentResponse;
 pub use self::user::UserResponse;
+pub use self::tag::TagResponse;
+pub use self::pagination::PaginatedResponse;
+pub use self::search::SearchHitResponse;
+pub use self::media::MediaResponse;
+pub use self::trash::TrashItemResponse;
+pub use self::views::{DailyViewsResponse, ViewStatsResponse};
+pub use self::reaction::ReactionResponse;
+pub use self::bookmark::{BookmarkResponse, ReadingListResponse, SharedReadingListResponse};
+pub use self::series::{SeriesNavigationResponse, SeriesPartResponse, SeriesResponse};
+pub use self::xml::XmlDocument;
+pub use self::sitemap::{SitemapEntry, SitemapFingerprint};
+pub use self::archive::{ArchiveMonthResponse, ArchiveYearResponse};
+pub use self::review::PostTransitionResponse;
+pub use self::post_lock::PostLockResponse;
 
 
 #[derive(Debug, Serialize)]
@@ -38,22 +76,45 @@ impl<T: Serialize> fmt::Display for ApiResponse<T> {
 pub struct ErrorResponse {
     pub status: String,
     pub message: String,
+    #[serde(skip)]
+    pub status_code: u16,
 }
 
 impl From<AppError> for ErrorResponse {
     fn from(error: AppError) -> Self {
-        let (status, message) = match error {
-            AppError::DbError(_) => ("error".to_string(), "Database error occurred".to_string()),
-            AppError::HashingError(_) => ("error".to_string(), "Error during password hashing".to_string()),
-            AppError::NotFound(ref msg) => ("error".to_string(), msg.clone()),
-            AppError::TokenExpiredError => ("error".to_string(), "Token has expired".to_string()),
-            AppError::TokenValidationError => ("error".to_string(), "Token validation failed".to_string()),
-            AppError::TokenGenerationError(_) => ("error".to_string(), "Token generation failed".to_string()),
-            AppError::BcryptError(ref msg) => ("error".to_string(), format!("Bcrypt error: {}", msg)),
-            AppError::InvalidCredentials => ("error".to_string(), "Invalid credentials".to_string()),
-            AppError::EmailAlreadyExists => ("error".to_string(), "Email already exists".to_string()),
+        let (status, message, status_code) = match error {
+            AppError::DbError(DbErr::RecordNotUpdated) => ("fail".to_string(), "Resource has been modified, reload and retry".to_string(), 412),
+            AppError::DbError(DbErr::RecordNotFound(ref msg)) => ("error".to_string(), msg.clone(), 404),
+            AppError::DbError(_) => ("error".to_string(), "Database error occurred".to_string(), 500),
+            AppError::HashingError(_) => ("error".to_string(), "Error during password hashing".to_string(), 500),
+            AppError::NotFound(ref msg) => ("error".to_string(), msg.clone(), 404),
+            AppError::TokenExpiredError => ("error".to_string(), "Token has expired".to_string(), 401),
+            AppError::TokenValidationError => ("error".to_string(), "Token validation failed".to_string(), 401),
+            AppError::TokenGenerationError(_) => ("error".to_string(), "Token generation failed".to_string(), 500),
+            AppError::BcryptError(ref msg) => ("error".to_string(), format!("Bcrypt error: {}", msg), 500),
+            AppError::Unauthorized(ref msg) => ("fail".to_string(), msg.clone(), 401),
+            AppError::Forbidden(ref msg) => ("fail".to_string(), msg.clone(), 403),
+            AppError::InvalidCredentials => ("error".to_string(), "Invalid credentials".to_string(), 401),
+            AppError::EmailAlreadyExists => ("error".to_string(), "Email already exists".to_string(), 409),
+            AppError::ValidationError(ref msg) => ("fail".to_string(), msg.clone(), 400),
+            AppError::PayloadTooLarge(ref msg) => ("fail".to_string(), msg.clone(), 413),
+            AppError::UnsupportedMediaType(ref msg) => ("fail".to_string(), msg.clone(), 415),
+            AppError::Conflict(ref msg) => ("fail".to_string(), msg.clone(), 409),
+            AppError::PreconditionFailed(ref msg) => ("fail".to_string(), msg.clone(), 412),
+            AppError::Locked(ref msg) => ("fail".to_string(), msg.clone(), 423),
+            AppError::StorageError(_) => ("error".to_string(), "Storage error occurred".to_string(), 500),
         };
-        ErrorResponse { status, message }
+        ErrorResponse { status, message, status_code }
+    }
+}
+
+impl ResponseError for ErrorResponse {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(self.status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(self)
     }
 }
 
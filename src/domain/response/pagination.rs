@@ -0,0 +1,22 @@
+use serde::Serialize;
+
+use crate::domain::Pagination;
+
+#[derive(Debug, Serialize)]
+pub struct PaginatedResponse<T> {
+    pub items: Vec<T>,
+    pub page: u64,
+    pub per_page: u64,
+    pub total: u64,
+}
+
+impl<T> PaginatedResponse<T> {
+    pub fn new(items: Vec<T>, pagination: &Pagination, total: u64) -> Self {
+        PaginatedResponse {
+            items,
+            page: pagination.page(),
+            per_page: pagination.limit(),
+            total,
+        }
+    }
+}
@@ -1,15 +1,61 @@
 use serde::Serialize;
+use serde_json::Value;
 
-use crate::entities::{comments, posts};
+use crate::{domain::PostFields, entities::{featured_posts, post_locks, posts, sea_orm_active_enums::{BodyFormat, PostStatus, PostVisibility}, tags}};
+
+use super::{CategoryResponse, CommentResponse, PaginatedResponse, ReactionResponse, SeriesNavigationResponse, UserResponse};
 
 #[derive(Debug, Serialize)]
 pub struct PostResponse {
     pub id: i32,
     pub title: String,
-    pub body: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    pub body_format: BodyFormat,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body_html: Option<String>,
+    /// Plain-text summary stored when the body is saved.
+    pub excerpt: String,
+    pub word_count: i32,
+    pub reading_time_minutes: i32,
     pub category_id: i32,
     pub user_id: i32,
     pub user_name: String,
+    pub status: PostStatus,
+    pub visibility: PostVisibility,
+    /// Whether the post is pinned to the top of its category.
+    pub pinned: bool,
+    pub metadata: Value,
+    pub locale: String,
+    /// Shared by all translations of the post; `None` until the first translation is created.
+    pub translation_group_id: Option<i32>,
+    pub tags: Vec<String>,
+    pub reactions: Vec<ReactionResponse>,
+    /// Whether the requesting user has bookmarked the post; always false when unauthenticated.
+    pub bookmarked: bool,
+    /// Previous/next part and position when the post belongs to a series.
+    pub series: Option<SeriesNavigationResponse>,
+    pub version: i32,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl PostResponse {
+    pub fn from_post_and_tags(post: posts::Model, tags: Vec<tags::Model>) -> Self {
+        PostResponse {
+            tags: tags.into_iter().map(|tag| tag.name).collect(),
+            ..PostResponse::from(post)
+        }
+    }
+
+    /// Drops the body fields that were not requested through `?fields=`.
+    pub fn with_fields(self, fields: &PostFields) -> Self {
+        PostResponse {
+            body: self.body.filter(|_| fields.body),
+            body_html: self.body_html.filter(|_| fields.body_html),
+            ..self
+        }
+    }
 }
 
 impl From<posts::Model> for PostResponse {
@@ -17,33 +63,155 @@ impl From<posts::Model> for PostResponse {
         PostResponse {
             id: post.id,
             title: post.title,
-            body: post.body,
+            body: Some(post.body),
+            body_format: post.body_format,
+            body_html: Some(post.body_html),
+            excerpt: post.excerpt,
+            word_count: post.word_count,
+            reading_time_minutes: post.reading_time_minutes,
             category_id: post.category_id,
             user_id: post.user_id,
             user_name: post.user_name,
+            status: post.status,
+            visibility: post.visibility,
+            pinned: post.pinned_at.is_some(),
+            metadata: post.metadata,
+            locale: post.locale,
+            translation_group_id: post.translation_group_id,
+            tags: Vec::new(),
+            reactions: Vec::new(),
+            bookmarked: false,
+            series: None,
+            version: post.version,
+            created_at: post.created_at.to_rfc3339(),
+            updated_at: post.updated_at.to_rfc3339(),
         }
     }
 }
 
+/// A post with the related resources requested through `?include=`.
 #[derive(Debug, Serialize)]
-pub struct PostRelationResponse {
-    pub post_id: i32,
+pub struct PostDetailResponse {
+    #[serde(flatten)]
+    pub post: PostResponse,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<UserResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<CategoryResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments: Option<PaginatedResponse<CommentResponse>>,
+    /// Other translations of the post the viewer may read.
+    pub translations: Vec<PostTranslationResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PostTranslationResponse {
+    pub id: i32,
+    pub locale: String,
     pub title: String,
-    pub comment_id: i32,
-    pub id_post_comment: i32,
-    pub user_name_comment: String,
-    pub comment: String,
-}
-
-impl PostRelationResponse {
-    pub fn from_post_and_comment(post: &posts::Model, comment: &comments::Model) -> Self {
-        PostRelationResponse {
-            post_id: post.id,
-            title: post.title.clone(),
-            comment_id: comment.id,
-            id_post_comment: comment.id_post_comment,
-            user_name_comment: comment.user_name_comment.clone(),
-            comment: comment.comment.clone(),
+    pub status: PostStatus,
+}
+
+impl From<posts::Model> for PostTranslationResponse {
+    fn from(post: posts::Model) -> Self {
+        PostTranslationResponse {
+            id: post.id,
+            locale: post.locale,
+            title: post.title,
+            status: post.status,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct FeaturedPostResponse {
+    #[serde(flatten)]
+    pub post: PostResponse,
+    pub position: i32,
+    pub expires_at: Option<String>,
+}
+
+impl FeaturedPostResponse {
+    pub fn new(featured: featured_posts::Model, post: PostResponse) -> Self {
+        FeaturedPostResponse {
+            post,
+            position: featured.position,
+            expires_at: featured.expires_at.map(|expires_at| expires_at.to_rfc3339()),
+        }
+    }
+}
+
+/// A trending or related post with the score it was ranked by.
+#[derive(Debug, Serialize)]
+pub struct RankedPostResponse {
+    #[serde(flatten)]
+    pub post: PostResponse,
+    pub score: f64,
+}
+
+/// Token to send in the `X-Post-Token` header to read a password-protected post.
+#[derive(Debug, Serialize)]
+pub struct UnlockPostResponse {
+    pub post_id: i32,
+    pub token: String,
+    pub expires_at: String,
+}
+
+/// Outcome of a bulk operation for one post.
+#[derive(Debug, Serialize)]
+pub struct BulkPostResult {
+    pub post_id: i32,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl BulkPostResult {
+    pub fn success(post_id: i32) -> Self {
+        BulkPostResult { post_id, success: true, error: None }
+    }
+
+    pub fn failure(post_id: i32, error: &str) -> Self {
+        BulkPostResult { post_id, success: false, error: Some(error.to_string()) }
+    }
+}
+
+/// Outcome of a save of a post, which is refused while another user holds its edit lock.
+#[derive(Debug)]
+pub enum PostWrite<T> {
+    Saved(T),
+    Locked(post_locks::Model),
+}
+
+/// What a bulk operation did to one post, before the service reports it as a [`BulkPostResult`].
+#[derive(Debug)]
+pub enum BulkPostOutcome {
+    Updated,
+    NotFound,
+    /// The post belongs to another author and the actor is not an editor.
+    Forbidden,
+    /// Another user holds the edit lock on the post.
+    Locked(post_locks::Model),
+    /// The post does not satisfy the metadata schema of its new category.
+    Invalid(String),
+}
+
+#[derive(Debug, Serialize)]
+pub struct BulkPostResponse {
+    pub succeeded: usize,
+    pub failed: usize,
+    /// One result per requested post, in request order.
+    pub results: Vec<BulkPostResult>,
+}
+
+impl BulkPostResponse {
+    pub fn new(results: Vec<BulkPostResult>) -> Self {
+        let succeeded = results.iter().filter(|result| result.success).count();
+
+        BulkPostResponse {
+            succeeded,
+            failed: results.len() - succeeded,
+            results,
         }
     }
 }
@@ -0,0 +1,24 @@
+use serde::Serialize;
+
+use crate::entities::post_locks;
+
+#[derive(Debug, Serialize)]
+pub struct PostLockResponse {
+    pub post_id: i32,
+    /// The user holding the lock.
+    pub user_id: i32,
+    pub acquired_at: String,
+    /// The lock is released at this time unless renewed by a heartbeat.
+    pub expires_at: String,
+}
+
+impl From<post_locks::Model> for PostLockResponse {
+    fn from(lock: post_locks::Model) -> Self {
+        PostLockResponse {
+            post_id: lock.post_id,
+            user_id: lock.user_id,
+            acquired_at: lock.acquired_at.to_rfc3339(),
+            expires_at: lock.expires_at.to_rfc3339(),
+        }
+    }
+}
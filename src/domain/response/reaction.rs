@@ -0,0 +1,9 @@
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReactionResponse {
+    pub kind: String,
+    pub count: i64,
+    /// Whether the requesting user has reacted with this kind; always false when unauthenticated.
+    pub reacted: bool,
+}
@@ -0,0 +1,29 @@
+use serde::Serialize;
+
+use crate::entities::{post_transitions, sea_orm_active_enums::PostStatus};
+
+#[derive(Debug, Serialize)]
+pub struct PostTransitionResponse {
+    pub id: i32,
+    pub post_id: i32,
+    pub from_status: PostStatus,
+    pub to_status: PostStatus,
+    /// `None` once the user who made the change is deleted.
+    pub actor_id: Option<i32>,
+    pub note: Option<String>,
+    pub created_at: String,
+}
+
+impl From<post_transitions::Model> for PostTransitionResponse {
+    fn from(transition: post_transitions::Model) -> Self {
+        PostTransitionResponse {
+            id: transition.id,
+            post_id: transition.post_id,
+            from_status: transition.from_status,
+            to_status: transition.to_status,
+            actor_id: transition.actor_id,
+            note: transition.note,
+            created_at: transition.created_at.to_rfc3339(),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use sea_orm::FromQueryResult;
+use serde::Serialize;
+
+#[derive(Debug, Serialize, FromQueryResult)]
+pub struct SearchHitResponse {
+    /// Either `post` or `comment`.
+    pub kind: String,
+    pub post_id: i32,
+    pub comment_id: Option<i32>,
+    pub title: String,
+    pub snippet: String,
+    pub rank: f32,
+}
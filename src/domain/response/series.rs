@@ -0,0 +1,69 @@
+use serde::Serialize;
+
+use crate::entities::series;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SeriesPartResponse {
+    pub post_id: i32,
+    pub title: String,
+    /// 1-based position of the part within the series.
+    pub position: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SeriesResponse {
+    pub id: i32,
+    pub title: String,
+    pub description: String,
+    pub user_id: i32,
+    pub parts: Vec<SeriesPartResponse>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl SeriesResponse {
+    pub fn new(series: series::Model, parts: Vec<SeriesPartResponse>) -> Self {
+        SeriesResponse {
+            id: series.id,
+            title: series.title,
+            description: series.description,
+            user_id: series.user_id,
+            parts,
+            created_at: series.created_at.to_rfc3339(),
+            updated_at: series.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+/// Where a post sits in its series, embedded in `PostResponse`.
+#[derive(Debug, Clone, Serialize)]
+pub struct SeriesNavigationResponse {
+    pub id: i32,
+    pub title: String,
+    /// Part N of `total`, 1-based.
+    pub position: usize,
+    pub total: usize,
+    pub previous: Option<SeriesPartResponse>,
+    pub next: Option<SeriesPartResponse>,
+}
+
+impl SeriesNavigationResponse {
+    /// Navigation for every part of the series, keyed by post id.
+    pub fn for_parts(series: &series::Model, parts: &[SeriesPartResponse]) -> Vec<(i32, Self)> {
+        parts
+            .iter()
+            .enumerate()
+            .map(|(index, part)| {
+                let navigation = SeriesNavigationResponse {
+                    id: series.id,
+                    title: series.title.clone(),
+                    position: part.position,
+                    total: parts.len(),
+                    previous: index.checked_sub(1).map(|previous| parts[previous].clone()),
+                    next: parts.get(index + 1).cloned(),
+                };
+                (part.post_id, navigation)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,15 @@
+use sea_orm::{prelude::DateTimeWithTimeZone, FromQueryResult};
+
+/// A post, category or author page listed in the sitemap.
+#[derive(Debug, Clone, FromQueryResult)]
+pub struct SitemapEntry {
+    pub id: i32,
+    pub last_modified: DateTimeWithTimeZone,
+}
+
+/// Row counts and latest updates of the content in the sitemap; when it changes the sitemap is rebuilt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SitemapFingerprint {
+    pub posts: (i64, Option<DateTimeWithTimeZone>),
+    pub categories: (i64, Option<DateTimeWithTimeZone>),
+}
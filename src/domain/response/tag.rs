@@ -0,0 +1,10 @@
+use sea_orm::FromQueryResult;
+use serde::Serialize;
+
+#[derive(Debug, Serialize, FromQueryResult)]
+pub struct TagResponse {
+    pub id: i32,
+    pub name: String,
+    pub slug: String,
+    pub post_count: i64,
+}
@@ -0,0 +1,23 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use serde::Serialize;
+
+use crate::domain::TrashKind;
+
+#[derive(Debug, Serialize)]
+pub struct TrashItemResponse {
+    pub kind: TrashKind,
+    pub id: i32,
+    pub label: String,
+    pub deleted_at: String,
+}
+
+impl TrashItemResponse {
+    pub fn new(kind: TrashKind, id: i32, label: String, deleted_at: Option<DateTimeWithTimeZone>) -> Self {
+        TrashItemResponse {
+            kind,
+            id,
+            label,
+            deleted_at: deleted_at.map(|at| at.to_rfc3339()).unwrap_or_default(),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::entities::users;
+use crate::entities::{sea_orm_active_enums::UserRole, users};
 
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -9,6 +9,10 @@ pub struct UserResponse {
     pub firstname: String,
     pub lastname: String,
     pub email: String,
+    pub role: UserRole,
+    pub created_at: String,
+    pub updated_at: String,
+    pub version: i32,
 }
 
 impl From<users::Model> for UserResponse {
@@ -18,6 +22,10 @@ impl From<users::Model> for UserResponse {
             firstname: user.firstname,
             lastname: user.lastname,
             email: user.email,
+            role: user.role,
+            created_at: user.created_at.to_rfc3339(),
+            updated_at: user.updated_at.to_rfc3339(),
+            version: user.version,
         }
     }
 }
\ No newline at end of file
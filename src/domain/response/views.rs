@@ -0,0 +1,42 @@
+use chrono::NaiveDate;
+use sea_orm::FromQueryResult;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize, FromQueryResult)]
+pub struct DailyViewsResponse {
+    pub day: NaiveDate,
+    pub views: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ViewStatsResponse {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub total: i64,
+    /// One entry per day in the range, including days without views.
+    pub series: Vec<DailyViewsResponse>,
+}
+
+impl ViewStatsResponse {
+    /// Builds a gap-free series from the days that have views.
+    pub fn new(from: NaiveDate, to: NaiveDate, days: Vec<DailyViewsResponse>) -> Self {
+        let series: Vec<DailyViewsResponse> = from
+            .iter_days()
+            .take_while(|day| *day <= to)
+            .map(|day| DailyViewsResponse {
+                day,
+                views: days
+                    .iter()
+                    .find(|stat| stat.day == day)
+                    .map_or(0, |stat| stat.views),
+            })
+            .collect();
+
+        ViewStatsResponse {
+            from,
+            to,
+            total: series.iter().map(|stat| stat.views).sum(),
+            series,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+
+/// A rendered XML document, such as a feed or sitemap, with the validators used for conditional requests.
+#[derive(Debug, Clone)]
+pub struct XmlDocument {
+    pub body: String,
+    pub content_type: &'static str,
+    pub etag: String,
+    /// Latest update among the listed content; `None` when it is empty.
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+impl XmlDocument {
+    /// Wraps a rendered body, tagging it with a hash of its content.
+    pub fn new(body: String, content_type: &'static str, last_modified: Option<DateTime<Utc>>) -> Self {
+        XmlDocument {
+            etag: format!("\"{:x}\"", Sha256::digest(body.as_bytes())),
+            body,
+            content_type,
+            last_modified,
+        }
+    }
+}
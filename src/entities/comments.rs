@@ -1,6 +1,6 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.0
 
-use sea_orm::entity::prelude::*;
+use sea_orm::{entity::prelude::*, Set};
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
 #[sea_orm(table_name = "comments")]
@@ -10,10 +10,17 @@ pub struct Model {
     pub id_post_comment: i32,
     pub user_name_comment: String,
     pub comment: String,
+    pub deleted_at: Option<DateTimeWithTimeZone>,
+    pub deleted_by: Option<i32>,
+    pub version: i32,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::comment_reactions::Entity")]
+    CommentReactions,
     #[sea_orm(
         belongs_to = "super::posts::Entity",
         from = "Column::IdPostComment",
@@ -24,10 +31,31 @@ pub enum Relation {
     Posts,
 }
 
+impl Related<super::comment_reactions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommentReactions.def()
+    }
+}
+
 impl Related<super::posts::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Posts.def()
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let now: DateTimeWithTimeZone = chrono::Utc::now().into();
+
+        if insert && self.created_at.is_not_set() {
+            self.created_at = Set(now);
+        }
+        self.updated_at = Set(now);
+
+        Ok(self)
+    }
+}
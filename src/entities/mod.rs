@@ -2,13 +2,44 @@
 
 pub mod prelude;
 
+pub mod bookmarks;
 pub mod categories;
+pub mod comment_reactions;
 pub mod comments;
+pub mod featured_posts;
+pub mod media;
+pub mod post_reactions;
+pub mod post_locks;
+pub mod post_tags;
+pub mod post_transitions;
+pub mod post_view_stats;
 pub mod posts;
+pub mod reading_lists;
+pub mod related_posts;
+pub mod sea_orm_active_enums;
+pub mod series;
+pub mod series_posts;
+pub mod tags;
+pub mod trending_posts;
 pub mod users;
 
 
 pub use users::Entity as Users;
 pub use categories::Entity as Categories;
 pub use posts::Entity as Posts;
-pub use comments::Entity as Comments;
\ No newline at end of file
+pub use comments::Entity as Comments;
+pub use tags::Entity as Tags;
+pub use post_tags::Entity as PostTags;
+pub use media::Entity as Media;
+pub use post_view_stats::Entity as PostViewStats;
+pub use post_reactions::Entity as PostReactions;
+pub use comment_reactions::Entity as CommentReactions;
+pub use bookmarks::Entity as Bookmarks;
+pub use reading_lists::Entity as ReadingLists;
+pub use series::Entity as Series;
+pub use series_posts::Entity as SeriesPosts;
+pub use featured_posts::Entity as FeaturedPosts;
+pub use trending_posts::Entity as TrendingPosts;
+pub use related_posts::Entity as RelatedPosts;
+pub use post_transitions::Entity as PostTransitions;
+pub use post_locks::Entity as PostLocks;
@@ -1,6 +1,8 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.0
 
-use sea_orm::entity::prelude::*;
+use sea_orm::{entity::prelude::*, Set};
+
+use super::sea_orm_active_enums::{BodyFormat, PostStatus, PostVisibility};
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
 #[sea_orm(table_name = "posts")]
@@ -12,23 +14,61 @@ pub struct Model {
     pub img: String,
     #[sea_orm(column_type = "Text")]
     pub body: String,
+    pub body_format: BodyFormat,
+    #[sea_orm(column_type = "Text")]
+    pub body_html: String,
+    #[sea_orm(column_type = "Text")]
+    pub excerpt: String,
+    pub word_count: i32,
+    pub reading_time_minutes: i32,
     pub category_id: i32,
     pub user_id: i32,
     pub user_name: String,
+    pub status: PostStatus,
+    pub visibility: PostVisibility,
+    pub password_hash: Option<String>,
+    pub pinned_at: Option<DateTimeWithTimeZone>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub metadata: Json,
+    pub locale: String,
+    pub translation_group_id: Option<i32>,
+    pub deleted_at: Option<DateTimeWithTimeZone>,
+    pub deleted_by: Option<i32>,
+    pub version: i32,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::bookmarks::Entity")]
+    Bookmarks,
     #[sea_orm(
         belongs_to = "super::categories::Entity",
         from = "Column::CategoryId",
         to = "super::categories::Column::Id",
         on_update = "Cascade",
-        on_delete = "Cascade"
+        on_delete = "Restrict"
     )]
     Categories,
     #[sea_orm(has_many = "super::comments::Entity")]
     Comments,
+    #[sea_orm(has_one = "super::featured_posts::Entity")]
+    FeaturedPosts,
+    #[sea_orm(has_one = "super::post_locks::Entity")]
+    PostLocks,
+    #[sea_orm(has_many = "super::post_reactions::Entity")]
+    PostReactions,
+    #[sea_orm(has_many = "super::post_tags::Entity")]
+    PostTags,
+    #[sea_orm(has_many = "super::post_transitions::Entity")]
+    PostTransitions,
+    #[sea_orm(has_many = "super::post_view_stats::Entity")]
+    PostViewStats,
+    #[sea_orm(has_many = "super::series_posts::Entity")]
+    SeriesPosts,
+    #[sea_orm(has_one = "super::trending_posts::Entity")]
+    TrendingPosts,
     #[sea_orm(
         belongs_to = "super::users::Entity",
         from = "Column::UserId",
@@ -39,6 +79,12 @@ pub enum Relation {
     Users,
 }
 
+impl Related<super::bookmarks::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Bookmarks.def()
+    }
+}
+
 impl Related<super::categories::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Categories.def()
@@ -51,10 +97,93 @@ impl Related<super::comments::Entity> for Entity {
     }
 }
 
+impl Related<super::featured_posts::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::FeaturedPosts.def()
+    }
+}
+
+impl Related<super::post_locks::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PostLocks.def()
+    }
+}
+
+impl Related<super::post_reactions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PostReactions.def()
+    }
+}
+
+impl Related<super::post_tags::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PostTags.def()
+    }
+}
+
+impl Related<super::post_transitions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PostTransitions.def()
+    }
+}
+
+impl Related<super::post_view_stats::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PostViewStats.def()
+    }
+}
+
+impl Related<super::series_posts::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::SeriesPosts.def()
+    }
+}
+
+impl Related<super::series::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::series_posts::Relation::Series.def()
+    }
+
+    fn via() -> Option<RelationDef> {
+        Some(super::series_posts::Relation::Posts.def().rev())
+    }
+}
+
+impl Related<super::tags::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::post_tags::Relation::Tags.def()
+    }
+
+    fn via() -> Option<RelationDef> {
+        Some(super::post_tags::Relation::Posts.def().rev())
+    }
+}
+
+impl Related<super::trending_posts::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TrendingPosts.def()
+    }
+}
+
 impl Related<super::users::Entity> for Entity {
     fn to() -> RelationDef {
         Relation::Users.def()
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let now: DateTimeWithTimeZone = chrono::Utc::now().into();
+
+        if insert && self.created_at.is_not_set() {
+            self.created_at = Set(now);
+        }
+        self.updated_at = Set(now);
+
+        Ok(self)
+    }
+}
@@ -1,6 +1,21 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.0
 
+pub use super::bookmarks::Entity as Bookmarks;
 pub use super::categories::Entity as Categories;
+pub use super::comment_reactions::Entity as CommentReactions;
 pub use super::comments::Entity as Comments;
+pub use super::featured_posts::Entity as FeaturedPosts;
+pub use super::media::Entity as Media;
+pub use super::post_reactions::Entity as PostReactions;
+pub use super::post_locks::Entity as PostLocks;
+pub use super::post_tags::Entity as PostTags;
+pub use super::post_transitions::Entity as PostTransitions;
+pub use super::post_view_stats::Entity as PostViewStats;
 pub use super::posts::Entity as Posts;
+pub use super::reading_lists::Entity as ReadingLists;
+pub use super::related_posts::Entity as RelatedPosts;
+pub use super::series::Entity as Series;
+pub use super::series_posts::Entity as SeriesPosts;
+pub use super::tags::Entity as Tags;
+pub use super::trending_posts::Entity as TrendingPosts;
 pub use super::users::Entity as Users;
@@ -1,6 +1,8 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.0
 
-use sea_orm::entity::prelude::*;
+use sea_orm::{entity::prelude::*, Set};
+
+use super::sea_orm_active_enums::UserRole;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
 #[sea_orm(table_name = "users")]
@@ -12,12 +14,68 @@ pub struct Model {
     #[sea_orm(unique)]
     pub email: String,
     pub password: String,
+    pub role: UserRole,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+    pub version: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
+    #[sea_orm(has_many = "super::bookmarks::Entity")]
+    Bookmarks,
+    #[sea_orm(has_many = "super::comment_reactions::Entity")]
+    CommentReactions,
+    #[sea_orm(has_many = "super::media::Entity")]
+    Media,
+    #[sea_orm(has_many = "super::post_locks::Entity")]
+    PostLocks,
+    #[sea_orm(has_many = "super::post_reactions::Entity")]
+    PostReactions,
+    #[sea_orm(has_many = "super::post_transitions::Entity")]
+    PostTransitions,
     #[sea_orm(has_many = "super::posts::Entity")]
     Posts,
+    #[sea_orm(has_many = "super::reading_lists::Entity")]
+    ReadingLists,
+    #[sea_orm(has_many = "super::series::Entity")]
+    Series,
+}
+
+impl Related<super::bookmarks::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Bookmarks.def()
+    }
+}
+
+impl Related<super::comment_reactions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::CommentReactions.def()
+    }
+}
+
+impl Related<super::media::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Media.def()
+    }
+}
+
+impl Related<super::post_locks::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PostLocks.def()
+    }
+}
+
+impl Related<super::post_reactions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PostReactions.def()
+    }
+}
+
+impl Related<super::post_transitions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::PostTransitions.def()
+    }
 }
 
 impl Related<super::posts::Entity> for Entity {
@@ -26,4 +84,31 @@ impl Related<super::posts::Entity> for Entity {
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+impl Related<super::reading_lists::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ReadingLists.def()
+    }
+}
+
+impl Related<super::series::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Series.def()
+    }
+}
+
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let now: DateTimeWithTimeZone = chrono::Utc::now().into();
+
+        if insert && self.created_at.is_not_set() {
+            self.created_at = Set(now);
+        }
+        self.updated_at = Set(now);
+
+        Ok(self)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{domain::{ArchivePeriod, Pagination}, middleware::JwtMiddleware, state::AppState};
+use actix_web::{get, web, HttpResponse, Responder, ResponseError};
+use tracing::{error, info};
+
+#[get("/archive")]
+async fn get_archive(
+    data: web::Data<AppState>,
+    jwt_guard: Option<JwtMiddleware>,
+) -> impl Responder {
+    let viewer = jwt_guard.map(|guard| guard.user_id);
+
+    match data.di_container.post_service.get_archive(viewer).await {
+        Ok(archive) => HttpResponse::Ok().json(archive),
+        Err(e) => {
+            error!("Failed to fetch archive: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+#[get("/archive/{year}")]
+async fn get_archive_year(
+    data: web::Data<AppState>,
+    year: web::Path<i32>,
+    pagination: web::Query<Pagination>,
+    jwt_guard: Option<JwtMiddleware>,
+) -> impl Responder {
+    let period = ArchivePeriod::new(year.into_inner(), None);
+    info!("Fetching archive posts for {}", period.year);
+    let viewer = jwt_guard.map(|guard| guard.user_id);
+
+    match data.di_container.post_service.get_archive_posts(&period, &pagination, viewer).await {
+        Ok(posts) => HttpResponse::Ok().json(posts),
+        Err(e) => {
+            error!("Failed to fetch archive posts for {}: {:?}", period.year, e);
+            e.error_response()
+        }
+    }
+}
+
+#[get("/archive/{year}/{month}")]
+async fn get_archive_month(
+    data: web::Data<AppState>,
+    path: web::Path<(i32, u32)>,
+    pagination: web::Query<Pagination>,
+    jwt_guard: Option<JwtMiddleware>,
+) -> impl Responder {
+    let (year, month) = path.into_inner();
+    let period = ArchivePeriod::new(year, Some(month));
+    info!("Fetching archive posts for {}-{:02}", year, month);
+    let viewer = jwt_guard.map(|guard| guard.user_id);
+
+    match data.di_container.post_service.get_archive_posts(&period, &pagination, viewer).await {
+        Ok(posts) => HttpResponse::Ok().json(posts),
+        Err(e) => {
+            error!("Failed to fetch archive posts for {}-{:02}: {:?}", year, month, e);
+            e.error_response()
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use actix_web::{
     
-    get, post, web,  HttpResponse, Responder,
+    get, post, web,  HttpResponse, Responder, ResponseError,
 };
 use serde_json::json;
 
@@ -13,7 +13,7 @@ async fn register_user_handler(
 ) -> impl Responder {
     match data.di_container.auth_service.register_user(&body).await {
         Ok(response) => HttpResponse::Ok().json(response),
-        Err(e) => HttpResponse::InternalServerError().json(e),
+        Err(e) => e.error_response(),
     }
 }
 
@@ -0,0 +1,182 @@
+use crate::{
+    domain::{BookmarkQuery, CreateBookmarkRequest, Pagination, ReadingListRequest},
+    middleware::JwtMiddleware,
+    state::AppState,
+};
+use actix_web::{delete, get, post, put, web, HttpRequest, HttpResponse, Responder, ResponseError};
+use serde_json::json;
+use tracing::{error, info};
+
+use super::post::post_token;
+
+#[post("/bookmarks/{post_id}")]
+async fn add_bookmark(
+    req: HttpRequest,
+    data: web::Data<AppState>,
+    post_id: web::Path<i32>,
+    body: Option<web::Json<CreateBookmarkRequest>>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    let post_id = post_id.into_inner();
+    let input = body.map(|body| body.into_inner()).unwrap_or_default();
+    info!("Bookmarking post {} for user {}", post_id, jwt_guard.user_id);
+
+    match data.di_container.bookmark_service.add_bookmark(jwt_guard.user_id, post_id, &input, post_token(&req)).await {
+        Ok(bookmark) => HttpResponse::Ok().json(bookmark),
+        Err(e) => {
+            error!("Failed to bookmark post {}: {:?}", post_id, e);
+            e.error_response()
+        }
+    }
+}
+
+#[delete("/bookmarks/{post_id}")]
+async fn remove_bookmark(data: web::Data<AppState>, post_id: web::Path<i32>, jwt_guard: JwtMiddleware) -> impl Responder {
+    let post_id = post_id.into_inner();
+    info!("Removing bookmark of post {} for user {}", post_id, jwt_guard.user_id);
+
+    match data.di_container.bookmark_service.remove_bookmark(jwt_guard.user_id, post_id).await {
+        Ok(Some(response)) => HttpResponse::Ok().json(response),
+        Ok(None) => HttpResponse::NotFound().json(json!({
+            "status": "fail",
+            "message": "Bookmark not found",
+        })),
+        Err(e) => {
+            error!("Failed to remove bookmark of post {}: {:?}", post_id, e);
+            e.error_response()
+        }
+    }
+}
+
+#[get("/bookmarks")]
+async fn get_bookmarks(
+    data: web::Data<AppState>,
+    query: web::Query<BookmarkQuery>,
+    pagination: web::Query<Pagination>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    info!("Fetching bookmarks for user {}", jwt_guard.user_id);
+
+    match data.di_container.bookmark_service.get_bookmarks(jwt_guard.user_id, &query, &pagination).await {
+        Ok(bookmarks) => HttpResponse::Ok().json(bookmarks),
+        Err(e) => {
+            error!("Failed to fetch bookmarks: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+#[get("/reading-lists")]
+async fn get_reading_lists(data: web::Data<AppState>, jwt_guard: JwtMiddleware) -> impl Responder {
+    info!("Fetching reading lists for user {}", jwt_guard.user_id);
+
+    match data.di_container.bookmark_service.get_reading_lists(jwt_guard.user_id).await {
+        Ok(lists) => HttpResponse::Ok().json(lists),
+        Err(e) => {
+            error!("Failed to fetch reading lists: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+#[post("/reading-lists")]
+async fn create_reading_list(
+    data: web::Data<AppState>,
+    body: web::Json<ReadingListRequest>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    match data.di_container.bookmark_service.create_reading_list(jwt_guard.user_id, &body).await {
+        Ok(list) => HttpResponse::Created().json(list),
+        Err(e) => {
+            error!("Failed to create reading list: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+#[put("/reading-lists/{id}")]
+async fn update_reading_list(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    body: web::Json<ReadingListRequest>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    let id = id.into_inner();
+
+    match data.di_container.bookmark_service.update_reading_list(id, jwt_guard.user_id, &body).await {
+        Ok(Some(list)) => HttpResponse::Ok().json(list),
+        Ok(None) => HttpResponse::NotFound().json(json!({
+            "status": "fail",
+            "message": "Reading list not found",
+        })),
+        Err(e) => {
+            error!("Failed to update reading list {}: {:?}", id, e);
+            e.error_response()
+        }
+    }
+}
+
+#[delete("/reading-lists/{id}")]
+async fn delete_reading_list(data: web::Data<AppState>, id: web::Path<i32>, jwt_guard: JwtMiddleware) -> impl Responder {
+    let id = id.into_inner();
+
+    match data.di_container.bookmark_service.delete_reading_list(id, jwt_guard.user_id).await {
+        Ok(Some(response)) => HttpResponse::Ok().json(response),
+        Ok(None) => HttpResponse::NotFound().json(json!({
+            "status": "fail",
+            "message": "Reading list not found",
+        })),
+        Err(e) => {
+            error!("Failed to delete reading list {}: {:?}", id, e);
+            e.error_response()
+        }
+    }
+}
+
+#[post("/reading-lists/{id}/share")]
+async fn share_reading_list(data: web::Data<AppState>, id: web::Path<i32>, jwt_guard: JwtMiddleware) -> impl Responder {
+    set_reading_list_shared(data, id.into_inner(), jwt_guard.user_id, true).await
+}
+
+#[delete("/reading-lists/{id}/share")]
+async fn unshare_reading_list(data: web::Data<AppState>, id: web::Path<i32>, jwt_guard: JwtMiddleware) -> impl Responder {
+    set_reading_list_shared(data, id.into_inner(), jwt_guard.user_id, false).await
+}
+
+async fn set_reading_list_shared(data: web::Data<AppState>, id: i32, user_id: i32, shared: bool) -> HttpResponse {
+    info!("Setting reading list {} shared={} for user {}", id, shared, user_id);
+
+    match data.di_container.bookmark_service.share_reading_list(id, user_id, shared).await {
+        Ok(Some(list)) => HttpResponse::Ok().json(list),
+        Ok(None) => HttpResponse::NotFound().json(json!({
+            "status": "fail",
+            "message": "Reading list not found",
+        })),
+        Err(e) => {
+            error!("Failed to update sharing of reading list {}: {:?}", id, e);
+            e.error_response()
+        }
+    }
+}
+
+#[get("/shared/reading-lists/{token}")]
+async fn get_shared_reading_list(
+    data: web::Data<AppState>,
+    token: web::Path<String>,
+    pagination: web::Query<Pagination>,
+    jwt_guard: Option<JwtMiddleware>,
+) -> impl Responder {
+    let viewer = jwt_guard.map(|guard| guard.user_id);
+
+    match data.di_container.bookmark_service.get_shared_reading_list(&token, &pagination, viewer).await {
+        Ok(Some(list)) => HttpResponse::Ok().json(list),
+        Ok(None) => HttpResponse::NotFound().json(json!({
+            "status": "fail",
+            "message": "Reading list not found",
+        })),
+        Err(e) => {
+            error!("Failed to fetch shared reading list: {:?}", e);
+            e.error_response()
+        }
+    }
+}
@@ -1,8 +1,8 @@
 use crate::{
-    domain::{CreateCategoryRequest, UpdateCategoryRequest}, middleware::JwtMiddleware, state::AppState
+    domain::{CreateCategoryRequest, UpdateCategoryRequest}, middleware::{IfMatch, JwtMiddleware}, state::AppState, utils::etag
 };
-use actix_web::{delete, get, post, put, web, HttpResponse, Responder};
-use serde_json::json;
+use actix_web::{delete, get, http::header, post, put, web, HttpResponse, Responder, ResponseError};
+use serde_json::{json, Value};
 
 #[get("/categories")]
 async fn get_categories(data: web::Data<AppState>) -> impl Responder {
@@ -23,15 +23,14 @@ async fn get_category(data: web::Data<AppState>, id: web::Path<i32>, _jwt_guard:
         .get_category(id.into_inner())
         .await
     {
-        Ok(Some(category)) => HttpResponse::Ok().json(category),
+        Ok(Some(category)) => HttpResponse::Ok()
+            .insert_header((header::ETAG, etag(category.data.version)))
+            .json(category),
         Ok(None) => HttpResponse::NotFound().json(json!({
             "status": "fail",
             "message": "Category not found",
         })),
-        Err(_) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": "Failed to fetch category",
-        })),
+        Err(e) => e.error_response(),
     }
 }
 
@@ -60,11 +59,13 @@ async fn update_category(
     data: web::Data<AppState>,
     id: web::Path<i32>,
     body: web::Json<UpdateCategoryRequest>,
+    if_match: IfMatch,
     _jwt_guard: JwtMiddleware
 ) -> impl Responder {
     let mut update_request = body.into_inner();
 
     update_request.id = Some(id.into_inner());
+    update_request.version = if_match.0;
 
     match data
         .di_container
@@ -72,33 +73,65 @@ async fn update_category(
         .update_category(&update_request)
         .await
     {
-        Ok(Some(category)) => HttpResponse::Ok().json(category),
+        Ok(Some(category)) => HttpResponse::Ok()
+            .insert_header((header::ETAG, etag(category.data.version)))
+            .json(category),
         Ok(None) => HttpResponse::NotFound().json(json!({
             "status": "fail",
             "message": "Category not found",
         })),
-        Err(_) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": "Failed to update category",
-        })),
+        Err(e) => e.error_response(),
+    }
+}
+
+#[put("/categories/{id}/metadata-schema")]
+async fn set_metadata_schema(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    body: web::Json<Value>,
+    if_match: IfMatch,
+    jwt_guard: JwtMiddleware
+) -> impl Responder {
+    match data
+        .di_container
+        .category_service
+        .set_metadata_schema(id.into_inner(), Some(&body), jwt_guard.user_id, if_match.0)
+        .await
+    {
+        Ok(category) => HttpResponse::Ok()
+            .insert_header((header::ETAG, etag(category.data.version)))
+            .json(category),
+        Err(e) => e.error_response(),
+    }
+}
+
+#[delete("/categories/{id}/metadata-schema")]
+async fn delete_metadata_schema(data: web::Data<AppState>, id: web::Path<i32>, if_match: IfMatch, jwt_guard: JwtMiddleware) -> impl Responder {
+    match data
+        .di_container
+        .category_service
+        .set_metadata_schema(id.into_inner(), None, jwt_guard.user_id, if_match.0)
+        .await
+    {
+        Ok(category) => HttpResponse::Ok()
+            .insert_header((header::ETAG, etag(category.data.version)))
+            .json(category),
+        Err(e) => e.error_response(),
     }
 }
 
 #[delete("/categories/{id}")]
-async fn delete_category(data: web::Data<AppState>, id: web::Path<i32>, _jwt_guard: JwtMiddleware) -> impl Responder {
+async fn delete_category(data: web::Data<AppState>, id: web::Path<i32>, if_match: IfMatch, jwt_guard: JwtMiddleware) -> impl Responder {
     match data
         .di_container
         .category_service
-        .delete_category(id.into_inner())
+        .delete_category(id.into_inner(), jwt_guard.user_id, if_match.0)
         .await
     {
         Ok(_) => HttpResponse::Ok().json(json!({
             "status": "success",
             "message": "Category deleted successfully",
         })),
-        Err(_) => HttpResponse::InternalServerError().json(json!({
-            "status": "error",
-            "message": "Failed to delete category",
-        })),
+        Err(e) => e.error_response(),
     }
 }
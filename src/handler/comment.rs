@@ -1,14 +1,16 @@
 use crate::{
-    domain::{CreateCommentRequest, UpdateCommentRequest}, middleware::JwtMiddleware, state::AppState
+    domain::{CreateCommentRequest, ErrorResponse, PatchDocument, UpdateCommentRequest}, middleware::{IfMatch, JwtMiddleware}, state::AppState, utils::etag
 };
-use actix_web::{delete, get, post, put, web, HttpResponse, Responder};
+use actix_web::{delete, get, http::header, patch, post, put, web, HttpRequest, HttpResponse, Responder, ResponseError};
 use serde_json::json;
 use tracing::{info, error};
 
+use super::post::post_token;
+
 #[get("/comments")]
-async fn get_comments(data: web::Data<AppState>, _jwt_guard: JwtMiddleware) -> impl Responder {
+async fn get_comments(data: web::Data<AppState>, jwt_guard: JwtMiddleware) -> impl Responder {
     info!("Fetching all comments");
-    match data.di_container.comment_service.get_comments().await {
+    match data.di_container.comment_service.get_comments(Some(jwt_guard.user_id)).await {
         Ok(comments) => {
             info!("Successfully fetched comments");
             HttpResponse::Ok().json(comments)
@@ -24,7 +26,7 @@ async fn get_comments(data: web::Data<AppState>, _jwt_guard: JwtMiddleware) -> i
 }
 
 #[get("/comments/{id}")]
-async fn get_comment(data: web::Data<AppState>, id: web::Path<i32>, _jwt_guard: JwtMiddleware) -> impl Responder {
+async fn get_comment(req: HttpRequest, data: web::Data<AppState>, id: web::Path<i32>, jwt_guard: JwtMiddleware) -> impl Responder {
     let comment_id = id.into_inner();
     
     info!("Fetching comment with ID: {}", comment_id);
@@ -32,12 +34,14 @@ async fn get_comment(data: web::Data<AppState>, id: web::Path<i32>, _jwt_guard:
     match data
         .di_container
         .comment_service
-        .get_comment(comment_id)
+        .get_comment(comment_id, Some(jwt_guard.user_id), post_token(&req))
         .await
     {
         Ok(Some(comment)) => {
             info!("Comment found: {:?}", comment);
-            HttpResponse::Ok().json(comment)
+            HttpResponse::Ok()
+                .insert_header((header::ETAG, etag(comment.data.version)))
+                .json(comment)
         },
         Ok(None) => {
             info!("Comment with ID {} not found", comment_id);
@@ -48,25 +52,23 @@ async fn get_comment(data: web::Data<AppState>, id: web::Path<i32>, _jwt_guard:
         },
         Err(e) => {
             error!("Failed to fetch comment with ID {}: {:?}", comment_id, e);
-            HttpResponse::InternalServerError().json(json!({
-                "status": "error",
-                "message": "Failed to fetch comment",
-            }))
+            e.error_response()
         }
     }
 }
 
 #[post("/comments")]
 async fn create_comment(
+    req: HttpRequest,
     data: web::Data<AppState>,
     body: web::Json<CreateCommentRequest>,
-    _jwt_guard: JwtMiddleware
+    jwt_guard: JwtMiddleware
 ) -> impl Responder {
     info!("Creating comment for post ID: {}", body.id_post_comment);
     match data
         .di_container
         .comment_service
-        .create_comment(&body)
+        .create_comment(&body, Some(jwt_guard.user_id), post_token(&req))
         .await
     {
         Ok(comment) => {
@@ -75,50 +77,94 @@ async fn create_comment(
         },
         Err(e) => {
             error!("Failed to create comment: {:?}", e);
-            HttpResponse::InternalServerError().json(json!({
-                "status": "error",
-                "message": "Failed to create comment",
-            }))
+            e.error_response()
         }
     }
 }
 
 #[put("/comments/{id}")]
 async fn update_comment(
+    req: HttpRequest,
     data: web::Data<AppState>,
+    id: web::Path<i32>,
     body: web::Json<UpdateCommentRequest>,
-    _jwt_guard: JwtMiddleware
+    if_match: IfMatch,
+    jwt_guard: JwtMiddleware
 ) -> impl Responder {
-    info!("Updating comment with ID: {}", body.id_post_comment);
+    let comment_id = id.into_inner();
+    let mut update_request = body.into_inner();
+    update_request.id = Some(comment_id);
+    update_request.version = if_match.0;
+
+    info!("Updating comment with ID: {}", comment_id);
     match data
         .di_container
         .comment_service
-        .update_comment(&body)
+        .update_comment(&update_request, Some(jwt_guard.user_id), post_token(&req))
         .await
     {
         Ok(Some(comment)) => {
             info!("Successfully updated comment: {:?}", comment);
-            HttpResponse::Ok().json(comment)
+            HttpResponse::Ok()
+                .insert_header((header::ETAG, etag(comment.data.version)))
+                .json(comment)
         },
         Ok(None) => {
-            info!("Comment with ID {} not found for update", body.id_post_comment);
+            info!("Comment with ID {} not found for update", comment_id);
             HttpResponse::NotFound().json(json!({
                 "status": "fail",
                 "message": "Comment not found",
             }))
         },
         Err(e) => {
-            error!("Failed to update comment with ID {}: {:?}", body.id_post_comment, e);
-            HttpResponse::InternalServerError().json(json!({
-                "status": "error",
-                "message": "Failed to update comment",
-            }))
+            error!("Failed to update comment with ID {}: {:?}", comment_id, e);
+            e.error_response()
+        }
+    }
+}
+
+#[patch("/comments/{id}")]
+async fn patch_comment(
+    req: HttpRequest,
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    body: web::Bytes,
+    if_match: IfMatch,
+    jwt_guard: JwtMiddleware
+) -> impl Responder {
+    let comment_id = id.into_inner();
+    let content_type = req.headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    let patch = match PatchDocument::parse(content_type, &body) {
+        Ok(patch) => patch,
+        Err(e) => return ErrorResponse::from(e).error_response(),
+    };
+
+    info!("Patching comment with ID: {}", comment_id);
+    match data
+        .di_container
+        .comment_service
+        .patch_comment(comment_id, &patch, if_match.0, Some(jwt_guard.user_id), post_token(&req))
+        .await
+    {
+        Ok(comment) => {
+            info!("Successfully patched comment: {:?}", comment);
+            HttpResponse::Ok()
+                .insert_header((header::ETAG, etag(comment.data.version)))
+                .json(comment)
+        },
+        Err(e) => {
+            error!("Failed to patch comment with ID {}: {:?}", comment_id, e);
+            e.error_response()
         }
     }
 }
 
 #[delete("/comments/{id}")]
-async fn delete_comment(data: web::Data<AppState>, id: web::Path<i32>, _jwt_guard: JwtMiddleware) -> impl Responder {
+async fn delete_comment(req: HttpRequest, data: web::Data<AppState>, id: web::Path<i32>, if_match: IfMatch, jwt_guard: JwtMiddleware) -> impl Responder {
     let comment_id = id.into_inner();
     
     info!("Deleting comment with ID: {}", comment_id);
@@ -128,7 +174,7 @@ async fn delete_comment(data: web::Data<AppState>, id: web::Path<i32>, _jwt_guar
     match data
         .di_container
         .comment_service
-        .delete_comment(comment_id)
+        .delete_comment(comment_id, jwt_guard.user_id, if_match.0, post_token(&req))
         .await
     {
         Ok(_) => {
@@ -140,10 +186,7 @@ async fn delete_comment(data: web::Data<AppState>, id: web::Path<i32>, _jwt_guar
         },
         Err(e) => {
             error!("Failed to delete comment with ID {}: {:?}", comment_id, e);
-            HttpResponse::InternalServerError().json(json!({
-                "status": "error",
-                "message": "Failed to delete comment",
-            }))
+            e.error_response()
         }
     }
 }
@@ -0,0 +1,77 @@
+use std::time::SystemTime;
+
+use crate::{
+    domain::{FeedFormat, FeedScope, XmlDocument},
+    state::AppState,
+    utils::is_not_modified,
+};
+use actix_web::{get, http::header, web, HttpRequest, HttpResponse, Responder, ResponseError};
+use serde_json::json;
+use tracing::{error, info};
+
+#[get("/posts.xml")]
+async fn get_posts_atom(req: HttpRequest, data: web::Data<AppState>) -> impl Responder {
+    feed_response(req, data, FeedScope::All, FeedFormat::Atom).await
+}
+
+#[get("/posts.rss")]
+async fn get_posts_rss(req: HttpRequest, data: web::Data<AppState>) -> impl Responder {
+    feed_response(req, data, FeedScope::All, FeedFormat::Rss).await
+}
+
+#[get("/categories/{id}.xml")]
+async fn get_category_atom(req: HttpRequest, data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
+    feed_response(req, data, FeedScope::Category(id.into_inner()), FeedFormat::Atom).await
+}
+
+#[get("/categories/{id}.rss")]
+async fn get_category_rss(req: HttpRequest, data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
+    feed_response(req, data, FeedScope::Category(id.into_inner()), FeedFormat::Rss).await
+}
+
+#[get("/authors/{id}.xml")]
+async fn get_author_atom(req: HttpRequest, data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
+    feed_response(req, data, FeedScope::Author(id.into_inner()), FeedFormat::Atom).await
+}
+
+#[get("/authors/{id}.rss")]
+async fn get_author_rss(req: HttpRequest, data: web::Data<AppState>, id: web::Path<i32>) -> impl Responder {
+    feed_response(req, data, FeedScope::Author(id.into_inner()), FeedFormat::Rss).await
+}
+
+async fn feed_response(req: HttpRequest, data: web::Data<AppState>, scope: FeedScope, format: FeedFormat) -> HttpResponse {
+    info!("Rendering {:?} feed for {:?}", format, scope);
+
+    match data.di_container.feed_service.get_feed(scope, format).await {
+        Ok(Some(feed)) => xml_response(&req, feed),
+        Ok(None) => HttpResponse::NotFound().json(json!({
+            "status": "fail",
+            "message": "Feed not found",
+        })),
+        Err(e) => {
+            error!("Failed to render feed for {:?}: {:?}", scope, e);
+            e.error_response()
+        }
+    }
+}
+
+/// Responds with the document, or `304 Not Modified` when the client's copy is current.
+pub(super) fn xml_response(req: &HttpRequest, document: XmlDocument) -> HttpResponse {
+    let not_modified = is_not_modified(req.headers(), &document.etag, document.last_modified);
+    let mut response = if not_modified {
+        HttpResponse::NotModified()
+    } else {
+        HttpResponse::Ok()
+    };
+
+    response.insert_header((header::ETAG, document.etag.clone()));
+    if let Some(last_modified) = document.last_modified {
+        response.insert_header(header::LastModified(SystemTime::from(last_modified).into()));
+    }
+
+    if not_modified {
+        response.finish()
+    } else {
+        response.content_type(document.content_type).body(document.body)
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{domain::UploadMediaRequest, middleware::JwtMiddleware, state::AppState};
+use actix_multipart::Multipart;
+use actix_web::{post, web, HttpResponse, Responder, ResponseError};
+use futures_util::StreamExt;
+use serde_json::json;
+use tracing::{error, info};
+
+#[post("/media")]
+async fn upload_media(
+    data: web::Data<AppState>,
+    mut payload: Multipart,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    let max_size = data.di_container.media_service.max_upload_size();
+
+    while let Some(field) = payload.next().await {
+        let mut field = match field {
+            Ok(field) => field,
+            Err(e) => {
+                error!("Failed to read multipart field: {:?}", e);
+                return HttpResponse::BadRequest().json(json!({
+                    "status": "fail",
+                    "message": "Invalid multipart payload",
+                }));
+            }
+        };
+
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let content_type = field.content_type().map(|mime| mime.essence_str().to_string());
+        let mut bytes = Vec::new();
+
+        while let Some(chunk) = field.next().await {
+            let chunk = match chunk {
+                Ok(chunk) => chunk,
+                Err(e) => {
+                    error!("Failed to read upload: {:?}", e);
+                    return HttpResponse::BadRequest().json(json!({
+                        "status": "fail",
+                        "message": "Failed to read upload",
+                    }));
+                }
+            };
+
+            // Stop reading as soon as the limit is crossed instead of buffering the whole body
+            if bytes.len() + chunk.len() > max_size {
+                return HttpResponse::PayloadTooLarge().json(json!({
+                    "status": "fail",
+                    "message": format!("File exceeds the maximum size of {} bytes", max_size),
+                }));
+            }
+
+            bytes.extend_from_slice(&chunk);
+        }
+
+        let request = UploadMediaRequest {
+            user_id: jwt_guard.user_id,
+            content_type,
+            bytes,
+        };
+
+        info!("Uploading media of {} bytes for user {}", request.bytes.len(), request.user_id);
+
+        return match data.di_container.media_service.upload(&request).await {
+            Ok(media) => HttpResponse::Created().json(media),
+            Err(e) => {
+                error!("Failed to upload media: {:?}", e);
+                e.error_response()
+            }
+        };
+    }
+
+    HttpResponse::BadRequest().json(json!({
+        "status": "fail",
+        "message": "Missing `file` field",
+    }))
+}
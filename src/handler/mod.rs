@@ -3,10 +3,26 @@ mod user;
 mod category;
 mod comment;
 mod post;
+mod tag;
+mod search;
+mod media;
+mod trash;
+mod views;
+mod reaction;
+mod bookmark;
+mod series;
+mod feed;
+mod sitemap;
+mod ranking;
+mod archive;
+mod review;
+mod post_lock;
 
 
 use actix_web::web;
 
+pub use self::post::POST_TOKEN_HEADER;
+
 use self::auth::{
     get_user,
     login_user_handler,
@@ -17,6 +33,7 @@ use self::user::{
     create_user,
     find_user_by_email,
     update_user,
+    patch_user,
     delete_user
 };
 
@@ -25,16 +42,26 @@ use self::category::{
     get_category,
     create_category,
     update_category,
+    set_metadata_schema,
+    delete_metadata_schema,
     delete_category
 };
 
 use self::post::{
     get_posts,
-    get_post_relation,
     get_post,
+    get_featured_posts,
+    feature_post,
+    unfeature_post,
+    pin_post,
+    unpin_post,
+    unlock_post,
     create_post,
+    create_translation,
     update_post,
-    delete_post
+    patch_post,
+    delete_post,
+    bulk_update_posts
 };
 
 use self::comment::{
@@ -42,9 +69,96 @@ use self::comment::{
     get_comment,
     create_comment,
     update_comment,
+    patch_comment,
     delete_comment
 };
 
+use self::tag::{
+    get_tags,
+    get_tag_posts
+};
+
+use self::search::search_posts;
+
+use self::media::upload_media;
+
+use self::trash::{
+    get_trash,
+    restore_trash_item
+};
+
+use self::views::{
+    get_post_views,
+    get_author_views
+};
+
+use self::reaction::{
+    toggle_post_reaction,
+    toggle_comment_reaction
+};
+
+use self::bookmark::{
+    add_bookmark,
+    remove_bookmark,
+    get_bookmarks,
+    get_reading_lists,
+    create_reading_list,
+    update_reading_list,
+    delete_reading_list,
+    share_reading_list,
+    unshare_reading_list,
+    get_shared_reading_list
+};
+
+use self::series::{
+    get_all_series,
+    get_series,
+    create_series,
+    reorder_series,
+    delete_series
+};
+
+use self::feed::{
+    get_posts_atom,
+    get_posts_rss,
+    get_category_atom,
+    get_category_rss,
+    get_author_atom,
+    get_author_rss
+};
+
+use self::ranking::{
+    get_trending_posts,
+    get_related_posts
+};
+
+use self::archive::{
+    get_archive,
+    get_archive_year,
+    get_archive_month
+};
+
+use self::review::{
+    get_review_queue,
+    submit_post,
+    approve_post,
+    request_changes,
+    get_post_transitions
+};
+
+use self::post_lock::{
+    get_post_lock,
+    acquire_post_lock,
+    renew_post_lock,
+    release_post_lock,
+    steal_post_lock
+};
+
+use self::sitemap::{
+    get_sitemap,
+    get_sitemap_page
+};
+
 pub fn router_config(conf: &mut web::ServiceConfig) {
     let router = web::scope("/api")
   
@@ -55,22 +169,85 @@ pub fn router_config(conf: &mut web::ServiceConfig) {
         .service(get_category)
         .service(create_category)
         .service(update_category)
+        .service(set_metadata_schema)
+        .service(delete_metadata_schema)
         .service(delete_category)
         .service(get_posts)
-        .service(get_post_relation)
+        .service(get_post_views)
+        .service(get_author_views)
+        .service(toggle_post_reaction)
+        .service(get_featured_posts)
+        .service(get_trending_posts)
+        .service(get_related_posts)
+        .service(get_review_queue)
         .service(get_post)
+        .service(get_post_transitions)
+        .service(submit_post)
+        .service(approve_post)
+        .service(request_changes)
+        .service(get_post_lock)
+        .service(acquire_post_lock)
+        .service(renew_post_lock)
+        .service(release_post_lock)
+        .service(steal_post_lock)
+        .service(feature_post)
+        .service(unfeature_post)
+        .service(pin_post)
+        .service(unpin_post)
+        .service(unlock_post)
         .service(create_post)
+        .service(create_translation)
+        .service(bulk_update_posts)
         .service(update_post)
+        .service(patch_post)
         .service(delete_post)
+        .service(get_tags)
+        .service(get_tag_posts)
+        .service(search_posts)
+        .service(get_archive)
+        .service(get_archive_year)
+        .service(get_archive_month)
+        .service(upload_media)
+        .service(get_trash)
+        .service(restore_trash_item)
+        .service(add_bookmark)
+        .service(remove_bookmark)
+        .service(get_bookmarks)
+        .service(get_reading_lists)
+        .service(create_reading_list)
+        .service(update_reading_list)
+        .service(delete_reading_list)
+        .service(share_reading_list)
+        .service(unshare_reading_list)
+        .service(get_shared_reading_list)
+        .service(get_all_series)
+        .service(get_series)
+        .service(create_series)
+        .service(reorder_series)
+        .service(delete_series)
         .service(get_comments)
         .service(get_comment)
         .service(create_comment)
         .service(update_comment)
+        .service(patch_comment)
+        .service(toggle_comment_reaction)
         .service(delete_comment)
         .service(create_user)
         .service(find_user_by_email)
         .service(update_user)
+        .service(patch_user)
         .service(delete_user);
 
+    let feeds = web::scope("/feeds")
+        .service(get_posts_atom)
+        .service(get_posts_rss)
+        .service(get_category_atom)
+        .service(get_category_rss)
+        .service(get_author_atom)
+        .service(get_author_rss);
+
     conf.service(router);
+    conf.service(feeds);
+    conf.service(get_sitemap);
+    conf.service(get_sitemap_page);
 }
\ No newline at end of file
@@ -1,14 +1,46 @@
 use crate::{
-    domain::{CreatePostRequest, UpdatePostRequest}, middleware::JwtMiddleware, state::AppState
+    domain::{BulkPostRequest, CreatePostRequest, CreateTranslationRequest, ErrorResponse, FeaturePostRequest, Pagination, PatchDocument, PostDetailQuery, PostQuery, UnlockPostRequest, UpdatePostRequest}, middleware::{IfMatch, JwtMiddleware}, state::AppState, utils::etag
 };
-use actix_web::{delete, get, post, put, web, HttpResponse, Responder};
+use actix_web::{delete, get, http::header, patch, post, put, web, HttpRequest, HttpResponse, Responder, ResponseError};
 use serde_json::json;
+use std::collections::HashMap;
 use tracing::{info, error};
 
+/// Header carrying the token returned by `POST /posts/{id}/unlock`.
+pub const POST_TOKEN_HEADER: &str = "x-post-token";
+
+pub(super) fn post_token(req: &HttpRequest) -> Option<&str> {
+    req.headers()
+        .get(POST_TOKEN_HEADER)
+        .and_then(|value| value.to_str().ok())
+}
+
 #[get("/posts")]
-async fn get_posts(data: web::Data<AppState>) -> impl Responder {
-    info!("Fetching all posts...");
-    match data.di_container.post_service.get_all_posts().await {
+async fn get_posts(
+    req: HttpRequest,
+    data: web::Data<AppState>,
+    query: web::Query<PostQuery>,
+    params: web::Query<HashMap<String, String>>,
+    jwt_guard: Option<JwtMiddleware>
+) -> impl Responder {
+    let accept_language = req.headers()
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok());
+
+    let query = match query.into_inner().with_meta(&params) {
+        Ok(query) => query.with_accept_language(accept_language),
+        Err(e) => return ErrorResponse::from(e).error_response(),
+    };
+
+    info!("Fetching all posts with query: {:?}", query);
+    let viewer = jwt_guard.map(|guard| guard.user_id);
+
+    let fields = match query.fields() {
+        Ok(fields) => fields,
+        Err(e) => return ErrorResponse::from(e).error_response(),
+    };
+
+    match data.di_container.post_service.get_all_posts(&query, &fields, viewer).await {
         Ok(posts) => {
             info!("Posts fetched successfully: {:?}", posts);
             HttpResponse::Ok().json(json!({
@@ -19,29 +51,122 @@ async fn get_posts(data: web::Data<AppState>) -> impl Responder {
         },
         Err(e) => {
             error!("Failed to fetch posts: {:?}", e);
-            HttpResponse::InternalServerError().json(json!({
-                "status": "error",
-                "message": "Failed to fetch posts",
-            }))
+            e.error_response()
+        }
+    }
+}
+
+#[get("/posts/featured")]
+async fn get_featured_posts(data: web::Data<AppState>, jwt_guard: Option<JwtMiddleware>) -> impl Responder {
+    let viewer = jwt_guard.map(|guard| guard.user_id);
+
+    match data.di_container.post_service.get_featured_posts(viewer).await {
+        Ok(posts) => HttpResponse::Ok().json(posts),
+        Err(e) => {
+            error!("Failed to fetch featured posts: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+#[put("/posts/{id}/featured")]
+async fn feature_post(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    body: web::Json<FeaturePostRequest>,
+    jwt_guard: JwtMiddleware
+) -> impl Responder {
+    let post_id = id.into_inner();
+
+    match data.di_container.post_service.feature_post(post_id, &body, jwt_guard.user_id).await {
+        Ok(api_response) => HttpResponse::Ok().json(api_response),
+        Err(e) => {
+            error!("Failed to feature post {}: {:?}", post_id, e);
+            e.error_response()
+        }
+    }
+}
+
+#[delete("/posts/{id}/featured")]
+async fn unfeature_post(data: web::Data<AppState>, id: web::Path<i32>, jwt_guard: JwtMiddleware) -> impl Responder {
+    let post_id = id.into_inner();
+
+    match data.di_container.post_service.unfeature_post(post_id, jwt_guard.user_id).await {
+        Ok(Some(api_response)) => HttpResponse::Ok().json(api_response),
+        Ok(None) => HttpResponse::NotFound().json(json!({
+            "status": "fail",
+            "message": "Post is not featured",
+        })),
+        Err(e) => {
+            error!("Failed to unfeature post {}: {:?}", post_id, e);
+            e.error_response()
+        }
+    }
+}
+
+#[put("/posts/{id}/pin")]
+async fn pin_post(data: web::Data<AppState>, id: web::Path<i32>, jwt_guard: JwtMiddleware) -> impl Responder {
+    let post_id = id.into_inner();
+
+    match data.di_container.post_service.pin_post(post_id, true, jwt_guard.user_id).await {
+        Ok(api_response) => HttpResponse::Ok().json(api_response),
+        Err(e) => {
+            error!("Failed to pin post {}: {:?}", post_id, e);
+            e.error_response()
+        }
+    }
+}
+
+#[delete("/posts/{id}/pin")]
+async fn unpin_post(data: web::Data<AppState>, id: web::Path<i32>, jwt_guard: JwtMiddleware) -> impl Responder {
+    let post_id = id.into_inner();
+
+    match data.di_container.post_service.pin_post(post_id, false, jwt_guard.user_id).await {
+        Ok(api_response) => HttpResponse::Ok().json(api_response),
+        Err(e) => {
+            error!("Failed to unpin post {}: {:?}", post_id, e);
+            e.error_response()
         }
     }
 }
 
 #[get("/posts/{id}")]
-async fn get_post(data: web::Data<AppState>, id: web::Path<i32>, _jwt_guard: JwtMiddleware) -> impl Responder {
+async fn get_post(
+    req: HttpRequest,
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    query: web::Query<PostDetailQuery>,
+    comments: web::Query<Pagination>,
+    jwt_guard: Option<JwtMiddleware>
+) -> impl Responder {
     // Clone the `id` to use it later in the log statement
     let post_id = id.into_inner();
     info!("Fetching post with ID: {}", post_id);
+
+    let viewer = jwt_guard.map(|guard| guard.user_id);
+
+    let includes = match query.includes() {
+        Ok(includes) => includes,
+        Err(e) => return ErrorResponse::from(e).error_response(),
+    };
     
     match data
         .di_container
         .post_service
-        .get_post(post_id)
+        .get_post(post_id, &includes, &comments, viewer, post_token(&req))
         .await
     {
         Ok(Some(post)) => {
             info!("Post found: {:?}", post);
-            HttpResponse::Ok().json(post)
+            let visitor = match viewer {
+                Some(user_id) => format!("user:{}", user_id),
+                None => format!("ip:{}", req.connection_info().realip_remote_addr().unwrap_or_default()),
+            };
+            data.di_container.view_service.record_view(post_id, &visitor);
+
+            HttpResponse::Ok()
+                .insert_header((header::ETAG, etag(post.data.post.version)))
+                .json(post)
         },
         Ok(None) => {
             info!("Post with ID {} not found", post_id);
@@ -52,34 +177,27 @@ async fn get_post(data: web::Data<AppState>, id: web::Path<i32>, _jwt_guard: Jwt
         },
         Err(e) => {
             error!("Failed to fetch post: {:?}", e);
-            HttpResponse::InternalServerError().json(json!({
-                "status": "error",
-                "message": "Failed to fetch Post",
-            }))
+            e.error_response()
         }
     }
 }
 
 
-#[get("/posts/{id}/relation")]
-async fn get_post_relation(data: web::Data<AppState>, id: web::Path<i32>, _jwt_guard: JwtMiddleware) -> impl Responder {
-    info!("Fetching post relation for post ID: {}", id);
-    match data
-        .di_container
-        .post_service
-        .get_post_relation(id.into_inner())
-        .await
-    {
-        Ok(api_response) => {
-            info!("Post relation fetched successfully: {:?}", api_response);
-            HttpResponse::Ok().json(api_response)
-        },
+#[post("/posts/{id}/unlock")]
+async fn unlock_post(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    body: web::Json<UnlockPostRequest>,
+    jwt_guard: Option<JwtMiddleware>
+) -> impl Responder {
+    let post_id = id.into_inner();
+    let viewer = jwt_guard.map(|guard| guard.user_id);
+
+    match data.di_container.post_service.unlock_post(post_id, &body, viewer).await {
+        Ok(api_response) => HttpResponse::Ok().json(api_response),
         Err(e) => {
-            error!("Failed to fetch post relation: {:?}", e);
-            HttpResponse::InternalServerError().json(json!({
-                "status": "error",
-                "message": "Failed to fetch post relation",
-            }))
+            error!("Failed to unlock post {}: {:?}", post_id, e);
+            e.error_response()
         }
     }
 }
@@ -88,59 +206,118 @@ async fn get_post_relation(data: web::Data<AppState>, id: web::Path<i32>, _jwt_g
 async fn create_post(
     data: web::Data<AppState>,
     body: web::Json<CreatePostRequest>,
-    _jwt_guard: JwtMiddleware
+    jwt_guard: JwtMiddleware
 ) -> impl Responder {
     info!("Creating post with data: {:?}", body);
-    match data.di_container.post_service.create_post(&body).await {
+    match data.di_container.post_service.create_post(&body, jwt_guard.user_id).await {
         Ok(post) => {
             info!("Post created successfully: {:?}", post);
             HttpResponse::Created().json(post)
         },
         Err(e) => {
             error!("Failed to create post: {:?}", e);
-            HttpResponse::InternalServerError().json(json!({
-                "status": "error",
-                "message": "Failed to create post",
-            }))
+            e.error_response()
+        }
+    }
+}
+
+#[post("/posts/{id}/translations")]
+async fn create_translation(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    body: web::Json<CreateTranslationRequest>,
+    jwt_guard: JwtMiddleware
+) -> impl Responder {
+    let post_id = id.into_inner();
+
+    match data.di_container.post_service.create_translation(post_id, &body, jwt_guard.user_id).await {
+        Ok(api_response) => {
+            info!("Translation of post {} created: {:?}", post_id, api_response);
+            HttpResponse::Created().json(api_response)
+        },
+        Err(e) => {
+            error!("Failed to translate post {}: {:?}", post_id, e);
+            e.error_response()
         }
     }
 }
 
 #[put("/posts/{id}")]
 async fn update_post(
+    req: HttpRequest,
     data: web::Data<AppState>,
     id: web::Path<i32>,
     body: web::Json<UpdatePostRequest>,
-    _jwt_guard: JwtMiddleware
+    if_match: IfMatch,
+    jwt_guard: JwtMiddleware
 ) -> impl Responder {
 
     let post_id = id.into_inner();
     let mut post_request = body.into_inner();
     post_request.post_id = Some(post_id);
+    post_request.version = if_match.0;
 
     info!("Updating post with ID {} and data: {:?}", post_id, post_request);
-    match data.di_container.post_service.update_post(&post_request).await {
+    match data.di_container.post_service.update_post(&post_request, Some(jwt_guard.user_id), post_token(&req)).await {
         Ok(api_response) => {
             info!("Post updated successfully: {:?}", api_response);
-            HttpResponse::Ok().json(api_response)
+            HttpResponse::Ok()
+                .insert_header((header::ETAG, etag(api_response.data.version)))
+                .json(api_response)
         },
         Err(e) => {
             error!("Failed to update post: {:?}", e);
-            HttpResponse::InternalServerError().json(json!({
-                "status": "error",
-                "message": "Failed to update post",
-            }))
+            e.error_response()
+        }
+    }
+}
+
+#[patch("/posts/{id}")]
+async fn patch_post(
+    req: HttpRequest,
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    body: web::Bytes,
+    if_match: IfMatch,
+    jwt_guard: JwtMiddleware
+) -> impl Responder {
+    let post_id = id.into_inner();
+    let content_type = req.headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    let patch = match PatchDocument::parse(content_type, &body) {
+        Ok(patch) => patch,
+        Err(e) => return ErrorResponse::from(e).error_response(),
+    };
+
+    match data
+        .di_container
+        .post_service
+        .patch_post(post_id, &patch, if_match.0, Some(jwt_guard.user_id), post_token(&req))
+        .await
+    {
+        Ok(api_response) => {
+            info!("Post patched successfully: {:?}", api_response);
+            HttpResponse::Ok()
+                .insert_header((header::ETAG, etag(api_response.data.version)))
+                .json(api_response)
+        },
+        Err(e) => {
+            error!("Failed to patch post: {:?}", e);
+            e.error_response()
         }
     }
 }
 
 #[delete("/posts/{id}")]
-async fn delete_post(data: web::Data<AppState>, id: web::Path<i32>, _jwt_guard: JwtMiddleware) -> impl Responder {
+async fn delete_post(req: HttpRequest, data: web::Data<AppState>, id: web::Path<i32>, if_match: IfMatch, jwt_guard: JwtMiddleware) -> impl Responder {
     info!("Deleting post with ID: {}", id);
     match data
         .di_container
         .post_service
-        .delete_post(id.into_inner())
+        .delete_post(id.into_inner(), jwt_guard.user_id, if_match.0, post_token(&req))
         .await
     {
         Ok(data) => {
@@ -149,10 +326,26 @@ async fn delete_post(data: web::Data<AppState>, id: web::Path<i32>, _jwt_guard:
         },
         Err(e) => {
             error!("Failed to delete post: {:?}", e);
-            HttpResponse::InternalServerError().json(json!({
-                "status": "error",
-                "message": "Failed to delete post",
-            }))
+            e.error_response()
+        }
+    }
+}
+
+#[post("/posts/bulk")]
+async fn bulk_update_posts(
+    data: web::Data<AppState>,
+    body: web::Json<BulkPostRequest>,
+    jwt_guard: JwtMiddleware
+) -> impl Responder {
+    info!("Applying bulk operation {:?} to posts {:?}", body.operation, body.post_ids);
+    match data.di_container.post_service.bulk_update_posts(jwt_guard.user_id, &body).await {
+        Ok(api_response) => {
+            info!("Bulk operation completed: {:?}", api_response);
+            HttpResponse::Ok().json(api_response)
+        },
+        Err(e) => {
+            error!("Failed to apply bulk operation: {:?}", e);
+            e.error_response()
         }
     }
 }
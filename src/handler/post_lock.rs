@@ -0,0 +1,100 @@
+use crate::{middleware::JwtMiddleware, state::AppState};
+use actix_web::{delete, get, post, put, web, HttpRequest, HttpResponse, Responder, ResponseError};
+use tracing::{error, info};
+
+use super::post::post_token;
+
+#[get("/posts/{id}/lock")]
+async fn get_post_lock(
+    req: HttpRequest,
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    let post_id = id.into_inner();
+
+    match data.di_container.post_lock_service.get_lock(post_id, jwt_guard.user_id, post_token(&req)).await {
+        Ok(lock) => HttpResponse::Ok().json(lock),
+        Err(e) => {
+            error!("Failed to fetch edit lock of post {}: {:?}", post_id, e);
+            e.error_response()
+        }
+    }
+}
+
+#[post("/posts/{id}/lock")]
+async fn acquire_post_lock(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    let post_id = id.into_inner();
+
+    match data.di_container.post_lock_service.acquire(post_id, jwt_guard.user_id).await {
+        Ok(lock) => {
+            info!("User {} locked post {}", jwt_guard.user_id, post_id);
+            HttpResponse::Ok().json(lock)
+        }
+        Err(e) => {
+            error!("Failed to lock post {}: {:?}", post_id, e);
+            e.error_response()
+        }
+    }
+}
+
+#[put("/posts/{id}/lock")]
+async fn renew_post_lock(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    let post_id = id.into_inner();
+
+    match data.di_container.post_lock_service.heartbeat(post_id, jwt_guard.user_id).await {
+        Ok(lock) => HttpResponse::Ok().json(lock),
+        Err(e) => {
+            error!("Failed to renew edit lock of post {}: {:?}", post_id, e);
+            e.error_response()
+        }
+    }
+}
+
+#[delete("/posts/{id}/lock")]
+async fn release_post_lock(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    let post_id = id.into_inner();
+
+    match data.di_container.post_lock_service.release(post_id, jwt_guard.user_id).await {
+        Ok(api_response) => {
+            info!("User {} unlocked post {}", jwt_guard.user_id, post_id);
+            HttpResponse::Ok().json(api_response)
+        }
+        Err(e) => {
+            error!("Failed to release edit lock of post {}: {:?}", post_id, e);
+            e.error_response()
+        }
+    }
+}
+
+#[post("/posts/{id}/lock/steal")]
+async fn steal_post_lock(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    let post_id = id.into_inner();
+
+    match data.di_container.post_lock_service.steal(post_id, jwt_guard.user_id).await {
+        Ok(lock) => {
+            info!("User {} took over the edit lock of post {}", jwt_guard.user_id, post_id);
+            HttpResponse::Ok().json(lock)
+        }
+        Err(e) => {
+            error!("Failed to take over edit lock of post {}: {:?}", post_id, e);
+            e.error_response()
+        }
+    }
+}
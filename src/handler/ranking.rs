@@ -0,0 +1,42 @@
+use crate::{domain::Pagination, middleware::JwtMiddleware, state::AppState};
+use actix_web::{get, web, HttpRequest, HttpResponse, Responder, ResponseError};
+use tracing::{error, info};
+
+use super::post::post_token;
+
+#[get("/posts/trending")]
+async fn get_trending_posts(
+    data: web::Data<AppState>,
+    pagination: web::Query<Pagination>,
+    jwt_guard: Option<JwtMiddleware>,
+) -> impl Responder {
+    let viewer = jwt_guard.map(|guard| guard.user_id);
+
+    match data.di_container.ranking_service.get_trending(&pagination, viewer).await {
+        Ok(posts) => HttpResponse::Ok().json(posts),
+        Err(e) => {
+            error!("Failed to fetch trending posts: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+#[get("/posts/{id}/related")]
+async fn get_related_posts(
+    req: HttpRequest,
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: Option<JwtMiddleware>,
+) -> impl Responder {
+    let post_id = id.into_inner();
+    info!("Fetching posts related to post {}", post_id);
+    let viewer = jwt_guard.map(|guard| guard.user_id);
+
+    match data.di_container.ranking_service.get_related(post_id, viewer, post_token(&req)).await {
+        Ok(posts) => HttpResponse::Ok().json(posts),
+        Err(e) => {
+            error!("Failed to fetch posts related to post {}: {:?}", post_id, e);
+            e.error_response()
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{middleware::JwtMiddleware, state::AppState};
+use actix_web::{post, web, HttpRequest, HttpResponse, Responder, ResponseError};
+use tracing::{error, info};
+
+use super::post::post_token;
+
+#[post("/posts/{id}/reactions/{kind}")]
+async fn toggle_post_reaction(
+    req: HttpRequest,
+    data: web::Data<AppState>,
+    path: web::Path<(i32, String)>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    let (post_id, kind) = path.into_inner();
+    info!("Toggling {} reaction on post {} for user {}", kind, post_id, jwt_guard.user_id);
+
+    match data
+        .di_container
+        .reaction_service
+        .toggle_post_reaction(post_id, jwt_guard.user_id, &kind, post_token(&req))
+        .await
+    {
+        Ok(reactions) => HttpResponse::Ok().json(reactions),
+        Err(e) => {
+            error!("Failed to toggle reaction on post {}: {:?}", post_id, e);
+            e.error_response()
+        }
+    }
+}
+
+#[post("/comments/{id}/reactions/{kind}")]
+async fn toggle_comment_reaction(
+    req: HttpRequest,
+    data: web::Data<AppState>,
+    path: web::Path<(i32, String)>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    let (comment_id, kind) = path.into_inner();
+    info!("Toggling {} reaction on comment {} for user {}", kind, comment_id, jwt_guard.user_id);
+
+    match data
+        .di_container
+        .reaction_service
+        .toggle_comment_reaction(comment_id, jwt_guard.user_id, &kind, post_token(&req))
+        .await
+    {
+        Ok(reactions) => HttpResponse::Ok().json(reactions),
+        Err(e) => {
+            error!("Failed to toggle reaction on comment {}: {:?}", comment_id, e);
+            e.error_response()
+        }
+    }
+}
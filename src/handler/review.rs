@@ -0,0 +1,86 @@
+use crate::{domain::{Pagination, ReviewAction, ReviewRequest}, middleware::JwtMiddleware, state::AppState};
+use actix_web::{get, post, web, HttpResponse, Responder, ResponseError};
+use tracing::{error, info};
+
+#[get("/posts/review-queue")]
+async fn get_review_queue(
+    data: web::Data<AppState>,
+    pagination: web::Query<Pagination>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    match data.di_container.post_service.get_review_queue(&pagination, jwt_guard.user_id).await {
+        Ok(posts) => HttpResponse::Ok().json(posts),
+        Err(e) => {
+            error!("Failed to fetch review queue: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+#[post("/posts/{id}/submit")]
+async fn submit_post(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    body: Option<web::Json<ReviewRequest>>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    review_post(data, id.into_inner(), ReviewAction::Submit, body, jwt_guard).await
+}
+
+#[post("/posts/{id}/approve")]
+async fn approve_post(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    body: Option<web::Json<ReviewRequest>>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    review_post(data, id.into_inner(), ReviewAction::Approve, body, jwt_guard).await
+}
+
+#[post("/posts/{id}/request-changes")]
+async fn request_changes(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    body: Option<web::Json<ReviewRequest>>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    review_post(data, id.into_inner(), ReviewAction::RequestChanges, body, jwt_guard).await
+}
+
+#[get("/posts/{id}/transitions")]
+async fn get_post_transitions(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    let post_id = id.into_inner();
+
+    match data.di_container.post_service.get_transitions(post_id, jwt_guard.user_id).await {
+        Ok(transitions) => HttpResponse::Ok().json(transitions),
+        Err(e) => {
+            error!("Failed to fetch transitions of post {}: {:?}", post_id, e);
+            e.error_response()
+        }
+    }
+}
+
+async fn review_post(
+    data: web::Data<AppState>,
+    post_id: i32,
+    action: ReviewAction,
+    body: Option<web::Json<ReviewRequest>>,
+    jwt_guard: JwtMiddleware,
+) -> HttpResponse {
+    let input = body.map(web::Json::into_inner).unwrap_or_default();
+
+    match data.di_container.post_service.review_post(post_id, action, &input, jwt_guard.user_id).await {
+        Ok(api_response) => {
+            info!("Review action {:?} on post {} by user {}", action, post_id, jwt_guard.user_id);
+            HttpResponse::Ok().json(api_response)
+        }
+        Err(e) => {
+            error!("Failed to apply review action {:?} on post {}: {:?}", action, post_id, e);
+            e.error_response()
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::{domain::{Pagination, SearchQuery}, middleware::JwtMiddleware, state::AppState};
+use actix_web::{get, web, HttpResponse, Responder};
+use serde_json::json;
+use tracing::{error, info};
+
+#[get("/search")]
+async fn search_posts(
+    data: web::Data<AppState>,
+    query: web::Query<SearchQuery>,
+    pagination: web::Query<Pagination>,
+    jwt_guard: Option<JwtMiddleware>,
+) -> impl Responder {
+    info!("Searching posts with query: {:?}", query);
+    let viewer = jwt_guard.map(|guard| guard.user_id);
+    match data.di_container.search_service.search(&query, &pagination, viewer).await {
+        Ok(results) => HttpResponse::Ok().json(results),
+        Err(e) => {
+            error!("Failed to search posts: {:?}", e);
+            HttpResponse::InternalServerError().json(json!({
+                "status": "error",
+                "message": "Failed to search posts",
+            }))
+        }
+    }
+}
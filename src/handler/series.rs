@@ -0,0 +1,98 @@
+use crate::{
+    domain::{CreateSeriesRequest, Pagination, ReorderSeriesRequest},
+    middleware::JwtMiddleware,
+    state::AppState,
+};
+use actix_web::{delete, get, post, put, web, HttpResponse, Responder, ResponseError};
+use serde_json::json;
+use tracing::{error, info};
+
+#[get("/series")]
+async fn get_all_series(data: web::Data<AppState>, pagination: web::Query<Pagination>, jwt_guard: Option<JwtMiddleware>) -> impl Responder {
+    info!("Fetching series");
+    let viewer = jwt_guard.map(|guard| guard.user_id);
+
+    match data.di_container.series_service.get_all_series(&pagination, viewer).await {
+        Ok(series) => HttpResponse::Ok().json(series),
+        Err(e) => {
+            error!("Failed to fetch series: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+#[get("/series/{id}")]
+async fn get_series(data: web::Data<AppState>, id: web::Path<i32>, jwt_guard: Option<JwtMiddleware>) -> impl Responder {
+    let id = id.into_inner();
+    info!("Fetching series {}", id);
+    let viewer = jwt_guard.map(|guard| guard.user_id);
+
+    match data.di_container.series_service.get_series(id, viewer).await {
+        Ok(Some(series)) => HttpResponse::Ok().json(series),
+        Ok(None) => HttpResponse::NotFound().json(json!({
+            "status": "fail",
+            "message": "Series not found",
+        })),
+        Err(e) => {
+            error!("Failed to fetch series {}: {:?}", id, e);
+            e.error_response()
+        }
+    }
+}
+
+#[post("/series")]
+async fn create_series(
+    data: web::Data<AppState>,
+    body: web::Json<CreateSeriesRequest>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    info!("Creating series for user {}", jwt_guard.user_id);
+
+    match data.di_container.series_service.create_series(jwt_guard.user_id, &body).await {
+        Ok(series) => HttpResponse::Created().json(series),
+        Err(e) => {
+            error!("Failed to create series: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+#[put("/series/{id}/posts")]
+async fn reorder_series(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    body: web::Json<ReorderSeriesRequest>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    let id = id.into_inner();
+    info!("Reordering series {} for user {}", id, jwt_guard.user_id);
+
+    match data.di_container.series_service.reorder_series(id, jwt_guard.user_id, &body).await {
+        Ok(Some(series)) => HttpResponse::Ok().json(series),
+        Ok(None) => HttpResponse::NotFound().json(json!({
+            "status": "fail",
+            "message": "Series not found",
+        })),
+        Err(e) => {
+            error!("Failed to reorder series {}: {:?}", id, e);
+            e.error_response()
+        }
+    }
+}
+
+#[delete("/series/{id}")]
+async fn delete_series(data: web::Data<AppState>, id: web::Path<i32>, jwt_guard: JwtMiddleware) -> impl Responder {
+    let id = id.into_inner();
+
+    match data.di_container.series_service.delete_series(id, jwt_guard.user_id).await {
+        Ok(Some(response)) => HttpResponse::Ok().json(response),
+        Ok(None) => HttpResponse::NotFound().json(json!({
+            "status": "fail",
+            "message": "Series not found",
+        })),
+        Err(e) => {
+            error!("Failed to delete series {}: {:?}", id, e);
+            e.error_response()
+        }
+    }
+}
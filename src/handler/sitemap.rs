@@ -0,0 +1,37 @@
+use crate::state::AppState;
+use actix_web::{get, web, HttpRequest, HttpResponse, Responder, ResponseError};
+use serde_json::json;
+use tracing::{error, info};
+
+use super::feed::xml_response;
+
+#[get("/sitemap.xml")]
+async fn get_sitemap(req: HttpRequest, data: web::Data<AppState>) -> impl Responder {
+    info!("Rendering sitemap");
+
+    match data.di_container.sitemap_service.get_sitemap().await {
+        Ok(sitemap) => xml_response(&req, sitemap),
+        Err(e) => {
+            error!("Failed to render sitemap: {:?}", e);
+            e.error_response()
+        }
+    }
+}
+
+#[get("/sitemaps/{page}.xml")]
+async fn get_sitemap_page(req: HttpRequest, data: web::Data<AppState>, page: web::Path<usize>) -> impl Responder {
+    let page = page.into_inner();
+    info!("Rendering sitemap page {}", page);
+
+    match data.di_container.sitemap_service.get_sitemap_page(page).await {
+        Ok(Some(sitemap)) => xml_response(&req, sitemap),
+        Ok(None) => HttpResponse::NotFound().json(json!({
+            "status": "fail",
+            "message": "Sitemap not found",
+        })),
+        Err(e) => {
+            error!("Failed to render sitemap page {}: {:?}", page, e);
+            e.error_response()
+        }
+    }
+}
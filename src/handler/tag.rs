@@ -0,0 +1,43 @@
+use crate::{middleware::JwtMiddleware, state::AppState};
+use actix_web::{get, web, HttpResponse, Responder};
+use serde_json::json;
+use tracing::{error, info};
+
+#[get("/tags")]
+async fn get_tags(data: web::Data<AppState>, jwt_guard: Option<JwtMiddleware>) -> impl Responder {
+    info!("Fetching all tags");
+
+    let viewer = jwt_guard.map(|guard| guard.user_id);
+    match data.di_container.tag_service.get_tags(viewer).await {
+        Ok(tags) => HttpResponse::Ok().json(tags),
+        Err(e) => {
+            error!("Failed to fetch tags: {:?}", e);
+            HttpResponse::InternalServerError().json(json!({
+                "status": "error",
+                "message": "Failed to fetch tags",
+            }))
+        }
+    }
+}
+
+#[get("/tags/{slug}/posts")]
+async fn get_tag_posts(data: web::Data<AppState>, slug: web::Path<String>, jwt_guard: Option<JwtMiddleware>) -> impl Responder {
+    let slug = slug.into_inner();
+    info!("Fetching posts for tag: {}", slug);
+
+    let viewer = jwt_guard.map(|guard| guard.user_id);
+    match data.di_container.tag_service.get_tag_posts(&slug, viewer).await {
+        Ok(Some(posts)) => HttpResponse::Ok().json(posts),
+        Ok(None) => HttpResponse::NotFound().json(json!({
+            "status": "fail",
+            "message": "Tag not found",
+        })),
+        Err(e) => {
+            error!("Failed to fetch posts for tag {}: {:?}", slug, e);
+            HttpResponse::InternalServerError().json(json!({
+                "status": "error",
+                "message": "Failed to fetch posts",
+            }))
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{domain::TrashKind, middleware::JwtMiddleware, state::AppState};
+use actix_web::{get, post, web, HttpResponse, Responder};
+use serde_json::json;
+use tracing::{error, info};
+
+#[get("/trash")]
+async fn get_trash(data: web::Data<AppState>, jwt_guard: JwtMiddleware) -> impl Responder {
+    info!("Fetching trash for user {}", jwt_guard.user_id);
+    match data.di_container.trash_service.get_trash(jwt_guard.user_id).await {
+        Ok(items) => HttpResponse::Ok().json(items),
+        Err(e) => {
+            error!("Failed to fetch trash: {:?}", e);
+            HttpResponse::InternalServerError().json(json!({
+                "status": "error",
+                "message": "Failed to fetch trash",
+            }))
+        }
+    }
+}
+
+#[post("/trash/{kind}/{id}/restore")]
+async fn restore_trash_item(
+    data: web::Data<AppState>,
+    path: web::Path<(TrashKind, i32)>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    let (kind, id) = path.into_inner();
+    info!("Restoring {:?} {} for user {}", kind, id, jwt_guard.user_id);
+
+    match data.di_container.trash_service.restore(kind, id, jwt_guard.user_id).await {
+        Ok(Some(response)) => HttpResponse::Ok().json(response),
+        Ok(None) => HttpResponse::NotFound().json(json!({
+            "status": "fail",
+            "message": "Item not found in trash",
+        })),
+        Err(e) => {
+            error!("Failed to restore {:?} {}: {:?}", kind, id, e);
+            HttpResponse::InternalServerError().json(json!({
+                "status": "error",
+                "message": "Failed to restore item",
+            }))
+        }
+    }
+}
@@ -1,8 +1,9 @@
-use actix_web::{post, put, delete, get, web, HttpResponse, Responder};
+use actix_web::{http::header, post, put, patch, delete, get, web, HttpRequest, HttpResponse, Responder, ResponseError};
 use serde_json::json;
-use crate::domain::{CreateUserRequest, UpdateUserRequest};
+use crate::domain::{CreateUserRequest, ErrorResponse, PatchDocument, UpdateUserRequest};
 use crate::state::AppState;
-use crate::middleware::JwtMiddleware;
+use crate::middleware::{IfMatch, JwtMiddleware};
+use crate::utils::etag;
 
 #[post("/user")]
 async fn create_user(data: web::Data<AppState>, _jwt_guard: JwtMiddleware, body: web::Json<CreateUserRequest>) -> impl Responder {
@@ -51,6 +52,26 @@ async fn update_user(data: web::Data<AppState>, _jwt_guard: JwtMiddleware, id: w
     }
 }
 
+#[patch("/user/{id}")]
+async fn patch_user(req: HttpRequest, data: web::Data<AppState>, jwt_guard: JwtMiddleware, id: web::Path<i32>, body: web::Bytes, if_match: IfMatch) -> impl Responder {
+    let content_type = req.headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    let patch = match PatchDocument::parse(content_type, &body) {
+        Ok(patch) => patch,
+        Err(e) => return ErrorResponse::from(e).error_response(),
+    };
+
+    match data.di_container.user_service.patch_user(id.into_inner(), &patch, if_match.0, jwt_guard.user_id).await {
+        Ok(response) => HttpResponse::Ok()
+            .insert_header((header::ETAG, etag(response.data.version)))
+            .json(response),
+        Err(e) => e.error_response(),
+    }
+}
+
 #[delete("/user")]
 async fn delete_user(data: web::Data<AppState>,_jwt_guard: JwtMiddleware, email: web::Path<String>) -> impl Responder {
     match data.di_container.user_service.delete_user(&email).await {
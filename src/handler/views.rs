@@ -0,0 +1,41 @@
+use crate::{domain::ViewStatsQuery, middleware::JwtMiddleware, state::AppState};
+use actix_web::{get, web, HttpResponse, Responder, ResponseError};
+use tracing::{error, info};
+
+#[get("/posts/{id}/views")]
+async fn get_post_views(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    query: web::Query<ViewStatsQuery>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    let post_id = id.into_inner();
+    info!("Fetching views for post {}", post_id);
+
+    match data.di_container.view_service.get_post_views(post_id, &query, jwt_guard.user_id).await {
+        Ok(stats) => HttpResponse::Ok().json(stats),
+        Err(e) => {
+            error!("Failed to fetch views for post {}: {:?}", post_id, e);
+            e.error_response()
+        }
+    }
+}
+
+#[get("/authors/{id}/views")]
+async fn get_author_views(
+    data: web::Data<AppState>,
+    id: web::Path<i32>,
+    query: web::Query<ViewStatsQuery>,
+    jwt_guard: JwtMiddleware,
+) -> impl Responder {
+    let user_id = id.into_inner();
+    info!("Fetching views for author {}", user_id);
+
+    match data.di_container.view_service.get_author_views(user_id, &query, jwt_guard.user_id).await {
+        Ok(stats) => HttpResponse::Ok().json(stats),
+        Err(e) => {
+            error!("Failed to fetch views for author {}: {:?}", user_id, e);
+            e.error_response()
+        }
+    }
+}
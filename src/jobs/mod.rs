@@ -0,0 +1,7 @@
+mod trash;
+mod views;
+mod ranking;
+
+pub use self::trash::spawn_trash_purge;
+pub use self::views::spawn_view_flush;
+pub use self::ranking::spawn_ranking_refresh;
\ No newline at end of file
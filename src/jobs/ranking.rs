@@ -0,0 +1,20 @@
+use tokio::time;
+use tracing::{error, info};
+
+use crate::{abstract_trait::DynRankingService, config::RankingConfig};
+
+/// Periodically recomputes the trending and related posts.
+pub fn spawn_ranking_refresh(service: DynRankingService, config: RankingConfig) {
+    tokio::spawn(async move {
+        let mut interval = time::interval(config.refresh_interval);
+
+        loop {
+            interval.tick().await;
+
+            match service.refresh().await {
+                Ok((trending, related)) => info!("Ranked {} trending posts and {} related posts", trending, related),
+                Err(e) => error!("Failed to refresh post rankings: {:?}", e),
+            }
+        }
+    });
+}
@@ -0,0 +1,21 @@
+use tokio::time;
+use tracing::{error, info};
+
+use crate::{abstract_trait::DynTrashService, config::TrashConfig};
+
+/// Periodically removes trashed items older than the configured retention.
+pub fn spawn_trash_purge(service: DynTrashService, config: TrashConfig) {
+    tokio::spawn(async move {
+        let mut interval = time::interval(config.purge_interval);
+
+        loop {
+            interval.tick().await;
+
+            match service.purge_expired().await {
+                Ok(0) => {}
+                Ok(purged) => info!("Purged {} trashed items", purged),
+                Err(e) => error!("Failed to purge trash: {:?}", e),
+            }
+        }
+    });
+}
@@ -0,0 +1,21 @@
+use tokio::time;
+use tracing::{error, info};
+
+use crate::{abstract_trait::DynViewService, config::ViewConfig};
+
+/// Periodically writes buffered post views to the database.
+pub fn spawn_view_flush(service: DynViewService, config: ViewConfig) {
+    tokio::spawn(async move {
+        let mut interval = time::interval(config.flush_interval);
+
+        loop {
+            interval.tick().await;
+
+            match service.flush().await {
+                Ok(0) => {}
+                Ok(views) => info!("Flushed {} post views", views),
+                Err(e) => error!("Failed to flush post views: {:?}", e),
+            }
+        }
+    });
+}
@@ -7,4 +7,6 @@ pub mod service;
 pub mod config;
 pub mod state;
 pub mod middleware;
-pub mod handler;
\ No newline at end of file
+pub mod handler;
+pub mod storage;
+pub mod jobs;
\ No newline at end of file
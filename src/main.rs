@@ -1,6 +1,7 @@
 use actix_cors::Cors;
+use actix_files::Files;
 use actix_web::{http::header, middleware::Logger, web::Data, App, HttpServer};
-use example_crud_blog_seaorm::{handler::router_config, state};
+use example_crud_blog_seaorm::{config::{PostLockConfig, RankingConfig, ReactionConfig, SiteConfig, TrashConfig, UploadConfig, ViewConfig}, handler::{router_config, POST_TOKEN_HEADER}, jobs::{spawn_ranking_refresh, spawn_trash_purge, spawn_view_flush}, state};
 use sea_orm::{Database, DatabaseConnection};
 use example_crud_blog_seaorm::utils::tracing;
 
@@ -21,21 +22,43 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
 
 
-    let app_state = state::AppState::new(db.clone(), &jwt_secret);
+    let upload_config = UploadConfig::from_env();
+    std::fs::create_dir_all(&upload_config.dir)?;
+
+    let trash_config = TrashConfig::from_env();
+
+    let view_config = ViewConfig::from_env();
+    let reaction_config = ReactionConfig::from_env();
+    let site_config = SiteConfig::from_env();
+    let ranking_config = RankingConfig::from_env();
+    let post_lock_config = PostLockConfig::from_env();
+
+    let app_state = state::AppState::new(db.clone(), &jwt_secret, upload_config.clone(), trash_config.clone(), view_config.clone(), reaction_config, site_config, ranking_config.clone(), post_lock_config);
+
+    spawn_trash_purge(app_state.di_container.trash_service.clone(), trash_config);
+
+    let view_service = app_state.di_container.view_service.clone();
+    spawn_view_flush(view_service.clone(), view_config);
+
+    spawn_ranking_refresh(app_state.di_container.ranking_service.clone(), ranking_config);
 
     HttpServer::new(move || {
         let cors = Cors::default()
             .allowed_origin("http://localhost:3000")
-            .allowed_methods(vec!["GET", "POST", "PATCH", "DELETE"])
+            .allowed_methods(vec!["GET", "POST", "PUT", "PATCH", "DELETE"])
             .allowed_headers(vec![
                 header::CONTENT_TYPE,
                 header::AUTHORIZATION,
                 header::ACCEPT,
+                header::IF_MATCH,
+                header::HeaderName::from_static(POST_TOKEN_HEADER),
             ])
+            .expose_headers(vec![header::ETAG])
             .supports_credentials();
 
         App::new()
             .configure(router_config)
+            .service(Files::new(&upload_config.public_path, &upload_config.dir))
             .app_data(Data::new(app_state.clone()))
             .wrap(cors)
             .wrap(Logger::default())
@@ -45,5 +68,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     .await
     .expect("Failed");
 
+    // Write views buffered since the last flush before exiting.
+    if let Err(e) = view_service.flush().await {
+        tracing::error!("Failed to flush post views on shutdown: {:?}", e);
+    }
+
     Ok(())
 }
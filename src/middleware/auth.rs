@@ -31,6 +31,7 @@ impl FromRequest for JwtMiddleware {
             let json_error = ErrorResponse {
                 status: "fail".to_string(),
                 message: "You are not logged in, please provide token".to_string(),
+                status_code: 401,
             };
             return ready(Err(ErrorUnauthorized(json_error)));
         }
@@ -47,6 +48,7 @@ impl FromRequest for JwtMiddleware {
                 let json_error = ErrorResponse {
                     status: "fail".to_string(),
                     message: "Invalid token".to_string(),
+                    status_code: 401,
                 };
                 return ready(Err(ErrorUnauthorized(json_error)));
             }
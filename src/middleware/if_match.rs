@@ -0,0 +1,34 @@
+use std::future::{ready, Ready};
+use actix_web::error::ErrorPreconditionFailed;
+use actix_web::{dev::Payload, Error as ActixWebError};
+use actix_web::{http, FromRequest, HttpRequest};
+
+use crate::domain::ErrorResponse;
+use crate::utils::{parse_if_match, AppError};
+
+/// Expected resource version taken from the `If-Match` request header.
+///
+/// `None` when the header is missing or `*`, so the write is unconditional.
+pub struct IfMatch(pub Option<i32>);
+
+impl FromRequest for IfMatch {
+    type Error = ActixWebError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        let header = match req.headers().get(http::header::IF_MATCH) {
+            Some(header) => header,
+            None => return ready(Ok(IfMatch(None))),
+        };
+
+        let version = header
+            .to_str()
+            .map_err(|_| AppError::PreconditionFailed("Invalid If-Match header".to_string()))
+            .and_then(parse_if_match);
+
+        match version {
+            Ok(version) => ready(Ok(IfMatch(version))),
+            Err(e) => ready(Err(ErrorPreconditionFailed(ErrorResponse::from(e)))),
+        }
+    }
+}
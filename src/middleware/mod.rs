@@ -1,3 +1,5 @@
 mod auth;
+mod if_match;
 
-pub use auth::JwtMiddleware;
\ No newline at end of file
+pub use auth::JwtMiddleware;
+pub use if_match::IfMatch;
\ No newline at end of file
@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set,
+};
+
+use crate::abstract_trait::BookmarkRepositoryTrait;
+use crate::domain::{Pagination, VisibilityScope};
+use crate::entities::sea_orm_active_enums::PostStatus;
+use crate::entities::{bookmarks, posts, reading_lists, Bookmarks, Posts, ReadingLists};
+use crate::repository::visibility::visible_posts;
+
+pub struct BookmarkRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl BookmarkRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+
+    /// Counts bookmarks of live posts per reading list.
+    async fn count_bookmarks(&self, list_ids: &[i32]) -> Result<HashMap<i32, i64>, DbErr> {
+        if list_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let counts: Vec<(i32, i64)> = Bookmarks::find()
+            .select_only()
+            .column(bookmarks::Column::ReadingListId)
+            .column_as(Expr::cust("COUNT(*)"), "count")
+            .inner_join(Posts)
+            .filter(posts::Column::DeletedAt.is_null())
+            .filter(bookmarks::Column::ReadingListId.is_in(list_ids.to_vec()))
+            .group_by(bookmarks::Column::ReadingListId)
+            .into_tuple()
+            .all(&self.db_pool)
+            .await?;
+
+        Ok(counts.into_iter().collect())
+    }
+
+    async fn find_owned_list(&self, id: i32, user_id: i32) -> Result<Option<reading_lists::Model>, DbErr> {
+        ReadingLists::find_by_id(id)
+            .filter(reading_lists::Column::UserId.eq(user_id))
+            .one(&self.db_pool)
+            .await
+    }
+}
+
+#[async_trait]
+impl BookmarkRepositoryTrait for BookmarkRepository {
+    async fn add_bookmark(&self, user_id: i32, post_id: i32, reading_list_id: Option<i32>) -> Result<(bookmarks::Model, posts::Model), DbErr> {
+        let post = Posts::find_by_id(post_id)
+            .filter(posts::Column::DeletedAt.is_null())
+            .one(&self.db_pool)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Post not found".to_owned()))?;
+
+        if let Some(list_id) = reading_list_id {
+            self.find_owned_list(list_id, user_id)
+                .await?
+                .ok_or(DbErr::RecordNotFound("Reading list not found".to_owned()))?;
+        }
+
+        Bookmarks::insert(bookmarks::ActiveModel {
+            user_id: Set(user_id),
+            post_id: Set(post_id),
+            reading_list_id: Set(reading_list_id),
+            ..Default::default()
+        })
+        .on_conflict(
+            OnConflict::columns([bookmarks::Column::UserId, bookmarks::Column::PostId])
+                .update_column(bookmarks::Column::ReadingListId)
+                .to_owned(),
+        )
+        .exec_without_returning(&self.db_pool)
+        .await?;
+
+        let bookmark = Bookmarks::find()
+            .filter(bookmarks::Column::UserId.eq(user_id))
+            .filter(bookmarks::Column::PostId.eq(post_id))
+            .one(&self.db_pool)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Bookmark not found".to_owned()))?;
+
+        Ok((bookmark, post))
+    }
+
+    async fn remove_bookmark(&self, user_id: i32, post_id: i32) -> Result<bool, DbErr> {
+        let result = Bookmarks::delete_many()
+            .filter(bookmarks::Column::UserId.eq(user_id))
+            .filter(bookmarks::Column::PostId.eq(post_id))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn find_bookmarks(
+        &self,
+        user_id: i32,
+        reading_list_id: Option<i32>,
+        scope: &VisibilityScope,
+        pagination: &Pagination,
+    ) -> Result<(Vec<(bookmarks::Model, posts::Model)>, u64), DbErr> {
+        let mut query = Bookmarks::find()
+            .inner_join(Posts)
+            .filter(bookmarks::Column::UserId.eq(user_id))
+            .filter(posts::Column::DeletedAt.is_null())
+            .filter(visible_posts(scope));
+
+        if let Some(list_id) = reading_list_id {
+            query = query.filter(bookmarks::Column::ReadingListId.eq(list_id));
+        }
+
+        let total = query.clone().count(&self.db_pool).await?;
+
+        let bookmarks = query
+            .select_also(Posts)
+            .order_by_desc(bookmarks::Column::CreatedAt)
+            .order_by_desc(bookmarks::Column::Id)
+            .limit(pagination.limit())
+            .offset(pagination.offset())
+            .all(&self.db_pool)
+            .await?
+            .into_iter()
+            .filter_map(|(bookmark, post)| post.map(|post| (bookmark, post)))
+            .collect();
+
+        Ok((bookmarks, total))
+    }
+
+    async fn find_bookmarked(&self, user_id: i32, post_ids: &[i32]) -> Result<Vec<i32>, DbErr> {
+        if post_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Bookmarks::find()
+            .select_only()
+            .column(bookmarks::Column::PostId)
+            .filter(bookmarks::Column::UserId.eq(user_id))
+            .filter(bookmarks::Column::PostId.is_in(post_ids.to_vec()))
+            .into_tuple()
+            .all(&self.db_pool)
+            .await
+    }
+
+    async fn create_reading_list(&self, user_id: i32, name: &str) -> Result<reading_lists::Model, DbErr> {
+        reading_lists::ActiveModel {
+            user_id: Set(user_id),
+            name: Set(name.to_string()),
+            ..Default::default()
+        }
+        .insert(&self.db_pool)
+        .await
+    }
+
+    async fn find_reading_lists(&self, user_id: i32) -> Result<Vec<(reading_lists::Model, i64)>, DbErr> {
+        let lists = ReadingLists::find()
+            .filter(reading_lists::Column::UserId.eq(user_id))
+            .order_by_asc(reading_lists::Column::Name)
+            .all(&self.db_pool)
+            .await?;
+
+        let list_ids: Vec<i32> = lists.iter().map(|list| list.id).collect();
+        let counts = self.count_bookmarks(&list_ids).await?;
+
+        Ok(lists
+            .into_iter()
+            .map(|list| {
+                let count = counts.get(&list.id).copied().unwrap_or_default();
+                (list, count)
+            })
+            .collect())
+    }
+
+    async fn find_reading_list(&self, id: i32, user_id: i32) -> Result<Option<(reading_lists::Model, i64)>, DbErr> {
+        let list = match self.find_owned_list(id, user_id).await? {
+            Some(list) => list,
+            None => return Ok(None),
+        };
+
+        let count = self.count_bookmarks(&[list.id]).await?
+            .get(&list.id)
+            .copied()
+            .unwrap_or_default();
+
+        Ok(Some((list, count)))
+    }
+
+    async fn find_reading_list_by_token(&self, token: &str) -> Result<Option<reading_lists::Model>, DbErr> {
+        ReadingLists::find()
+            .filter(reading_lists::Column::ShareToken.eq(token))
+            .one(&self.db_pool)
+            .await
+    }
+
+    async fn update_reading_list(&self, id: i32, user_id: i32, name: &str) -> Result<bool, DbErr> {
+        let list = match self.find_owned_list(id, user_id).await? {
+            Some(list) => list,
+            None => return Ok(false),
+        };
+
+        let mut list: reading_lists::ActiveModel = list.into();
+        list.name = Set(name.to_string());
+        list.update(&self.db_pool).await?;
+
+        Ok(true)
+    }
+
+    async fn set_share_token(&self, id: i32, user_id: i32, token: Option<String>) -> Result<bool, DbErr> {
+        let list = match self.find_owned_list(id, user_id).await? {
+            Some(list) => list,
+            None => return Ok(false),
+        };
+
+        let mut list: reading_lists::ActiveModel = list.into();
+        list.share_token = Set(token);
+        list.update(&self.db_pool).await?;
+
+        Ok(true)
+    }
+
+    async fn delete_reading_list(&self, id: i32, user_id: i32) -> Result<bool, DbErr> {
+        let result = ReadingLists::delete_many()
+            .filter(reading_lists::Column::Id.eq(id))
+            .filter(reading_lists::Column::UserId.eq(user_id))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn find_reading_list_posts(&self, id: i32, scope: &VisibilityScope, pagination: &Pagination) -> Result<(Vec<posts::Model>, u64), DbErr> {
+        let query = Posts::find()
+            .inner_join(Bookmarks)
+            .filter(bookmarks::Column::ReadingListId.eq(id))
+            .filter(posts::Column::DeletedAt.is_null())
+            .filter(posts::Column::Status.eq(PostStatus::Published))
+            .filter(visible_posts(scope));
+
+        let total = query.clone().count(&self.db_pool).await?;
+
+        let posts = query
+            .order_by_desc(bookmarks::Column::CreatedAt)
+            .order_by_desc(posts::Column::Id)
+            .limit(pagination.limit())
+            .offset(pagination.offset())
+            .all(&self.db_pool)
+            .await?;
+
+        Ok((posts, total))
+    }
+}
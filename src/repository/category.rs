@@ -1,9 +1,12 @@
 use async_trait::async_trait;
-use sea_orm::{ActiveModelTrait, DatabaseConnection, DbErr, EntityTrait,  Set};
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, Set, TransactionTrait};
+use serde_json::Value;
 
 use crate::domain::{CreateCategoryRequest, UpdateCategoryRequest};
 use crate::entities::{categories, Categories};
 use crate::abstract_trait::CategoryRepositoryTrait;
+use crate::repository::version::bump_version;
 
 
 
@@ -22,12 +25,14 @@ impl CategoryRepository{
 impl CategoryRepositoryTrait for CategoryRepository {
     async fn find_all(&self) -> Result<Vec<categories::Model>, DbErr> {
         Categories::find()
+            .filter(categories::Column::DeletedAt.is_null())
             .all(&self.db_pool)
             .await
     }
 
     async fn find_by_id(&self, id: i32) -> Result<Option<categories::Model>, DbErr> {
         Categories::find_by_id(id)
+            .filter(categories::Column::DeletedAt.is_null())
             .one(&self.db_pool)
             .await
     }
@@ -47,26 +52,91 @@ impl CategoryRepositoryTrait for CategoryRepository {
             None => return Err(DbErr::Custom("Category ID is required".to_string())), 
         };
 
-        let mut category: categories::ActiveModel = Categories::find_by_id(id)
-            .one(&self.db_pool)
+        let txn = self.db_pool.begin().await?;
+
+        let category = Categories::find_by_id(id)
+            .filter(categories::Column::DeletedAt.is_null())
+            .one(&txn)
             .await?
-            .ok_or(DbErr::Custom("Category not found".to_string()))?
-            .into();
+            .ok_or(DbErr::RecordNotFound("Category not found".to_string()))?;
+
+        bump_version::<Categories, _>(
+            &txn,
+            categories::Column::Id,
+            categories::Column::Version,
+            categories::Column::UpdatedAt,
+            category.id,
+            input.version.unwrap_or(category.version),
+        )
+        .await?;
+
+        let mut category: categories::ActiveModel = category.into();
 
         if let Some(name) = &input.name {
             category.name = Set(name.clone());
         }
 
-        category.update(&self.db_pool).await
+        let category = category.update(&txn).await?;
+
+        txn.commit().await?;
+
+        Ok(category)
     }
 
-    async fn delete(&self, id: i32) -> Result<(), DbErr> {
-        let category: categories::ActiveModel = Categories::find_by_id(id)
-            .one(&self.db_pool)
+    async fn set_metadata_schema(&self, id: i32, schema: Option<&Value>, expected_version: Option<i32>) -> Result<categories::Model, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        let category = Categories::find_by_id(id)
+            .filter(categories::Column::DeletedAt.is_null())
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Category not found".to_string()))?;
+
+        bump_version::<Categories, _>(
+            &txn,
+            categories::Column::Id,
+            categories::Column::Version,
+            categories::Column::UpdatedAt,
+            category.id,
+            expected_version.unwrap_or(category.version),
+        )
+        .await?;
+
+        let mut category: categories::ActiveModel = category.into();
+        category.metadata_schema = Set(schema.cloned());
+
+        let category = category.update(&txn).await?;
+
+        txn.commit().await?;
+
+        Ok(category)
+    }
+
+    async fn delete(&self, id: i32, deleted_by: i32, expected_version: Option<i32>) -> Result<(), DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        let category = Categories::find_by_id(id)
+            .filter(categories::Column::DeletedAt.is_null())
+            .one(&txn)
             .await?
-            .ok_or(DbErr::Custom("Category not found".to_string()))?
-            .into();
+            .ok_or(DbErr::RecordNotFound("Category not found".to_string()))?;
+
+        bump_version::<Categories, _>(
+            &txn,
+            categories::Column::Id,
+            categories::Column::Version,
+            categories::Column::UpdatedAt,
+            category.id,
+            expected_version.unwrap_or(category.version),
+        )
+        .await?;
+
+        let mut category: categories::ActiveModel = category.into();
+        category.deleted_at = Set(Some(Utc::now().into()));
+        category.deleted_by = Set(Some(deleted_by));
+
+        category.update(&txn).await?;
 
-        category.delete(&self.db_pool).await.map(|_| ())
+        txn.commit().await
     }
 }
@@ -1,9 +1,12 @@
 use async_trait::async_trait;
-use sea_orm::{ActiveModelTrait, DatabaseConnection, DbErr, EntityTrait,  Set};
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait};
 
-use crate::domain::{CreateCommentRequest, UpdateCommentRequest};
-use crate::entities::{comments, Comments};
+use crate::domain::{CreateCommentRequest, Pagination, PatchCommentRequest, UpdateCommentRequest, VisibilityScope};
+use crate::entities::{comments, posts, Comments, Posts};
 use crate::abstract_trait::CommentRepositoryTrait;
+use crate::repository::version::bump_version;
+use crate::repository::visibility::visible_posts;
 
 pub struct CommentRepository {
     db_pool: DatabaseConnection,
@@ -17,18 +20,41 @@ impl CommentRepository {
 
 #[async_trait]
 impl CommentRepositoryTrait for CommentRepository {
-    async fn find_all(&self) -> Result<Vec<comments::Model>, DbErr> {
+    async fn find_all(&self, scope: &VisibilityScope) -> Result<Vec<comments::Model>, DbErr> {
         Comments::find()
+            .inner_join(Posts)
+            .filter(comments::Column::DeletedAt.is_null())
+            .filter(posts::Column::DeletedAt.is_null())
+            .filter(visible_posts(scope))
             .all(&self.db_pool)
             .await
     }
 
     async fn find_by_id(&self, id: i32) -> Result<Option<comments::Model>, DbErr> {
         Comments::find_by_id(id)
+            .filter(comments::Column::DeletedAt.is_null())
             .one(&self.db_pool)
             .await
     }
 
+    async fn find_by_post(&self, post_id: i32, pagination: &Pagination) -> Result<(Vec<comments::Model>, u64), DbErr> {
+        let query = Comments::find()
+            .filter(comments::Column::IdPostComment.eq(post_id))
+            .filter(comments::Column::DeletedAt.is_null());
+
+        let total = query.clone().count(&self.db_pool).await?;
+
+        let comments = query
+            .order_by_asc(comments::Column::CreatedAt)
+            .order_by_asc(comments::Column::Id)
+            .limit(pagination.limit())
+            .offset(pagination.offset())
+            .all(&self.db_pool)
+            .await?;
+
+        Ok((comments, total))
+    }
+
     async fn create(&self, input: &CreateCommentRequest) -> Result<comments::Model, DbErr> {
         let comment = comments::ActiveModel {
             id_post_comment: Set(input.id_post_comment),
@@ -41,25 +67,80 @@ impl CommentRepositoryTrait for CommentRepository {
     }
 
     async fn update(&self, input: &UpdateCommentRequest) -> Result<comments::Model, DbErr> {
-        let mut comment: comments::ActiveModel = Comments::find_by_id(input.id_post_comment)
-            .one(&self.db_pool)
+        let id = match input.id {
+            Some(id) => id,
+            None => return Err(DbErr::Custom("Comment ID is required".to_string())),
+        };
+
+        self.patch(&PatchCommentRequest {
+            id,
+            user_name_comment: Some(input.user_name_comment.clone()),
+            comment: Some(input.comment.clone()),
+            version: input.version,
+        })
+        .await
+    }
+
+    async fn patch(&self, input: &PatchCommentRequest) -> Result<comments::Model, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        let comment = Comments::find_by_id(input.id)
+            .filter(comments::Column::DeletedAt.is_null())
+            .one(&txn)
             .await?
-            .ok_or(DbErr::Custom("Comment not found".to_string()))?
-            .into();
+            .ok_or(DbErr::RecordNotFound("Comment not found".to_string()))?;
+
+        bump_version::<Comments, _>(
+            &txn,
+            comments::Column::Id,
+            comments::Column::Version,
+            comments::Column::UpdatedAt,
+            comment.id,
+            input.version.unwrap_or(comment.version),
+        )
+        .await?;
+
+        let mut comment: comments::ActiveModel = comment.into();
+
+        if let Some(user_name_comment) = &input.user_name_comment {
+            comment.user_name_comment = Set(user_name_comment.clone());
+        }
+        if let Some(text) = &input.comment {
+            comment.comment = Set(text.clone());
+        }
+
+        let comment = comment.update(&txn).await?;
 
-        comment.user_name_comment = Set(input.user_name_comment.clone());
-        comment.comment = Set(input.comment.clone());
+        txn.commit().await?;
 
-        comment.update(&self.db_pool).await
+        Ok(comment)
     }
 
-    async fn delete(&self, id: i32) -> Result<(), DbErr> {
-        let comment: comments::ActiveModel = Comments::find_by_id(id)
-            .one(&self.db_pool)
+    async fn delete(&self, id: i32, deleted_by: i32, expected_version: Option<i32>) -> Result<(), DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        let comment = Comments::find_by_id(id)
+            .filter(comments::Column::DeletedAt.is_null())
+            .one(&txn)
             .await?
-            .ok_or(DbErr::Custom("Comment not found".to_string()))?
-            .into();
+            .ok_or(DbErr::RecordNotFound("Comment not found".to_string()))?;
+
+        bump_version::<Comments, _>(
+            &txn,
+            comments::Column::Id,
+            comments::Column::Version,
+            comments::Column::UpdatedAt,
+            comment.id,
+            expected_version.unwrap_or(comment.version),
+        )
+        .await?;
+
+        let mut comment: comments::ActiveModel = comment.into();
+        comment.deleted_at = Set(Some(Utc::now().into()));
+        comment.deleted_by = Set(Some(deleted_by));
+
+        comment.update(&txn).await?;
 
-        comment.delete(&self.db_pool).await.map(|_| ())
+        txn.commit().await
     }
 }
@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, Set};
+
+use crate::abstract_trait::MediaRepositoryTrait;
+use crate::domain::CreateMediaRequest;
+use crate::entities::{media, Media};
+
+pub struct MediaRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl MediaRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl MediaRepositoryTrait for MediaRepository {
+    async fn find_by_id(&self, id: i32) -> Result<Option<media::Model>, DbErr> {
+        Media::find_by_id(id)
+            .one(&self.db_pool)
+            .await
+    }
+
+    async fn find_by_key(&self, key: &str) -> Result<Option<media::Model>, DbErr> {
+        Media::find()
+            .filter(media::Column::Key.eq(key))
+            .one(&self.db_pool)
+            .await
+    }
+
+    async fn create(&self, input: &CreateMediaRequest) -> Result<media::Model, DbErr> {
+        let media = media::ActiveModel {
+            key: Set(input.key.clone()),
+            content_type: Set(input.content_type.clone()),
+            size: Set(input.size),
+            user_id: Set(input.user_id),
+            ..Default::default()
+        };
+
+        media.insert(&self.db_pool).await
+    }
+}
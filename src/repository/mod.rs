@@ -2,8 +2,32 @@ mod category;
 mod posts;
 mod comment;
 mod user;
+mod tag;
+mod search;
+mod media;
+mod trash;
+mod version;
+mod visibility;
+mod views;
+mod reaction;
+mod bookmark;
+mod series;
+mod sitemap;
+mod ranking;
+mod post_lock;
 
 pub use self::category::CategoryRepository;
 pub use self::posts::PostRepository;
 pub use self::comment::CommentRepository;
-pub use self::user::UserRepository;
\ No newline at end of file
+pub use self::user::UserRepository;
+pub use self::tag::TagRepository;
+pub use self::search::SearchRepository;
+pub use self::media::MediaRepository;
+pub use self::trash::TrashRepository;
+pub use self::views::ViewRepository;
+pub use self::reaction::ReactionRepository;
+pub use self::bookmark::BookmarkRepository;
+pub use self::series::SeriesRepository;
+pub use self::sitemap::SitemapRepository;
+pub use self::ranking::RankingRepository;
+pub use self::post_lock::PostLockRepository;
\ No newline at end of file
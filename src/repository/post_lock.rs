@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    prelude::DateTimeWithTimeZone, ColumnTrait, ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QuerySelect, Statement, Value,
+};
+
+use crate::abstract_trait::PostLockRepositoryTrait;
+use crate::entities::{post_locks, PostLocks};
+
+/// Takes the lock when it is free, expired, already held by the user, or when `$4` forces it.
+/// A user renewing their own lock keeps its acquisition time. `$3` is the TTL in seconds.
+const ACQUIRE_SQL: &str = r#"
+INSERT INTO post_locks (post_id, user_id, acquired_at, expires_at)
+VALUES ($1, $2, now(), now() + make_interval(secs => $3))
+ON CONFLICT (post_id) DO UPDATE SET
+    user_id = EXCLUDED.user_id,
+    acquired_at = CASE
+        WHEN post_locks.user_id = EXCLUDED.user_id AND post_locks.expires_at > now() THEN post_locks.acquired_at
+        ELSE EXCLUDED.acquired_at
+    END,
+    expires_at = EXCLUDED.expires_at
+WHERE $4 OR post_locks.user_id = EXCLUDED.user_id OR post_locks.expires_at <= now()
+RETURNING post_id, user_id, acquired_at, expires_at"#;
+
+/// Extends an unexpired lock held by the user. `$3` is the TTL in seconds.
+const REFRESH_SQL: &str = r#"
+UPDATE post_locks
+SET expires_at = now() + make_interval(secs => $3)
+WHERE post_id = $1 AND user_id = $2 AND expires_at > now()
+RETURNING post_id, user_id, acquired_at, expires_at"#;
+
+/// The unexpired lock on the post when a user other than `user_id` holds it.
+///
+/// Run it in the same transaction as the write it guards: the shared row lock makes a concurrent
+/// takeover of the edit lock wait until the write is committed.
+pub(crate) async fn find_other_lock<C: ConnectionTrait>(
+    db: &C,
+    post_id: i32,
+    user_id: Option<i32>,
+) -> Result<Option<post_locks::Model>, DbErr> {
+    let now: DateTimeWithTimeZone = Utc::now().into();
+
+    let lock = PostLocks::find_by_id(post_id)
+        .filter(post_locks::Column::ExpiresAt.gt(now))
+        .lock_shared()
+        .one(db)
+        .await?;
+
+    Ok(lock.filter(|lock| Some(lock.user_id) != user_id))
+}
+
+pub struct PostLockRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl PostLockRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl PostLockRepositoryTrait for PostLockRepository {
+    async fn find_lock(&self, post_id: i32) -> Result<Option<post_locks::Model>, DbErr> {
+        let now: DateTimeWithTimeZone = Utc::now().into();
+
+        PostLocks::find_by_id(post_id)
+            .filter(post_locks::Column::ExpiresAt.gt(now))
+            .one(&self.db_pool)
+            .await
+    }
+
+    async fn acquire_lock(&self, post_id: i32, user_id: i32, ttl: Duration, force: bool) -> Result<Option<post_locks::Model>, DbErr> {
+        let values: Vec<Value> = vec![post_id.into(), user_id.into(), ttl.as_secs_f64().into(), force.into()];
+
+        PostLocks::find()
+            .from_raw_sql(Statement::from_sql_and_values(DatabaseBackend::Postgres, ACQUIRE_SQL, values))
+            .one(&self.db_pool)
+            .await
+    }
+
+    async fn refresh_lock(&self, post_id: i32, user_id: i32, ttl: Duration) -> Result<Option<post_locks::Model>, DbErr> {
+        let values: Vec<Value> = vec![post_id.into(), user_id.into(), ttl.as_secs_f64().into()];
+
+        PostLocks::find()
+            .from_raw_sql(Statement::from_sql_and_values(DatabaseBackend::Postgres, REFRESH_SQL, values))
+            .one(&self.db_pool)
+            .await
+    }
+
+    async fn release_lock(&self, post_id: i32, user_id: i32) -> Result<bool, DbErr> {
+        let result = PostLocks::delete_many()
+            .filter(post_locks::Column::PostId.eq(post_id))
+            .filter(post_locks::Column::UserId.eq(user_id))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -1,12 +1,26 @@
+use std::collections::HashMap;
+
 use crate::abstract_trait::PostsRepositoryTrait;
-use crate::domain::{CreatePostRequest, PostRelationResponse, UpdatePostRequest};
-use crate::entities::{comments, posts};
+use crate::domain::{ArchiveMonthResponse, BulkPostOperation, BulkPostOutcome, BulkPostRequest, CreatePostRequest, FeaturePostRequest, FeedScope, PatchPostRequest, Pagination, PostQuery, PostWrite, TagMatch, UpdatePostRequest, VisibilityScope};
+use crate::entities::{categories, featured_posts, post_locks, post_tags, post_transitions, posts, sea_orm_active_enums::{PostStatus, PostVisibility}, tags, FeaturedPosts, PostLocks, PostTransitions, Posts};
+use crate::repository::post_lock::find_other_lock;
+use crate::repository::tag::{add_post_tags, sync_post_tags};
+use crate::repository::version::bump_version;
+use crate::repository::visibility::visible_posts;
+use crate::utils::{render_body, validate_metadata, AppError, ReadingStats, DEFAULT_LOCALE};
 use async_trait::async_trait;
+use chrono::Utc;
+use serde_json::json;
+use sea_orm::sea_query::{Expr, NullOrdering, OnConflict, Query};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, DbErr, EntityTrait, ModelTrait, QueryFilter,
-    Set,
+    prelude::{DateTimeWithTimeZone, Json}, ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, LoaderTrait,
+    Order, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, SqlErr, TransactionTrait,
 };
-use tracing::{error, info};
+use tracing::error;
+
+/// Year and month of a post's creation time, in UTC.
+const ARCHIVE_YEAR: &str = "EXTRACT(YEAR FROM posts.created_at AT TIME ZONE 'UTC')::int4";
+const ARCHIVE_MONTH: &str = "EXTRACT(MONTH FROM posts.created_at AT TIME ZONE 'UTC')::int4";
 
 pub struct PostRepository {
     db_pool: DatabaseConnection,
@@ -20,109 +34,717 @@ impl PostRepository {
 
 #[async_trait]
 impl PostsRepositoryTrait for PostRepository {
-    async fn get_all_posts(&self) -> Result<Vec<posts::Model>, DbErr> {
-        posts::Entity::find().all(&self.db_pool).await
+    async fn get_all_posts(&self, query: &PostQuery, scope: &VisibilityScope) -> Result<Vec<posts::Model>, DbErr> {
+        let mut select = posts::Entity::find()
+            .filter(posts::Column::DeletedAt.is_null())
+            .filter(visible_posts(scope));
+
+        let slugs = query.tag_slugs();
+        if !slugs.is_empty() {
+            let mut tagged = Query::select();
+            tagged
+                .column((post_tags::Entity, post_tags::Column::PostId))
+                .from(post_tags::Entity)
+                .inner_join(
+                    tags::Entity,
+                    Expr::col((tags::Entity, tags::Column::Id))
+                        .equals((post_tags::Entity, post_tags::Column::TagId)),
+                )
+                .and_where(Expr::col((tags::Entity, tags::Column::Slug)).is_in(slugs.clone()));
+
+            if query.tag_match == TagMatch::All {
+                tagged
+                    .group_by_col((post_tags::Entity, post_tags::Column::PostId))
+                    .and_having(
+                        Expr::col((post_tags::Entity, post_tags::Column::TagId))
+                            .count_distinct()
+                            .eq(slugs.len() as i64),
+                    );
+            }
+
+            select = select.filter(posts::Column::Id.in_subquery(tagged.to_owned()));
+        }
+
+        for (key, value) in &query.meta {
+            select = select.filter(Expr::cust_with_values(
+                r#""posts"."metadata" ->> $1 = $2"#,
+                [key.clone(), value.clone()],
+            ));
+        }
+
+        if let Some(locale) = &query.locale {
+            select = select.filter(posts::Column::Locale.eq(locale.as_str()));
+        }
+
+        // Pinned posts lead a category listing, most recently pinned first; posts are pinned to
+        // their category, so other listings order by ID only.
+        if let Some(category_id) = query.category {
+            select = select
+                .filter(posts::Column::CategoryId.eq(category_id))
+                .order_by_with_nulls(posts::Column::PinnedAt, Order::Desc, NullOrdering::Last);
+        }
+
+        select
+            .order_by_asc(posts::Column::Id)
+            .all(&self.db_pool)
+            .await
+    }
+
+    async fn get_featured_posts(&self, scope: &VisibilityScope) -> Result<Vec<(featured_posts::Model, posts::Model)>, DbErr> {
+        let now: DateTimeWithTimeZone = Utc::now().into();
+
+        let featured = FeaturedPosts::find()
+            .find_also_related(Posts)
+            .filter(
+                Condition::any()
+                    .add(featured_posts::Column::ExpiresAt.is_null())
+                    .add(featured_posts::Column::ExpiresAt.gt(now)),
+            )
+            .filter(posts::Column::DeletedAt.is_null())
+            .filter(posts::Column::Status.eq(PostStatus::Published))
+            .filter(visible_posts(scope))
+            .order_by_asc(featured_posts::Column::Position)
+            .order_by_asc(featured_posts::Column::PostId)
+            .all(&self.db_pool)
+            .await?;
+
+        Ok(featured
+            .into_iter()
+            .filter_map(|(featured, post)| post.map(|post| (featured, post)))
+            .collect())
+    }
+
+    async fn feature_post(&self, post_id: i32, input: &FeaturePostRequest) -> Result<(featured_posts::Model, posts::Model), DbErr> {
+        let post = self.get_post(post_id)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Post not found".to_owned()))?;
+
+        FeaturedPosts::insert(featured_posts::ActiveModel {
+            post_id: Set(post_id),
+            position: Set(input.position),
+            expires_at: Set(input.expires_at),
+            ..Default::default()
+        })
+        .on_conflict(
+            OnConflict::column(featured_posts::Column::PostId)
+                .update_columns([featured_posts::Column::Position, featured_posts::Column::ExpiresAt])
+                .to_owned(),
+        )
+        .exec_without_returning(&self.db_pool)
+        .await?;
+
+        let featured = FeaturedPosts::find_by_id(post_id)
+            .one(&self.db_pool)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Featured post not found".to_owned()))?;
+
+        Ok((featured, post))
+    }
+
+    async fn unfeature_post(&self, post_id: i32) -> Result<bool, DbErr> {
+        let result = FeaturedPosts::delete_by_id(post_id)
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn set_pinned(&self, post_id: i32, pinned: bool) -> Result<posts::Model, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        let post = posts::Entity::find_by_id(post_id)
+            .filter(posts::Column::DeletedAt.is_null())
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Post not found".to_owned()))?;
+
+        // Re-pinning keeps the original pin time, and with it the order of pinned posts.
+        if post.pinned_at.is_some() == pinned {
+            return Ok(post);
+        }
+
+        bump_version::<posts::Entity, _>(
+            &txn,
+            posts::Column::Id,
+            posts::Column::Version,
+            posts::Column::UpdatedAt,
+            post.id,
+            post.version,
+        )
+        .await?;
+
+        let mut post: posts::ActiveModel = post.into();
+        post.pinned_at = Set(pinned.then(|| Utc::now().into()));
+        let post = post.update(&txn).await?;
+
+        txn.commit().await?;
+
+        Ok(post)
     }
 
     async fn get_post(&self, post_id: i32) -> Result<Option<posts::Model>, DbErr> {
-        posts::Entity::find_by_id(post_id).one(&self.db_pool).await
+        posts::Entity::find_by_id(post_id)
+            .filter(posts::Column::DeletedAt.is_null())
+            .one(&self.db_pool)
+            .await
     }
 
-    async fn get_post_relation(&self, post_id: i32) -> Result<Vec<PostRelationResponse>, DbErr> {
-        // Log the start of the function
-        info!("Fetching post relation for post ID: {}", post_id);
+    async fn get_feed_posts(&self, scope: &FeedScope, visibility: &VisibilityScope, limit: u64) -> Result<Vec<posts::Model>, DbErr> {
+        let mut select = posts::Entity::find()
+            .filter(posts::Column::DeletedAt.is_null())
+            .filter(posts::Column::Status.eq(PostStatus::Published))
+            .filter(visible_posts(visibility));
 
-        match posts::Entity::find()
-            .filter(posts::Column::Id.eq(post_id))
-            .find_with_related(comments::Entity)
+        select = match scope {
+            FeedScope::All => select,
+            FeedScope::Category(category_id) => select.filter(posts::Column::CategoryId.eq(*category_id)),
+            FeedScope::Author(user_id) => select.filter(posts::Column::UserId.eq(*user_id)),
+        };
+
+        select
+            .order_by_desc(posts::Column::CreatedAt)
+            .order_by_desc(posts::Column::Id)
+            .limit(limit)
             .all(&self.db_pool)
             .await
-        {
-            Ok(post_with_comments) => {
-                info!(
-                    "Successfully fetched post with related comments for post ID: {}",
-                    post_id
-                );
-
-                let result = post_with_comments
-                    .into_iter()
-                    .flat_map(|(post, comments)| {
-                        comments.into_iter().map(move |comment| {
-                            PostRelationResponse::from_post_and_comment(&post, &comment)
-                        })
-                    })
-                    .collect::<Vec<_>>();
-
-            
-                info!(
-                    "Found {} related comments for post ID: {}",
-                    result.len(),
-                    post_id
-                );
-
-                Ok(result)
-            }
-            Err(e) => {
-               
-                error!(
-                    "Failed to fetch post relation for post ID: {}. Error: {:?}",
-                    post_id, e
-                );
-                Err(e)
-            }
+    }
+
+    async fn get_archive(&self, scope: &VisibilityScope) -> Result<Vec<ArchiveMonthResponse>, DbErr> {
+        posts::Entity::find()
+            .select_only()
+            .column_as(Expr::cust(ARCHIVE_YEAR), "year")
+            .column_as(Expr::cust(ARCHIVE_MONTH), "month")
+            .column_as(posts::Column::Id.count(), "count")
+            .filter(posts::Column::DeletedAt.is_null())
+            .filter(posts::Column::Status.eq(PostStatus::Published))
+            .filter(visible_posts(scope))
+            .group_by(Expr::cust(ARCHIVE_YEAR))
+            .group_by(Expr::cust(ARCHIVE_MONTH))
+            .order_by(Expr::cust(ARCHIVE_YEAR), Order::Desc)
+            .order_by(Expr::cust(ARCHIVE_MONTH), Order::Desc)
+            .into_model::<ArchiveMonthResponse>()
+            .all(&self.db_pool)
+            .await
+    }
+
+    async fn get_archive_posts(&self, start: DateTimeWithTimeZone, end: DateTimeWithTimeZone, scope: &VisibilityScope, pagination: &Pagination) -> Result<(Vec<posts::Model>, u64), DbErr> {
+        let query = posts::Entity::find()
+            .filter(posts::Column::DeletedAt.is_null())
+            .filter(posts::Column::Status.eq(PostStatus::Published))
+            .filter(visible_posts(scope))
+            .filter(posts::Column::CreatedAt.gte(start))
+            .filter(posts::Column::CreatedAt.lt(end));
+
+        let total = query.clone().count(&self.db_pool).await?;
+
+        let posts = query
+            .order_by_desc(posts::Column::CreatedAt)
+            .order_by_desc(posts::Column::Id)
+            .limit(pagination.limit())
+            .offset(pagination.offset())
+            .all(&self.db_pool)
+            .await?;
+
+        Ok((posts, total))
+    }
+
+    async fn get_review_queue(&self, pagination: &Pagination) -> Result<(Vec<posts::Model>, u64), DbErr> {
+        let query = posts::Entity::find()
+            .filter(posts::Column::DeletedAt.is_null())
+            .filter(posts::Column::Status.eq(PostStatus::PendingReview));
+
+        let total = query.clone().count(&self.db_pool).await?;
+
+        let posts = query
+            .order_by_asc(posts::Column::UpdatedAt)
+            .order_by_asc(posts::Column::Id)
+            .limit(pagination.limit())
+            .offset(pagination.offset())
+            .all(&self.db_pool)
+            .await?;
+
+        Ok((posts, total))
+    }
+
+    async fn transition_post(&self, post_id: i32, from: PostStatus, to: PostStatus, actor_id: i32, note: Option<String>) -> Result<posts::Model, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        // Matching on the old status keeps two reviewers from both moving the post.
+        let result = posts::Entity::update_many()
+            .col_expr(posts::Column::Status, Expr::value(to))
+            .col_expr(posts::Column::Version, Expr::col(posts::Column::Version).add(1))
+            .col_expr(posts::Column::UpdatedAt, Expr::value(DateTimeWithTimeZone::from(Utc::now())))
+            .filter(posts::Column::Id.eq(post_id))
+            .filter(posts::Column::Status.eq(from))
+            .filter(posts::Column::DeletedAt.is_null())
+            .exec(&txn)
+            .await?;
+
+        if result.rows_affected == 0 {
+            return Err(DbErr::RecordNotUpdated);
         }
+
+        insert_transition(&txn, post_id, from, to, Some(actor_id), note).await?;
+
+        let post = posts::Entity::find_by_id(post_id)
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Post not found".to_owned()))?;
+
+        txn.commit().await?;
+
+        Ok(post)
+    }
+
+    async fn get_transitions(&self, post_id: i32) -> Result<Vec<post_transitions::Model>, DbErr> {
+        PostTransitions::find()
+            .filter(post_transitions::Column::PostId.eq(post_id))
+            .order_by_asc(post_transitions::Column::CreatedAt)
+            .order_by_asc(post_transitions::Column::Id)
+            .all(&self.db_pool)
+            .await
+    }
+
+    async fn get_post_tags(&self, posts: &[posts::Model]) -> Result<Vec<Vec<tags::Model>>, DbErr> {
+        posts
+            .load_many_to_many(tags::Entity, post_tags::Entity, &self.db_pool)
+            .await
     }
 
     async fn create_post(&self, input: &CreatePostRequest) -> Result<posts::Model, DbErr> {
-        let new_post = posts::ActiveModel {
-            title: Set(input.title.to_string()),
-            body: Set(input.body.to_string()),
-            slug: Set(input.title.to_string()),
-            img: Set(input.img.to_string()),
-            category_id: Set(input.category_id),
-            user_id: Set(input.user_id),
-            user_name: Set(input.user_name.to_string()),
-            ..Default::default()
-        };
+        let txn = self.db_pool.begin().await?;
+
+        let post = insert_post(&txn, input, None).await?;
+
+        txn.commit().await?;
+
+        Ok(post)
+    }
 
-        match new_post.insert(&self.db_pool).await {
-            Ok(post) => Ok(post),
-            Err(e) => {
-                error!("Failed to create post: {:?}", e);
-                Err(e)
+    async fn create_translation(&self, source_id: i32, input: &CreatePostRequest) -> Result<Option<posts::Model>, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        // Locked so that concurrent translations of an ungrouped post join the same group.
+        let source = posts::Entity::find_by_id(source_id)
+            .filter(posts::Column::DeletedAt.is_null())
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Post not found".to_owned()))?;
+
+        let group_id = match source.translation_group_id {
+            Some(group_id) => group_id,
+            None => {
+                posts::Entity::update_many()
+                    .col_expr(posts::Column::TranslationGroupId, Expr::value(source.id))
+                    .filter(posts::Column::Id.eq(source.id))
+                    .exec(&txn)
+                    .await?;
+
+                source.id
             }
+        };
+
+        let locale = input.locale.as_deref().unwrap_or(DEFAULT_LOCALE);
+        let taken = posts::Entity::find()
+            .filter(posts::Column::DeletedAt.is_null())
+            .filter(posts::Column::TranslationGroupId.eq(group_id))
+            .filter(posts::Column::Locale.eq(locale))
+            .count(&txn)
+            .await?;
+
+        if taken > 0 {
+            return Ok(None);
         }
+
+        // The unique index on (translation_group_id, locale) settles races between translations
+        // started from different posts of the group.
+        let post = match insert_post(&txn, input, Some(group_id)).await {
+            Ok(post) => post,
+            Err(e) if matches!(e.sql_err(), Some(SqlErr::UniqueConstraintViolation(_))) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+
+        txn.commit().await?;
+
+        Ok(Some(post))
     }
 
-    async fn update_post(&self, input: &UpdatePostRequest) -> Result<posts::Model, DbErr> {
-        let id = match input.post_id {
+    async fn get_translations(&self, group_id: i32, scope: &VisibilityScope) -> Result<Vec<posts::Model>, DbErr> {
+        posts::Entity::find()
+            .filter(posts::Column::TranslationGroupId.eq(group_id))
+            .filter(posts::Column::DeletedAt.is_null())
+            .filter(visible_posts(scope))
+            .order_by_asc(posts::Column::Locale)
+            .all(&self.db_pool)
+            .await
+    }
+
+    async fn get_locales(&self) -> Result<Vec<String>, DbErr> {
+        posts::Entity::find()
+            .select_only()
+            .column(posts::Column::Locale)
+            .distinct()
+            .filter(posts::Column::DeletedAt.is_null())
+            .order_by_asc(posts::Column::Locale)
+            .into_tuple::<String>()
+            .all(&self.db_pool)
+            .await
+    }
+
+    async fn update_post(&self, input: &UpdatePostRequest) -> Result<PostWrite<posts::Model>, DbErr> {
+        let post_id = match input.post_id {
             Some(id) => id,
             None => return Err(DbErr::Custom("Post ID is required".to_string())),
         };
 
-        let post = posts::Entity::find_by_id(id)
-            .one(&self.db_pool)
+        self.patch_post(&PatchPostRequest {
+            post_id,
+            title: Some(input.title.clone()),
+            body: Some(input.body.clone()),
+            body_format: input.body_format,
+            img: Some(input.img.clone()),
+            category_id: Some(input.category_id),
+            user_id: input.user_id,
+            user_name: Some(input.user_name.clone()),
+            tags: input.tags.clone(),
+            status: input.status,
+            visibility: input.visibility,
+            password_hash: input.password_hash.clone(),
+            metadata: input.metadata.clone(),
+            locale: input.locale.clone(),
+            version: input.version,
+            actor_id: input.actor_id,
+        })
+        .await
+    }
+
+    async fn patch_post(&self, input: &PatchPostRequest) -> Result<PostWrite<posts::Model>, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        let post = posts::Entity::find_by_id(input.post_id)
+            .filter(posts::Column::DeletedAt.is_null())
+            .one(&txn)
             .await?
             .ok_or(DbErr::RecordNotFound("Post not found".to_owned()))?;
 
+        if let Some(lock) = find_other_lock(&txn, post.id, input.actor_id).await? {
+            return Ok(PostWrite::Locked(lock));
+        }
+
+        bump_version::<posts::Entity, _>(
+            &txn,
+            posts::Column::Id,
+            posts::Column::Version,
+            posts::Column::UpdatedAt,
+            post.id,
+            input.version.unwrap_or(post.version),
+        )
+        .await?;
+
+        let from_status = post.status;
+
+        // The rendered body depends on both the source and its format.
+        let body_html = if input.body.is_some() || input.body_format.is_some() {
+            let body = input.body.as_deref().unwrap_or(&post.body);
+            Some(render_body(body, input.body_format.unwrap_or(post.body_format)))
+        } else {
+            None
+        };
+
         let mut post: posts::ActiveModel = post.into();
-        post.title = Set(input.title.to_string());
-        post.body = Set(input.body.to_string());
-        post.img = Set(input.img.to_string());
-        post.category_id = Set(input.category_id);
-        post.user_id = Set(input.user_id);
-        post.user_name = Set(input.user_name.to_string());
 
-        post.update(&self.db_pool).await
+        if let Some(title) = &input.title {
+            post.title = Set(title.clone());
+        }
+        if let Some(body) = &input.body {
+            post.body = Set(body.clone());
+        }
+        if let Some(body_format) = input.body_format {
+            post.body_format = Set(body_format);
+        }
+        if let Some(body_html) = body_html {
+            let stats = ReadingStats::from_html(&body_html);
+            post.body_html = Set(body_html);
+            post.excerpt = Set(stats.excerpt);
+            post.word_count = Set(stats.word_count);
+            post.reading_time_minutes = Set(stats.reading_time_minutes);
+        }
+        if let Some(img) = &input.img {
+            post.img = Set(img.clone());
+        }
+        if let Some(category_id) = input.category_id {
+            post.category_id = Set(category_id);
+        }
+        if let Some(user_id) = input.user_id {
+            post.user_id = Set(user_id);
+        }
+        if let Some(user_name) = &input.user_name {
+            post.user_name = Set(user_name.clone());
+        }
+        if let Some(status) = input.status {
+            post.status = Set(status);
+        }
+        if let Some(visibility) = input.visibility {
+            post.visibility = Set(visibility);
+            if visibility != PostVisibility::Password {
+                post.password_hash = Set(None);
+            }
+        }
+        if let Some(password_hash) = &input.password_hash {
+            post.password_hash = Set(Some(password_hash.clone()));
+        }
+        if let Some(metadata) = &input.metadata {
+            post.metadata = Set(metadata.clone());
+        }
+        if let Some(locale) = &input.locale {
+            post.locale = Set(locale.clone());
+        }
+
+        let post = post.update(&txn).await?;
+
+        if let Some(tags) = &input.tags {
+            sync_post_tags(&txn, post.id, tags).await?;
+        }
+
+        if post.status != from_status {
+            insert_transition(&txn, post.id, from_status, post.status, input.actor_id, None).await?;
+        }
+
+        txn.commit().await?;
+
+        Ok(PostWrite::Saved(post))
     }
 
-    async fn delete_post(&self, post_id: i32) -> Result<(), DbErr> {
+    async fn delete_post(&self, post_id: i32, deleted_by: i32, expected_version: Option<i32>) -> Result<PostWrite<()>, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
         let post = posts::Entity::find_by_id(post_id)
-            .one(&self.db_pool)
+            .filter(posts::Column::DeletedAt.is_null())
+            .one(&txn)
             .await?
             .ok_or(DbErr::RecordNotFound("Post not found".to_owned()))?;
 
-        post.delete(&self.db_pool).await?;
-        Ok(())
+        if let Some(lock) = find_other_lock(&txn, post.id, Some(deleted_by)).await? {
+            return Ok(PostWrite::Locked(lock));
+        }
+
+        bump_version::<posts::Entity, _>(
+            &txn,
+            posts::Column::Id,
+            posts::Column::Version,
+            posts::Column::UpdatedAt,
+            post.id,
+            expected_version.unwrap_or(post.version),
+        )
+        .await?;
+
+        let mut post: posts::ActiveModel = post.into();
+        post.deleted_at = Set(Some(Utc::now().into()));
+        post.deleted_by = Set(Some(deleted_by));
+
+        post.update(&txn).await?;
+        txn.commit().await?;
+
+        Ok(PostWrite::Saved(()))
     }
+
+    async fn bulk_update(&self, actor_id: i32, is_editor: bool, input: &BulkPostRequest) -> Result<Vec<(i32, BulkPostOutcome)>, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        // Posts moved into a category must satisfy its metadata schema.
+        let schema = match &input.operation {
+            BulkPostOperation::MoveCategory { category_id } => {
+                categories::Entity::find_by_id(*category_id)
+                    .filter(categories::Column::DeletedAt.is_null())
+                    .one(&txn)
+                    .await?
+                    .ok_or(DbErr::RecordNotFound("Category not found".to_owned()))?
+                    .metadata_schema
+            }
+            _ => None,
+        };
+
+        // Locking the rows keeps their owners from changing before the update.
+        let owners: HashMap<i32, (i32, PostStatus, Json)> = posts::Entity::find()
+            .select_only()
+            .column(posts::Column::Id)
+            .column(posts::Column::UserId)
+            .column(posts::Column::Status)
+            .column(posts::Column::Metadata)
+            .filter(posts::Column::Id.is_in(input.post_ids.clone()))
+            .filter(posts::Column::DeletedAt.is_null())
+            .lock_exclusive()
+            .into_tuple::<(i32, i32, PostStatus, Json)>()
+            .all(&txn)
+            .await?
+            .into_iter()
+            .map(|(post_id, owner, status, metadata)| (post_id, (owner, status, metadata)))
+            .collect();
+
+        // Shared row locks make a concurrent takeover of these edit locks wait for the update.
+        let now: DateTimeWithTimeZone = Utc::now().into();
+        let locks: HashMap<i32, post_locks::Model> = PostLocks::find()
+            .filter(post_locks::Column::PostId.is_in(input.post_ids.clone()))
+            .filter(post_locks::Column::ExpiresAt.gt(now))
+            .lock_shared()
+            .all(&txn)
+            .await?
+            .into_iter()
+            .map(|lock| (lock.post_id, lock))
+            .collect();
+
+        let mut allowed = Vec::new();
+        let results = input.post_ids
+            .iter()
+            .map(|post_id| {
+                let outcome = match owners.get(post_id) {
+                    None => BulkPostOutcome::NotFound,
+                    Some((owner, _, _)) if *owner != actor_id && !is_editor => BulkPostOutcome::Forbidden,
+                    Some(_) if locks.get(post_id).is_some_and(|lock| lock.user_id != actor_id) => {
+                        BulkPostOutcome::Locked(locks[post_id].clone())
+                    }
+                    Some((_, _, metadata)) => match validate_metadata(schema.as_ref(), metadata) {
+                        Err(AppError::ValidationError(message)) => BulkPostOutcome::Invalid(message),
+                        Err(e) => BulkPostOutcome::Invalid(e.to_string()),
+                        Ok(()) => {
+                            allowed.push(*post_id);
+                            BulkPostOutcome::Updated
+                        }
+                    },
+                };
+
+                (*post_id, outcome)
+            })
+            .collect();
+
+        if !allowed.is_empty() {
+            let update = posts::Entity::update_many()
+                .col_expr(posts::Column::Version, Expr::col(posts::Column::Version).add(1))
+                .col_expr(posts::Column::UpdatedAt, Expr::value(now))
+                .filter(posts::Column::Id.is_in(allowed.clone()));
+
+            let update = match &input.operation {
+                BulkPostOperation::MoveCategory { category_id } => {
+                    update.col_expr(posts::Column::CategoryId, Expr::value(*category_id))
+                }
+                BulkPostOperation::Publish => {
+                    log_status_changes(&txn, &allowed, &owners, PostStatus::Published, Some(actor_id)).await?;
+                    update.col_expr(posts::Column::Status, Expr::value(PostStatus::Published))
+                }
+                BulkPostOperation::Unpublish => {
+                    log_status_changes(&txn, &allowed, &owners, PostStatus::Draft, Some(actor_id)).await?;
+                    update.col_expr(posts::Column::Status, Expr::value(PostStatus::Draft))
+                }
+                BulkPostOperation::Delete => update
+                    .col_expr(posts::Column::DeletedAt, Expr::value(now))
+                    .col_expr(posts::Column::DeletedBy, Expr::value(actor_id)),
+                BulkPostOperation::AddTags { tags } => {
+                    add_post_tags(&txn, &allowed, tags).await?;
+                    update
+                }
+            };
+
+            update.exec(&txn).await?;
+        }
+
+        txn.commit().await?;
+
+        Ok(results)
+    }
+}
+
+async fn insert_transition<C: ConnectionTrait>(
+    db: &C,
+    post_id: i32,
+    from: PostStatus,
+    to: PostStatus,
+    actor_id: Option<i32>,
+    note: Option<String>,
+) -> Result<(), DbErr> {
+    post_transitions::ActiveModel {
+        post_id: Set(post_id),
+        from_status: Set(from),
+        to_status: Set(to),
+        actor_id: Set(actor_id),
+        note: Set(note),
+        ..Default::default()
+    }
+    .insert(db)
+    .await?;
+
+    Ok(())
+}
+
+/// Logs the bulk status change of the posts in `post_ids` that are not already in `to`.
+async fn log_status_changes<C: ConnectionTrait>(
+    db: &C,
+    post_ids: &[i32],
+    owners: &HashMap<i32, (i32, PostStatus, Json)>,
+    to: PostStatus,
+    actor_id: Option<i32>,
+) -> Result<(), DbErr> {
+    let transitions: Vec<post_transitions::ActiveModel> = post_ids
+        .iter()
+        .filter_map(|post_id| owners.get(post_id).map(|(_, status, _)| (*post_id, *status)))
+        .filter(|(_, from)| *from != to)
+        .map(|(post_id, from)| post_transitions::ActiveModel {
+            post_id: Set(post_id),
+            from_status: Set(from),
+            to_status: Set(to),
+            actor_id: Set(actor_id),
+            ..Default::default()
+        })
+        .collect();
+
+    if transitions.is_empty() {
+        return Ok(());
+    }
+
+    PostTransitions::insert_many(transitions).exec_without_returning(db).await?;
+
+    Ok(())
+}
+
+async fn insert_post<C: ConnectionTrait>(
+    db: &C,
+    input: &CreatePostRequest,
+    translation_group_id: Option<i32>,
+) -> Result<posts::Model, DbErr> {
+    let body_html = render_body(&input.body, input.body_format);
+    let stats = ReadingStats::from_html(&body_html);
+
+    let new_post = posts::ActiveModel {
+        title: Set(input.title.to_string()),
+        body: Set(input.body.to_string()),
+        body_format: Set(input.body_format),
+        body_html: Set(body_html),
+        excerpt: Set(stats.excerpt),
+        word_count: Set(stats.word_count),
+        reading_time_minutes: Set(stats.reading_time_minutes),
+        slug: Set(input.title.to_string()),
+        img: Set(input.img.to_string()),
+        category_id: Set(input.category_id),
+        user_id: Set(input.user_id),
+        user_name: Set(input.user_name.to_string()),
+        status: Set(input.status),
+        visibility: Set(input.visibility),
+        password_hash: Set(input.password_hash.clone()),
+        metadata: Set(input.metadata.clone().unwrap_or_else(|| json!({}))),
+        locale: Set(input.locale.clone().unwrap_or_else(|| DEFAULT_LOCALE.to_string())),
+        translation_group_id: Set(translation_group_id),
+        ..Default::default()
+    };
+
+    let post = match new_post.insert(db).await {
+        Ok(post) => post,
+        Err(e) => {
+            error!("Failed to create post: {:?}", e);
+            return Err(e);
+        }
+    };
+
+    sync_post_tags(db, post.id, &input.tags).await?;
+
+    Ok(post)
 }
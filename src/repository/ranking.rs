@@ -0,0 +1,197 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveEnum, ColumnTrait, ConnectionTrait, DatabaseBackend, DatabaseConnection, DbErr, EntityTrait, JoinType,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Statement, TransactionTrait, Value,
+};
+
+use crate::abstract_trait::RankingRepositoryTrait;
+use crate::domain::{Pagination, VisibilityScope};
+use crate::entities::{posts, related_posts, sea_orm_active_enums::PostStatus, trending_posts, Posts, RelatedPosts, TrendingPosts};
+use crate::repository::visibility::visible_posts;
+
+/// Trending weight of a view, a reaction and a comment.
+const VIEW_WEIGHT: f64 = 1.0;
+const REACTION_WEIGHT: f64 = 3.0;
+const COMMENT_WEIGHT: f64 = 5.0;
+
+/// Related score of a shared category, of each shared tag, and of identical text.
+const CATEGORY_WEIGHT: f64 = 1.0;
+const TAG_WEIGHT: f64 = 2.0;
+const TEXT_WEIGHT: f64 = 4.0;
+
+/// Sums the decayed weight of every interaction within the window. `$1` is the published
+/// status, `$2` the window and `$3` the half-life, both in seconds, and `$4` to `$6` the
+/// weights. Daily view counts are dated at noon.
+const TRENDING_SQL: &str = r#"
+INSERT INTO trending_posts (post_id, score, computed_at)
+SELECT activity.post_id, SUM(activity.weight * exp(-ln(2) * activity.age / $3)), now()
+FROM (
+    SELECT post_id, views * $4::float8 AS weight,
+        GREATEST(EXTRACT(EPOCH FROM now() - (day + time '12:00')), 0)::float8 AS age
+    FROM post_view_stats
+    WHERE day >= (now() - make_interval(secs => $2))::date
+    UNION ALL
+    SELECT post_id, $5::float8, EXTRACT(EPOCH FROM now() - created_at)::float8
+    FROM post_reactions
+    WHERE created_at >= now() - make_interval(secs => $2)
+    UNION ALL
+    SELECT id_post_comment, $6::float8, EXTRACT(EPOCH FROM now() - created_at)::float8
+    FROM comments
+    WHERE deleted_at IS NULL AND created_at >= now() - make_interval(secs => $2)
+) activity
+JOIN posts p ON p.id = activity.post_id
+WHERE p.status = $1 AND p.deleted_at IS NULL
+GROUP BY activity.post_id"#;
+
+/// Scores every pair of published posts in the same locale, leaving out translations of the
+/// post itself. Text similarity is the Jaccard index of the search lexemes. `$1` is the
+/// published status, `$2` the number of related posts kept per post and `$3` to `$5` the
+/// weights.
+const RELATED_SQL: &str = r#"
+WITH live AS (
+    SELECT id, category_id, locale, COALESCE(translation_group_id, id) AS group_id, search_vector
+    FROM posts
+    WHERE status = $1 AND deleted_at IS NULL
+),
+lexemes AS (
+    SELECT live.id AS post_id, lexeme
+    FROM live, unnest(tsvector_to_array(live.search_vector)) AS lexeme
+),
+lexeme_counts AS (
+    SELECT post_id, COUNT(*) AS total FROM lexemes GROUP BY post_id
+),
+shared_lexemes AS (
+    SELECT a.post_id, b.post_id AS related_post_id, COUNT(*) AS shared
+    FROM lexemes a
+    JOIN lexemes b ON b.lexeme = a.lexeme AND b.post_id <> a.post_id
+    GROUP BY a.post_id, b.post_id
+),
+shared_tags AS (
+    SELECT a.post_id, b.post_id AS related_post_id, COUNT(*) AS shared
+    FROM post_tags a
+    JOIN post_tags b ON b.tag_id = a.tag_id AND b.post_id <> a.post_id
+    GROUP BY a.post_id, b.post_id
+),
+scored AS (
+    SELECT p.id AS post_id, q.id AS related_post_id,
+        CASE WHEN q.category_id = p.category_id THEN $3::float8 ELSE 0 END
+        + $4::float8 * COALESCE(t.shared, 0)
+        + $5::float8 * COALESCE(l.shared::float8 / NULLIF(pc.total + qc.total - l.shared, 0), 0) AS score
+    FROM live p
+    JOIN live q ON q.locale = p.locale AND q.group_id <> p.group_id
+    LEFT JOIN shared_tags t ON t.post_id = p.id AND t.related_post_id = q.id
+    LEFT JOIN shared_lexemes l ON l.post_id = p.id AND l.related_post_id = q.id
+    LEFT JOIN lexeme_counts pc ON pc.post_id = p.id
+    LEFT JOIN lexeme_counts qc ON qc.post_id = q.id
+),
+ranked AS (
+    SELECT post_id, related_post_id, score,
+        ROW_NUMBER() OVER (PARTITION BY post_id ORDER BY score DESC, related_post_id DESC) AS rank
+    FROM scored
+    WHERE score > 0
+)
+INSERT INTO related_posts (post_id, related_post_id, score, computed_at)
+SELECT post_id, related_post_id, score, now()
+FROM ranked
+WHERE rank <= $2"#;
+
+pub struct RankingRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl RankingRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl RankingRepositoryTrait for RankingRepository {
+    async fn refresh_trending(&self, window: Duration, half_life: Duration) -> Result<u64, DbErr> {
+        let values: Vec<Value> = vec![
+            PostStatus::Published.to_value().into(),
+            window.as_secs_f64().into(),
+            half_life.as_secs_f64().into(),
+            VIEW_WEIGHT.into(),
+            REACTION_WEIGHT.into(),
+            COMMENT_WEIGHT.into(),
+        ];
+
+        // Readers keep seeing the previous scores until the new ones are committed.
+        let txn = self.db_pool.begin().await?;
+
+        TrendingPosts::delete_many().exec(&txn).await?;
+
+        let result = txn
+            .execute(Statement::from_sql_and_values(DatabaseBackend::Postgres, TRENDING_SQL, values))
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn refresh_related(&self, limit: u64) -> Result<u64, DbErr> {
+        let values: Vec<Value> = vec![
+            PostStatus::Published.to_value().into(),
+            (limit as i64).into(),
+            CATEGORY_WEIGHT.into(),
+            TAG_WEIGHT.into(),
+            TEXT_WEIGHT.into(),
+        ];
+
+        let txn = self.db_pool.begin().await?;
+
+        RelatedPosts::delete_many().exec(&txn).await?;
+
+        let result = txn
+            .execute(Statement::from_sql_and_values(DatabaseBackend::Postgres, RELATED_SQL, values))
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn find_trending(&self, scope: &VisibilityScope, pagination: &Pagination) -> Result<(Vec<(trending_posts::Model, posts::Model)>, u64), DbErr> {
+        let query = TrendingPosts::find()
+            .find_also_related(Posts)
+            .filter(posts::Column::DeletedAt.is_null())
+            .filter(posts::Column::Status.eq(PostStatus::Published))
+            .filter(visible_posts(scope));
+
+        let total = query.clone().count(&self.db_pool).await?;
+
+        let trending = query
+            .order_by_desc(trending_posts::Column::Score)
+            .order_by_asc(trending_posts::Column::PostId)
+            .limit(pagination.limit())
+            .offset(pagination.offset())
+            .all(&self.db_pool)
+            .await?
+            .into_iter()
+            .filter_map(|(trending, post)| post.map(|post| (trending, post)))
+            .collect();
+
+        Ok((trending, total))
+    }
+
+    async fn find_related(&self, post_id: i32, scope: &VisibilityScope) -> Result<Vec<(related_posts::Model, posts::Model)>, DbErr> {
+        Ok(RelatedPosts::find()
+            .select_also(Posts)
+            .join(JoinType::InnerJoin, related_posts::Relation::Posts1.def())
+            .filter(related_posts::Column::PostId.eq(post_id))
+            .filter(posts::Column::DeletedAt.is_null())
+            .filter(posts::Column::Status.eq(PostStatus::Published))
+            .filter(visible_posts(scope))
+            .order_by_desc(related_posts::Column::Score)
+            .order_by_asc(related_posts::Column::RelatedPostId)
+            .all(&self.db_pool)
+            .await?
+            .into_iter()
+            .filter_map(|(related, post)| post.map(|post| (related, post)))
+            .collect())
+    }
+}
@@ -0,0 +1,194 @@
+use async_trait::async_trait;
+use sea_orm::sea_query::{Expr, OnConflict, SimpleExpr};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, FromQueryResult,
+    QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+
+use crate::abstract_trait::ReactionRepositoryTrait;
+use crate::domain::ReactionResponse;
+use crate::entities::{
+    comment_reactions, comments, post_reactions, posts, CommentReactions, Comments, PostReactions,
+    Posts,
+};
+
+pub struct ReactionRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl ReactionRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[derive(FromQueryResult)]
+struct ReactionCount {
+    target_id: i32,
+    kind: String,
+    count: i64,
+    reacted: bool,
+}
+
+#[async_trait]
+impl ReactionRepositoryTrait for ReactionRepository {
+    async fn toggle_post_reaction(&self, post_id: i32, user_id: i32, kind: &str) -> Result<bool, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        Posts::find_by_id(post_id)
+            .filter(posts::Column::DeletedAt.is_null())
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Post not found".to_owned()))?;
+
+        // Insert first and fall back to removing, so concurrent toggles resolve on the unique key.
+        let inserted = PostReactions::insert(post_reactions::ActiveModel {
+            post_id: Set(post_id),
+            user_id: Set(user_id),
+            kind: Set(kind.to_string()),
+            ..Default::default()
+        })
+        .on_conflict(
+            OnConflict::columns([
+                post_reactions::Column::PostId,
+                post_reactions::Column::UserId,
+                post_reactions::Column::Kind,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(&txn)
+        .await?;
+
+        if inserted == 0 {
+            PostReactions::delete_many()
+                .filter(post_reactions::Column::PostId.eq(post_id))
+                .filter(post_reactions::Column::UserId.eq(user_id))
+                .filter(post_reactions::Column::Kind.eq(kind))
+                .exec(&txn)
+                .await?;
+        }
+
+        txn.commit().await?;
+
+        Ok(inserted > 0)
+    }
+
+    async fn toggle_comment_reaction(&self, comment_id: i32, user_id: i32, kind: &str) -> Result<bool, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        Comments::find_by_id(comment_id)
+            .filter(comments::Column::DeletedAt.is_null())
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Comment not found".to_owned()))?;
+
+        // Insert first and fall back to removing, so concurrent toggles resolve on the unique key.
+        let inserted = CommentReactions::insert(comment_reactions::ActiveModel {
+            comment_id: Set(comment_id),
+            user_id: Set(user_id),
+            kind: Set(kind.to_string()),
+            ..Default::default()
+        })
+        .on_conflict(
+            OnConflict::columns([
+                comment_reactions::Column::CommentId,
+                comment_reactions::Column::UserId,
+                comment_reactions::Column::Kind,
+            ])
+            .do_nothing()
+            .to_owned(),
+        )
+        .exec_without_returning(&txn)
+        .await?;
+
+        if inserted == 0 {
+            CommentReactions::delete_many()
+                .filter(comment_reactions::Column::CommentId.eq(comment_id))
+                .filter(comment_reactions::Column::UserId.eq(user_id))
+                .filter(comment_reactions::Column::Kind.eq(kind))
+                .exec(&txn)
+                .await?;
+        }
+
+        txn.commit().await?;
+
+        Ok(inserted > 0)
+    }
+
+    async fn find_for_posts(&self, post_ids: &[i32], viewer: Option<i32>) -> Result<Vec<Vec<ReactionResponse>>, DbErr> {
+        count_reactions::<PostReactions, _>(
+            &self.db_pool,
+            post_reactions::Column::PostId,
+            post_reactions::Column::UserId,
+            post_reactions::Column::Kind,
+            post_ids,
+            viewer,
+        )
+        .await
+    }
+
+    async fn find_for_comments(&self, comment_ids: &[i32], viewer: Option<i32>) -> Result<Vec<Vec<ReactionResponse>>, DbErr> {
+        count_reactions::<CommentReactions, _>(
+            &self.db_pool,
+            comment_reactions::Column::CommentId,
+            comment_reactions::Column::UserId,
+            comment_reactions::Column::Kind,
+            comment_ids,
+            viewer,
+        )
+        .await
+    }
+}
+
+/// Counts reactions per target and kind in one query, flagging the kinds `viewer` has used.
+async fn count_reactions<E, C>(
+    db: &C,
+    target_column: E::Column,
+    user_column: E::Column,
+    kind_column: E::Column,
+    ids: &[i32],
+    viewer: Option<i32>,
+) -> Result<Vec<Vec<ReactionResponse>>, DbErr>
+where
+    E: EntityTrait,
+    C: ConnectionTrait,
+{
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let reacted: SimpleExpr = match viewer {
+        Some(user_id) => Expr::cust_with_expr("BOOL_OR($1)", user_column.eq(user_id)),
+        None => Expr::cust("FALSE"),
+    };
+
+    let counts = E::find()
+        .select_only()
+        .column_as(target_column, "target_id")
+        .column_as(kind_column, "kind")
+        .column_as(Expr::cust("COUNT(*)"), "count")
+        .column_as(reacted, "reacted")
+        .filter(target_column.is_in(ids.to_vec()))
+        .group_by(target_column)
+        .group_by(kind_column)
+        .order_by_asc(kind_column)
+        .into_model::<ReactionCount>()
+        .all(db)
+        .await?;
+
+    Ok(ids
+        .iter()
+        .map(|id| {
+            counts
+                .iter()
+                .filter(|count| count.target_id == *id)
+                .map(|count| ReactionResponse {
+                    kind: count.kind.clone(),
+                    count: count.count,
+                    reacted: count.reacted,
+                })
+                .collect()
+        })
+        .collect())
+}
@@ -0,0 +1,163 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveEnum, DatabaseBackend, DatabaseConnection, DbErr, FromQueryResult, Statement, Value,
+};
+
+use crate::abstract_trait::SearchRepositoryTrait;
+use crate::domain::{Pagination, SearchHitResponse, VisibilityScope};
+use crate::entities::sea_orm_active_enums::PostStatus;
+
+/// Private-use characters that mark matches in headlines until the snippet is escaped.
+const MATCH_START: char = '\u{E000}';
+const MATCH_END: char = '\u{E001}';
+
+const HEADLINE_OPTIONS: &str =
+    "StartSel=\u{E000}, StopSel=\u{E001}, MaxWords=35, MinWords=15, MaxFragments=2";
+
+pub struct SearchRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl SearchRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+
+    /// Restricts `p` to the posts listed by `scope`, with placeholders numbered after `values`.
+    fn visibility_sql(scope: &VisibilityScope, values: &mut Vec<Value>) -> String {
+        let visibilities = match &scope.visibilities {
+            Some(visibilities) => visibilities,
+            None => return String::new(),
+        };
+
+        let mut placeholders = Vec::new();
+        for visibility in visibilities {
+            values.push(visibility.to_value().into());
+            placeholders.push(format!("${}", values.len()));
+        }
+
+        let mut sql = format!("p.visibility IN ({})", placeholders.join(", "));
+        if let Some(author_id) = scope.author_id {
+            values.push(author_id.into());
+            sql = format!("{} OR p.user_id = ${}", sql, values.len());
+        }
+
+        format!(" AND ({})", sql)
+    }
+
+    /// Builds the union of matching posts and, optionally, comments.
+    /// `$1` is the tsquery and `$2` the required post status.
+    fn hits_sql(include_comments: bool, visibility: &str) -> String {
+        let mut sql = format!(
+            r#"SELECT 'post' AS kind, p.id AS post_id, NULL::integer AS comment_id, p.title,
+                   ts_headline('english', p.body, q, '{options}') AS snippet,
+                   ts_rank_cd(p.search_vector, q) AS rank
+               FROM posts p, to_tsquery('english', $1) q
+               WHERE p.status = $2 AND p.deleted_at IS NULL AND p.search_vector @@ q{visibility}"#,
+            options = HEADLINE_OPTIONS,
+            visibility = visibility
+        );
+
+        if include_comments {
+            sql.push_str(&format!(
+                r#" UNION ALL
+               SELECT 'comment' AS kind, p.id AS post_id, c.id AS comment_id, p.title,
+                   ts_headline('english', c.comment, q, '{options}') AS snippet,
+                   ts_rank_cd(c.search_vector, q) AS rank
+               FROM comments c
+               JOIN posts p ON p.id = c.id_post_comment, to_tsquery('english', $1) q
+               WHERE p.status = $2 AND p.deleted_at IS NULL AND c.deleted_at IS NULL
+                   AND c.search_vector @@ q{visibility}"#,
+                options = HEADLINE_OPTIONS,
+                    visibility = visibility
+            ));
+        }
+
+        sql
+    }
+}
+
+#[derive(Debug, FromQueryResult)]
+struct SearchCount {
+    total: i64,
+}
+
+#[async_trait]
+impl SearchRepositoryTrait for SearchRepository {
+    async fn search(
+        &self,
+        tsquery: &str,
+        include_comments: bool,
+        scope: &VisibilityScope,
+        pagination: &Pagination,
+    ) -> Result<(Vec<SearchHitResponse>, u64), DbErr> {
+        let mut values: Vec<Value> = vec![tsquery.into(), PostStatus::Published.to_value().into()];
+        let visibility = Self::visibility_sql(scope, &mut values);
+        let hits_sql = Self::hits_sql(include_comments, &visibility);
+
+        let total = SearchCount::find_by_statement(Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!("SELECT COUNT(*) AS total FROM ({}) hits", hits_sql),
+            values.clone(),
+        ))
+        .one(&self.db_pool)
+        .await?
+        .map(|count| count.total as u64)
+        .unwrap_or_default();
+
+        let limit = values.len() + 1;
+        values.push((pagination.limit() as i64).into());
+        values.push((pagination.offset() as i64).into());
+
+        let hits = SearchHitResponse::find_by_statement(Statement::from_sql_and_values(
+            DatabaseBackend::Postgres,
+            format!(
+                "SELECT * FROM ({}) hits ORDER BY rank DESC, post_id DESC, comment_id NULLS FIRST LIMIT ${} OFFSET ${}",
+                hits_sql,
+                limit,
+                limit + 1
+            ),
+            values,
+        ))
+        .all(&self.db_pool)
+        .await?
+        .into_iter()
+        .map(|hit| SearchHitResponse { snippet: highlight(&hit.snippet), ..hit })
+        .collect();
+
+        Ok((hits, total))
+    }
+}
+
+/// Escapes the user-written snippet so it is safe to render as HTML, then turns the match
+/// markers into `<mark>` tags. Markers typed by users can at most add a balanced highlight.
+fn highlight(snippet: &str) -> String {
+    let mut html = String::with_capacity(snippet.len());
+    let mut marked = false;
+
+    for c in snippet.chars() {
+        match c {
+            MATCH_START if !marked => {
+                html.push_str("<mark>");
+                marked = true;
+            }
+            MATCH_END if marked => {
+                html.push_str("</mark>");
+                marked = false;
+            }
+            MATCH_START | MATCH_END => {}
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            c => html.push(c),
+        }
+    }
+
+    if marked {
+        html.push_str("</mark>");
+    }
+
+    html
+}
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+
+use crate::abstract_trait::SeriesRepositoryTrait;
+use crate::domain::{CreateSeriesRequest, Pagination, SeriesPartResponse, VisibilityScope};
+use crate::entities::{posts, series, series_posts, Posts, Series, SeriesPosts};
+use crate::repository::visibility::visible_posts;
+
+pub struct SeriesRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl SeriesRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+
+    /// Live parts of each series visible in `scope`, in reading order and numbered from 1 so that
+    /// hidden or deleted posts leave no gaps.
+    async fn find_parts(&self, series_ids: &[i32], scope: &VisibilityScope) -> Result<HashMap<i32, Vec<SeriesPartResponse>>, DbErr> {
+        if series_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let rows: Vec<(i32, i32, String)> = SeriesPosts::find()
+            .select_only()
+            .column(series_posts::Column::SeriesId)
+            .column(series_posts::Column::PostId)
+            .column(posts::Column::Title)
+            .inner_join(Posts)
+            .filter(series_posts::Column::SeriesId.is_in(series_ids.to_vec()))
+            .filter(posts::Column::DeletedAt.is_null())
+            .filter(visible_posts(scope))
+            .order_by_asc(series_posts::Column::SeriesId)
+            .order_by_asc(series_posts::Column::Position)
+            .into_tuple()
+            .all(&self.db_pool)
+            .await?;
+
+        let mut parts: HashMap<i32, Vec<SeriesPartResponse>> = HashMap::new();
+        for (series_id, post_id, title) in rows {
+            let series_parts = parts.entry(series_id).or_default();
+            series_parts.push(SeriesPartResponse {
+                post_id,
+                title,
+                position: series_parts.len() + 1,
+            });
+        }
+
+        Ok(parts)
+    }
+
+    async fn with_parts(&self, series: Vec<series::Model>, scope: &VisibilityScope) -> Result<Vec<(series::Model, Vec<SeriesPartResponse>)>, DbErr> {
+        let series_ids: Vec<i32> = series.iter().map(|series| series.id).collect();
+        let mut parts = self.find_parts(&series_ids, scope).await?;
+
+        Ok(series
+            .into_iter()
+            .map(|series| {
+                let series_parts = parts.remove(&series.id).unwrap_or_default();
+                (series, series_parts)
+            })
+            .collect())
+    }
+}
+
+/// Replaces the parts of `series_id` with `post_ids`, in order.
+///
+/// A post belongs to at most one series, so parts taken from another series of the same owner
+/// are moved rather than rejected.
+async fn replace_parts<C>(db: &C, series_id: i32, user_id: i32, post_ids: &[i32]) -> Result<(), DbErr>
+where
+    C: ConnectionTrait,
+{
+    if !post_ids.is_empty() {
+        let owned: Vec<i32> = Posts::find()
+            .select_only()
+            .column(posts::Column::Id)
+            .filter(posts::Column::Id.is_in(post_ids.to_vec()))
+            .filter(posts::Column::UserId.eq(user_id))
+            .filter(posts::Column::DeletedAt.is_null())
+            .into_tuple()
+            .all(db)
+            .await?;
+
+        if let Some(post_id) = post_ids.iter().find(|post_id| !owned.contains(post_id)) {
+            return Err(DbErr::RecordNotFound(format!("Post {} not found", post_id)));
+        }
+    }
+
+    SeriesPosts::delete_many()
+        .filter(
+            series_posts::Column::SeriesId
+                .eq(series_id)
+                .or(series_posts::Column::PostId.is_in(post_ids.to_vec())),
+        )
+        .exec(db)
+        .await?;
+
+    if post_ids.is_empty() {
+        return Ok(());
+    }
+
+    let parts = post_ids.iter().zip(1..).map(|(post_id, position)| series_posts::ActiveModel {
+        series_id: Set(series_id),
+        post_id: Set(*post_id),
+        position: Set(position),
+    });
+
+    SeriesPosts::insert_many(parts).exec_without_returning(db).await?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl SeriesRepositoryTrait for SeriesRepository {
+    async fn create_series(&self, user_id: i32, input: &CreateSeriesRequest) -> Result<series::Model, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        let series = series::ActiveModel {
+            title: Set(input.title.trim().to_string()),
+            description: Set(input.description.clone()),
+            user_id: Set(user_id),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await?;
+
+        replace_parts(&txn, series.id, user_id, &input.post_ids).await?;
+
+        txn.commit().await?;
+
+        Ok(series)
+    }
+
+    async fn find_series(&self, id: i32, scope: &VisibilityScope) -> Result<Option<(series::Model, Vec<SeriesPartResponse>)>, DbErr> {
+        let series = match Series::find_by_id(id).one(&self.db_pool).await? {
+            Some(series) => series,
+            None => return Ok(None),
+        };
+
+        Ok(self.with_parts(vec![series], scope).await?.pop())
+    }
+
+    async fn find_all_series(&self, scope: &VisibilityScope, pagination: &Pagination) -> Result<(Vec<(series::Model, Vec<SeriesPartResponse>)>, u64), DbErr> {
+        let total = Series::find().count(&self.db_pool).await?;
+
+        let series = Series::find()
+            .order_by_desc(series::Column::CreatedAt)
+            .order_by_desc(series::Column::Id)
+            .limit(pagination.limit())
+            .offset(pagination.offset())
+            .all(&self.db_pool)
+            .await?;
+
+        Ok((self.with_parts(series, scope).await?, total))
+    }
+
+    async fn set_parts(&self, id: i32, user_id: i32, post_ids: &[i32]) -> Result<bool, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        let series = Series::find_by_id(id)
+            .filter(series::Column::UserId.eq(user_id))
+            .one(&txn)
+            .await?;
+
+        let series = match series {
+            Some(series) => series,
+            None => return Ok(false),
+        };
+
+        replace_parts(&txn, series.id, user_id, post_ids).await?;
+
+        // Touch the series so `updated_at` reflects the new order.
+        let series: series::ActiveModel = series.into();
+        series.update(&txn).await?;
+
+        txn.commit().await?;
+
+        Ok(true)
+    }
+
+    async fn delete_series(&self, id: i32, user_id: i32) -> Result<bool, DbErr> {
+        let result = Series::delete_many()
+            .filter(series::Column::Id.eq(id))
+            .filter(series::Column::UserId.eq(user_id))
+            .exec(&self.db_pool)
+            .await?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn find_for_posts(&self, post_ids: &[i32], scope: &VisibilityScope) -> Result<Vec<(series::Model, Vec<SeriesPartResponse>)>, DbErr> {
+        if post_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let series = Series::find()
+            .inner_join(SeriesPosts)
+            .filter(series_posts::Column::PostId.is_in(post_ids.to_vec()))
+            .distinct()
+            .all(&self.db_pool)
+            .await?;
+
+        self.with_parts(series, scope).await
+    }
+}
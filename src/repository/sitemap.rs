@@ -0,0 +1,109 @@
+use async_trait::async_trait;
+use sea_orm::sea_query::{Expr, Func, Query, SimpleExpr};
+use sea_orm::{
+    prelude::DateTimeWithTimeZone, ColumnTrait, DatabaseConnection, DbErr, EntityTrait,
+    QueryFilter, QueryOrder, QuerySelect,
+};
+
+use crate::abstract_trait::SitemapRepositoryTrait;
+use crate::domain::{SitemapEntry, SitemapFingerprint};
+use crate::entities::sea_orm_active_enums::{PostStatus, PostVisibility};
+use crate::entities::{categories, posts, Categories, Posts};
+
+pub struct SitemapRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl SitemapRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl SitemapRepositoryTrait for SitemapRepository {
+    async fn fingerprint(&self) -> Result<SitemapFingerprint, DbErr> {
+        // Counting live rows catches deletes and restores, which do not all touch `updated_at`.
+        let posts: Option<(i64, Option<DateTimeWithTimeZone>)> = Posts::find()
+            .select_only()
+            .column_as(Expr::cust("COUNT(*)"), "count")
+            .column_as(posts::Column::UpdatedAt.max(), "updated_at")
+            .filter(posts::Column::DeletedAt.is_null())
+            .into_tuple()
+            .one(&self.db_pool)
+            .await?;
+
+        let categories: Option<(i64, Option<DateTimeWithTimeZone>)> = Categories::find()
+            .select_only()
+            .column_as(Expr::cust("COUNT(*)"), "count")
+            .column_as(categories::Column::UpdatedAt.max(), "updated_at")
+            .filter(categories::Column::DeletedAt.is_null())
+            .into_tuple()
+            .one(&self.db_pool)
+            .await?;
+
+        Ok(SitemapFingerprint {
+            posts: posts.unwrap_or_default(),
+            categories: categories.unwrap_or_default(),
+        })
+    }
+
+    async fn find_posts(&self) -> Result<Vec<SitemapEntry>, DbErr> {
+        Posts::find()
+            .select_only()
+            .column(posts::Column::Id)
+            .column_as(posts::Column::UpdatedAt, "last_modified")
+            .filter(posts::Column::DeletedAt.is_null())
+            .filter(posts::Column::Status.eq(PostStatus::Published))
+            .filter(posts::Column::Visibility.eq(PostVisibility::Public))
+            .order_by_asc(posts::Column::Id)
+            .into_model::<SitemapEntry>()
+            .all(&self.db_pool)
+            .await
+    }
+
+    async fn find_categories(&self) -> Result<Vec<SitemapEntry>, DbErr> {
+        let latest_post = Query::select()
+            .expr(Expr::col((posts::Entity, posts::Column::UpdatedAt)).max())
+            .from(posts::Entity)
+            .and_where(
+                Expr::col((posts::Entity, posts::Column::CategoryId))
+                    .equals((categories::Entity, categories::Column::Id)),
+            )
+            .and_where(posts::Column::DeletedAt.is_null())
+            .and_where(posts::Column::Status.eq(PostStatus::Published))
+            .and_where(posts::Column::Visibility.eq(PostVisibility::Public))
+            .to_owned();
+
+        // GREATEST ignores the NULL of categories without published posts.
+        let last_modified = Func::greatest([
+            Expr::col((categories::Entity, categories::Column::UpdatedAt)).into(),
+            SimpleExpr::SubQuery(None, Box::new(latest_post.into_sub_query_statement())),
+        ]);
+
+        Categories::find()
+            .select_only()
+            .column(categories::Column::Id)
+            .column_as(SimpleExpr::from(last_modified), "last_modified")
+            .filter(categories::Column::DeletedAt.is_null())
+            .order_by_asc(categories::Column::Id)
+            .into_model::<SitemapEntry>()
+            .all(&self.db_pool)
+            .await
+    }
+
+    async fn find_authors(&self) -> Result<Vec<SitemapEntry>, DbErr> {
+        Posts::find()
+            .select_only()
+            .column_as(posts::Column::UserId, "id")
+            .column_as(posts::Column::UpdatedAt.max(), "last_modified")
+            .filter(posts::Column::DeletedAt.is_null())
+            .filter(posts::Column::Status.eq(PostStatus::Published))
+            .filter(posts::Column::Visibility.eq(PostVisibility::Public))
+            .group_by(posts::Column::UserId)
+            .order_by_asc(posts::Column::UserId)
+            .into_model::<SitemapEntry>()
+            .all(&self.db_pool)
+            .await
+    }
+}
@@ -0,0 +1,185 @@
+use async_trait::async_trait;
+use sea_orm::sea_query::{Expr, OnConflict, Query};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, JoinType, QueryFilter,
+    QueryOrder, QuerySelect, RelationTrait, Set,
+};
+
+use crate::abstract_trait::TagRepositoryTrait;
+use crate::domain::{TagResponse, VisibilityScope};
+use crate::entities::{post_tags, tags, Tags};
+use crate::repository::visibility::visible_posts;
+use crate::utils::generate_slug;
+
+pub struct TagRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl TagRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl TagRepositoryTrait for TagRepository {
+    async fn find_all_with_post_count(&self, scope: &VisibilityScope) -> Result<Vec<TagResponse>, DbErr> {
+        // Filtering in the join keeps tags whose posts are all hidden, with a count of zero.
+        let visible = visible_posts(scope);
+
+        Tags::find()
+            .select_only()
+            .column(tags::Column::Id)
+            .column(tags::Column::Name)
+            .column(tags::Column::Slug)
+            .column_as(
+                Expr::cust(r#"COUNT("posts"."id") FILTER (WHERE "posts"."deleted_at" IS NULL)"#),
+                "post_count",
+            )
+            .left_join(post_tags::Entity)
+            .join(
+                JoinType::LeftJoin,
+                post_tags::Relation::Posts.def().on_condition(move |_, _| visible.clone()),
+            )
+            .group_by(tags::Column::Id)
+            .order_by_asc(tags::Column::Name)
+            .into_model::<TagResponse>()
+            .all(&self.db_pool)
+            .await
+    }
+
+    async fn find_by_slug(&self, slug: &str) -> Result<Option<tags::Model>, DbErr> {
+        Tags::find()
+            .filter(tags::Column::Slug.eq(slug))
+            .one(&self.db_pool)
+            .await
+    }
+}
+
+/// Replaces the tags of a post with `names`, creating missing tags and
+/// dropping the ones no post uses anymore.
+pub(crate) async fn sync_post_tags<C: ConnectionTrait>(
+    db: &C,
+    post_id: i32,
+    names: &[String],
+) -> Result<(), DbErr> {
+    let current: Vec<i32> = post_tags::Entity::find()
+        .select_only()
+        .column(post_tags::Column::TagId)
+        .filter(post_tags::Column::PostId.eq(post_id))
+        .into_tuple()
+        .all(db)
+        .await?;
+
+    let tag_ids = upsert_tags(db, names).await?;
+
+    let removed: Vec<i32> = current.iter().copied().filter(|tag_id| !tag_ids.contains(tag_id)).collect();
+    if removed.is_empty() && tag_ids.iter().all(|tag_id| current.contains(tag_id)) {
+        return Ok(());
+    }
+
+    if !removed.is_empty() {
+        post_tags::Entity::delete_many()
+            .filter(post_tags::Column::PostId.eq(post_id))
+            .filter(post_tags::Column::TagId.is_in(removed.clone()))
+            .exec(db)
+            .await?;
+    }
+
+    link_post_tags(db, &[post_id], &tag_ids).await?;
+
+    // Only the tags just detached can have become orphans; the purge job sweeps the rest.
+    delete_orphan_tags(db, Some(&removed)).await
+}
+
+/// Adds the tags in `names` to each of the posts, keeping the tags they already have.
+pub(crate) async fn add_post_tags<C: ConnectionTrait>(
+    db: &C,
+    post_ids: &[i32],
+    names: &[String],
+) -> Result<(), DbErr> {
+    let tag_ids = upsert_tags(db, names).await?;
+
+    link_post_tags(db, post_ids, &tag_ids).await
+}
+
+/// Creates the tags in `names` that do not exist yet and returns the ids of all of them.
+async fn upsert_tags<C: ConnectionTrait>(db: &C, names: &[String]) -> Result<Vec<i32>, DbErr> {
+    let mut tag_ids = Vec::new();
+
+    for name in names {
+        let slug = generate_slug(name.trim());
+        if slug.is_empty() {
+            continue;
+        }
+
+        Tags::insert(tags::ActiveModel {
+            name: Set(name.trim().to_string()),
+            slug: Set(slug.clone()),
+            ..Default::default()
+        })
+        .on_conflict(OnConflict::column(tags::Column::Slug).do_nothing().to_owned())
+        .exec_without_returning(db)
+        .await?;
+
+        let tag = Tags::find()
+            .filter(tags::Column::Slug.eq(&slug))
+            .one(db)
+            .await?
+            .ok_or(DbErr::RecordNotFound(format!("Tag {} not found", slug)))?;
+
+        if !tag_ids.contains(&tag.id) {
+            tag_ids.push(tag.id);
+        }
+    }
+
+    Ok(tag_ids)
+}
+
+async fn link_post_tags<C: ConnectionTrait>(db: &C, post_ids: &[i32], tag_ids: &[i32]) -> Result<(), DbErr> {
+    if post_ids.is_empty() || tag_ids.is_empty() {
+        return Ok(());
+    }
+
+    let links = post_ids.iter().flat_map(|post_id| {
+        tag_ids.iter().map(move |tag_id| post_tags::ActiveModel {
+            post_id: Set(*post_id),
+            tag_id: Set(*tag_id),
+        })
+    });
+
+    post_tags::Entity::insert_many(links)
+        .on_conflict(
+            OnConflict::columns([post_tags::Column::PostId, post_tags::Column::TagId])
+                .do_nothing()
+                .to_owned(),
+        )
+        .exec_without_returning(db)
+        .await?;
+
+    Ok(())
+}
+
+/// Removes tags that are no longer attached to any post, limited to `candidates` when given.
+pub(crate) async fn delete_orphan_tags<C: ConnectionTrait>(db: &C, candidates: Option<&[i32]>) -> Result<(), DbErr> {
+    let mut delete = Tags::delete_many();
+    if let Some(candidates) = candidates {
+        if candidates.is_empty() {
+            return Ok(());
+        }
+        delete = delete.filter(tags::Column::Id.is_in(candidates.to_vec()));
+    }
+
+    delete
+        .filter(
+            tags::Column::Id.not_in_subquery(
+                Query::select()
+                    .column(post_tags::Column::TagId)
+                    .from(post_tags::Entity)
+                    .to_owned(),
+            ),
+        )
+        .exec(db)
+        .await
+        .map(|_| ())
+}
@@ -0,0 +1,143 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sea_orm::sea_query::{Expr, Query};
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, TransactionTrait,
+};
+
+use crate::abstract_trait::TrashRepositoryTrait;
+use crate::domain::{TrashItemResponse, TrashKind};
+use crate::entities::{categories, comments, posts, Categories, Comments, Posts};
+use crate::repository::tag::delete_orphan_tags;
+
+pub struct TrashRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl TrashRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl TrashRepositoryTrait for TrashRepository {
+    async fn find_deleted_by(&self, user_id: i32) -> Result<Vec<TrashItemResponse>, DbErr> {
+        let posts = Posts::find()
+            .filter(posts::Column::DeletedAt.is_not_null())
+            .filter(posts::Column::DeletedBy.eq(user_id))
+            .all(&self.db_pool)
+            .await?;
+
+        let comments = Comments::find()
+            .filter(comments::Column::DeletedAt.is_not_null())
+            .filter(comments::Column::DeletedBy.eq(user_id))
+            .all(&self.db_pool)
+            .await?;
+
+        let categories = Categories::find()
+            .filter(categories::Column::DeletedAt.is_not_null())
+            .filter(categories::Column::DeletedBy.eq(user_id))
+            .all(&self.db_pool)
+            .await?;
+
+        let mut items: Vec<(Option<DateTimeWithTimeZone>, TrashItemResponse)> = Vec::new();
+
+        items.extend(posts.into_iter().map(|post| {
+            (post.deleted_at, TrashItemResponse::new(TrashKind::Post, post.id, post.title, post.deleted_at))
+        }));
+        items.extend(comments.into_iter().map(|comment| {
+            (comment.deleted_at, TrashItemResponse::new(TrashKind::Comment, comment.id, comment.comment, comment.deleted_at))
+        }));
+        items.extend(categories.into_iter().map(|category| {
+            (category.deleted_at, TrashItemResponse::new(TrashKind::Category, category.id, category.name, category.deleted_at))
+        }));
+
+        // Most recently deleted first
+        items.sort_by_key(|(deleted_at, _)| std::cmp::Reverse(*deleted_at));
+
+        Ok(items.into_iter().map(|(_, item)| item).collect())
+    }
+
+    async fn restore(&self, kind: TrashKind, id: i32, user_id: i32) -> Result<bool, DbErr> {
+        // `update_many` skips `before_save`, so the restore sets `updated_at` itself.
+        let now: DateTimeWithTimeZone = Utc::now().into();
+
+        let result = match kind {
+            TrashKind::Post => {
+                Posts::update_many()
+                    .col_expr(posts::Column::DeletedAt, Expr::cust("NULL"))
+                    .col_expr(posts::Column::DeletedBy, Expr::cust("NULL"))
+                    .col_expr(posts::Column::Version, Expr::col(posts::Column::Version).add(1))
+                    .col_expr(posts::Column::UpdatedAt, Expr::value(now))
+                    .filter(posts::Column::Id.eq(id))
+                    .filter(posts::Column::DeletedAt.is_not_null())
+                    .filter(posts::Column::DeletedBy.eq(user_id))
+                    .exec(&self.db_pool)
+                    .await?
+            }
+            TrashKind::Comment => {
+                Comments::update_many()
+                    .col_expr(comments::Column::DeletedAt, Expr::cust("NULL"))
+                    .col_expr(comments::Column::DeletedBy, Expr::cust("NULL"))
+                    .col_expr(comments::Column::Version, Expr::col(comments::Column::Version).add(1))
+                    .col_expr(comments::Column::UpdatedAt, Expr::value(now))
+                    .filter(comments::Column::Id.eq(id))
+                    .filter(comments::Column::DeletedAt.is_not_null())
+                    .filter(comments::Column::DeletedBy.eq(user_id))
+                    .exec(&self.db_pool)
+                    .await?
+            }
+            TrashKind::Category => {
+                Categories::update_many()
+                    .col_expr(categories::Column::DeletedAt, Expr::cust("NULL"))
+                    .col_expr(categories::Column::DeletedBy, Expr::cust("NULL"))
+                    .col_expr(categories::Column::Version, Expr::col(categories::Column::Version).add(1))
+                    .col_expr(categories::Column::UpdatedAt, Expr::value(now))
+                    .filter(categories::Column::Id.eq(id))
+                    .filter(categories::Column::DeletedAt.is_not_null())
+                    .filter(categories::Column::DeletedBy.eq(user_id))
+                    .exec(&self.db_pool)
+                    .await?
+            }
+        };
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn purge(&self, deleted_before: DateTimeWithTimeZone) -> Result<u64, DbErr> {
+        let txn = self.db_pool.begin().await?;
+
+        let comments = Comments::delete_many()
+            .filter(comments::Column::DeletedAt.lt(deleted_before))
+            .exec(&txn)
+            .await?;
+
+        // Comments and tag links of purged posts go with them through the cascading foreign keys
+        let posts = Posts::delete_many()
+            .filter(posts::Column::DeletedAt.lt(deleted_before))
+            .exec(&txn)
+            .await?;
+
+        delete_orphan_tags(&txn, None).await?;
+
+        // A category that still has posts, even trashed ones, is kept until they are gone
+        let categories = Categories::delete_many()
+            .filter(categories::Column::DeletedAt.lt(deleted_before))
+            .filter(
+                categories::Column::Id.not_in_subquery(
+                    Query::select()
+                        .column(posts::Column::CategoryId)
+                        .from(posts::Entity)
+                        .to_owned(),
+                ),
+            )
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await?;
+
+        Ok(comments.rows_affected + posts.rows_affected + categories.rows_affected)
+    }
+}
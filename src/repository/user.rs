@@ -1,9 +1,10 @@
 use sea_orm::{prelude::*, Set};
-use sea_orm::{DatabaseConnection, DbErr};
+use sea_orm::{DatabaseConnection, DbErr, TransactionTrait};
 use async_trait::async_trait;
 use crate::abstract_trait::UserRepositoryTrait;
 use crate::domain::{CreateUserRequest, UpdateUserRequest};
 use crate::entities::users; 
+use crate::repository::version::bump_version;
 
 pub struct UserRepository {
     db_pool: DatabaseConnection,
@@ -56,12 +57,25 @@ impl UserRepositoryTrait for UserRepository {
             None => return Err(DbErr::Custom("User ID is required".to_string())), 
         };
     
-        let mut user: users::ActiveModel = users::Entity::find_by_id(id)
-            .one(&self.db_pool)
+        let txn = self.db_pool.begin().await?;
+
+        let user = users::Entity::find_by_id(id)
+            .one(&txn)
             .await?
-            .ok_or(DbErr::Custom("User not found".to_string()))?
-            .into();
-    
+            .ok_or(DbErr::RecordNotFound("User not found".to_string()))?;
+
+        bump_version::<users::Entity, _>(
+            &txn,
+            users::Column::Id,
+            users::Column::Version,
+            users::Column::UpdatedAt,
+            user.id,
+            input.version.unwrap_or(user.version),
+        )
+        .await?;
+
+        let mut user: users::ActiveModel = user.into();
+
         // Update fields if provided
         if let Some(firstname) = &input.firstname {
             user.firstname = Set(firstname.clone());
@@ -76,7 +90,11 @@ impl UserRepositoryTrait for UserRepository {
         }
     
         // Update the user in the database
-        user.update(&self.db_pool).await
+        let user = user.update(&txn).await?;
+
+        txn.commit().await?;
+
+        Ok(user)
     }
     
 
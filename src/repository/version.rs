@@ -0,0 +1,38 @@
+use chrono::Utc;
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter};
+
+/// Conditionally bumps the version of a row, `UPDATE ... SET version = version + 1,
+/// updated_at = now() WHERE id = ? AND version = ?`.
+///
+/// Fails with `DbErr::RecordNotUpdated` when the row was changed since `expected`
+/// was read. Run it in the same transaction as the actual update so the row stays
+/// locked until the change is committed.
+pub(crate) async fn bump_version<E, C>(
+    db: &C,
+    id_column: E::Column,
+    version_column: E::Column,
+    updated_at_column: E::Column,
+    id: i32,
+    expected: i32,
+) -> Result<(), DbErr>
+where
+    E: EntityTrait,
+    C: ConnectionTrait,
+{
+    let result = E::update_many()
+        .col_expr(version_column, Expr::col(version_column).add(1))
+        // `update_many` skips `before_save`, which keeps `updated_at` current elsewhere.
+        .col_expr(updated_at_column, Expr::value(DateTimeWithTimeZone::from(Utc::now())))
+        .filter(id_column.eq(id))
+        .filter(version_column.eq(expected))
+        .exec(db)
+        .await?;
+
+    if result.rows_affected == 0 {
+        return Err(DbErr::RecordNotUpdated);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sea_orm::sea_query::{Expr, OnConflict};
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    Set,
+};
+
+use crate::abstract_trait::ViewRepositoryTrait;
+use crate::domain::DailyViewsResponse;
+use crate::entities::{post_view_stats, posts, PostViewStats, Posts};
+
+pub struct ViewRepository {
+    db_pool: DatabaseConnection,
+}
+
+impl ViewRepository {
+    pub fn new(db_pool: DatabaseConnection) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl ViewRepositoryTrait for ViewRepository {
+    async fn add_views(&self, counts: &[(i32, NaiveDate, i64)]) -> Result<u64, DbErr> {
+        if counts.is_empty() {
+            return Ok(0);
+        }
+
+        // Posts purged since the views were buffered would fail the foreign key.
+        let post_ids: Vec<i32> = Posts::find()
+            .select_only()
+            .column(posts::Column::Id)
+            .filter(posts::Column::Id.is_in(counts.iter().map(|(post_id, _, _)| *post_id)))
+            .into_tuple()
+            .all(&self.db_pool)
+            .await?;
+
+        let rows: Vec<post_view_stats::ActiveModel> = counts
+            .iter()
+            .filter(|(post_id, _, _)| post_ids.contains(post_id))
+            .map(|(post_id, day, views)| post_view_stats::ActiveModel {
+                post_id: Set(*post_id),
+                day: Set(*day),
+                views: Set(*views),
+            })
+            .collect();
+
+        let written = rows.len() as u64;
+
+        if written == 0 {
+            return Ok(0);
+        }
+
+        PostViewStats::insert_many(rows)
+            .on_conflict(
+                OnConflict::columns([post_view_stats::Column::PostId, post_view_stats::Column::Day])
+                    .value(
+                        post_view_stats::Column::Views,
+                        Expr::cust(r#""post_view_stats"."views" + "excluded"."views""#),
+                    )
+                    .to_owned(),
+            )
+            .exec_without_returning(&self.db_pool)
+            .await?;
+
+        Ok(written)
+    }
+
+    async fn find_by_post(&self, post_id: i32, from: NaiveDate, to: NaiveDate) -> Result<Vec<DailyViewsResponse>, DbErr> {
+        PostViewStats::find()
+            .select_only()
+            .column(post_view_stats::Column::Day)
+            .column(post_view_stats::Column::Views)
+            .filter(post_view_stats::Column::PostId.eq(post_id))
+            .filter(post_view_stats::Column::Day.between(from, to))
+            .order_by_asc(post_view_stats::Column::Day)
+            .into_model::<DailyViewsResponse>()
+            .all(&self.db_pool)
+            .await
+    }
+
+    async fn find_by_author(&self, user_id: i32, from: NaiveDate, to: NaiveDate) -> Result<Vec<DailyViewsResponse>, DbErr> {
+        PostViewStats::find()
+            .select_only()
+            .column(post_view_stats::Column::Day)
+            .column_as(Expr::cust(r#"SUM("post_view_stats"."views")::bigint"#), "views")
+            .inner_join(Posts)
+            .filter(posts::Column::UserId.eq(user_id))
+            .filter(post_view_stats::Column::Day.between(from, to))
+            .group_by(post_view_stats::Column::Day)
+            .order_by_asc(post_view_stats::Column::Day)
+            .into_model::<DailyViewsResponse>()
+            .all(&self.db_pool)
+            .await
+    }
+}
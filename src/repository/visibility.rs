@@ -0,0 +1,24 @@
+use sea_orm::{ColumnTrait, Condition};
+
+use crate::domain::VisibilityScope;
+use crate::entities::{posts, sea_orm_active_enums::PostStatus};
+
+/// Condition on `posts` matching the posts listed by `scope`. Outside an unrestricted scope, only
+/// published posts are listed besides the author's own.
+pub(crate) fn visible_posts(scope: &VisibilityScope) -> Condition {
+    let visibilities = match &scope.visibilities {
+        Some(visibilities) => visibilities.clone(),
+        None => return Condition::all(),
+    };
+
+    let mut condition = Condition::any().add(
+        Condition::all()
+            .add(posts::Column::Visibility.is_in(visibilities))
+            .add(posts::Column::Status.eq(PostStatus::Published)),
+    );
+    if let Some(author_id) = scope.author_id {
+        condition = condition.add(posts::Column::UserId.eq(author_id));
+    }
+
+    condition
+}
@@ -21,6 +21,8 @@ impl AuthService {
 #[async_trait]
 impl AuthServiceTrait for AuthService {
     async fn register_user(&self, input: &RegisterRequest) -> Result<ApiResponse<UserResponse>, ErrorResponse> {
+        input.validate()?;
+
         let exists = self.repository.find_by_email_exists(&input.email).await
             .map_err(AppError::from)  
             .map_err(ErrorResponse::from)?; 
@@ -71,6 +73,6 @@ impl AuthServiceTrait for AuthService {
     }
 
     fn verify_token(&self, token: &str) -> Result<i64, AppError> {
-        return self.jwt_config.verify_token(token).map_err(AppError::from);
+        self.jwt_config.verify_token(token)
     }
 }
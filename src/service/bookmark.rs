@@ -0,0 +1,211 @@
+use crate::{
+    abstract_trait::{BookmarkServiceTrait, DynBookmarkRepository, DynPostsRepository},
+    domain::{
+        ApiResponse, BookmarkQuery, BookmarkResponse, CreateBookmarkRequest, ErrorResponse,
+        PaginatedResponse, Pagination, ReadingListRequest, ReadingListResponse,
+        SharedReadingListResponse,
+    },
+    utils::AppError,
+};
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::{PostAccess, PostResponseBuilder};
+
+pub struct BookmarkService {
+    repository: DynBookmarkRepository,
+    post_repository: DynPostsRepository,
+    responses: PostResponseBuilder,
+    access: PostAccess,
+}
+
+impl BookmarkService {
+    pub fn new(repository: DynBookmarkRepository, post_repository: DynPostsRepository, responses: PostResponseBuilder, access: PostAccess) -> Self {
+        Self { repository, post_repository, responses, access }
+    }
+
+    async fn find_reading_list(&self, id: i32, user_id: i32) -> Result<Option<ReadingListResponse>, ErrorResponse> {
+        let list = self.repository.find_reading_list(id, user_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(list.map(|(list, count)| ReadingListResponse::new(list, count)))
+    }
+}
+
+#[async_trait]
+impl BookmarkServiceTrait for BookmarkService {
+    async fn add_bookmark(&self, user_id: i32, post_id: i32, input: &CreateBookmarkRequest, token: Option<&str>) -> Result<ApiResponse<BookmarkResponse>, ErrorResponse> {
+        let post = self.post_repository.get_post(post_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .ok_or_else(|| ErrorResponse::from(AppError::NotFound(format!("Posts with id {} not found", post_id))))?;
+        self.access.check(&self.access.viewer(Some(user_id)).await?, &post, token)?;
+
+        let (bookmark, post) = self.repository.add_bookmark(user_id, post_id, input.reading_list_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Bookmark saved successfully".to_string(),
+            data: BookmarkResponse::new(bookmark, self.responses.build_one(post, Some(user_id)).await?),
+        })
+    }
+
+    async fn remove_bookmark(&self, user_id: i32, post_id: i32) -> Result<Option<ApiResponse<()>>, ErrorResponse> {
+        let removed = self.repository.remove_bookmark(user_id, post_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        if !removed {
+            return Ok(None);
+        }
+
+        Ok(Some(ApiResponse {
+            status: "success".to_string(),
+            message: "Bookmark removed successfully".to_string(),
+            data: (),
+        }))
+    }
+
+    async fn get_bookmarks(
+        &self,
+        user_id: i32,
+        query: &BookmarkQuery,
+        pagination: &Pagination,
+    ) -> Result<ApiResponse<PaginatedResponse<BookmarkResponse>>, ErrorResponse> {
+        let scope = self.access.scope(&self.access.viewer(Some(user_id)).await?);
+
+        let (bookmarks, total) = self.repository.find_bookmarks(user_id, query.list, &scope, pagination)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let (bookmarks, posts): (Vec<_>, Vec<_>) = bookmarks.into_iter().unzip();
+        let posts = self.responses.build(posts, Some(user_id)).await?;
+
+        let items = bookmarks.into_iter()
+            .zip(posts)
+            .map(|(bookmark, post)| BookmarkResponse::new(bookmark, post))
+            .collect();
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Bookmarks retrieved successfully".to_string(),
+            data: PaginatedResponse::new(items, pagination, total),
+        })
+    }
+
+    async fn create_reading_list(&self, user_id: i32, input: &ReadingListRequest) -> Result<ApiResponse<ReadingListResponse>, ErrorResponse> {
+        input.validate()?;
+
+        let list = self.repository.create_reading_list(user_id, input.name.trim())
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Reading list created successfully".to_string(),
+            data: ReadingListResponse::new(list, 0),
+        })
+    }
+
+    async fn get_reading_lists(&self, user_id: i32) -> Result<ApiResponse<Vec<ReadingListResponse>>, ErrorResponse> {
+        let lists = self.repository.find_reading_lists(user_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Reading lists retrieved successfully".to_string(),
+            data: lists.into_iter()
+                .map(|(list, count)| ReadingListResponse::new(list, count))
+                .collect(),
+        })
+    }
+
+    async fn update_reading_list(&self, id: i32, user_id: i32, input: &ReadingListRequest) -> Result<Option<ApiResponse<ReadingListResponse>>, ErrorResponse> {
+        input.validate()?;
+
+        let updated = self.repository.update_reading_list(id, user_id, input.name.trim())
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        if !updated {
+            return Ok(None);
+        }
+
+        Ok(self.find_reading_list(id, user_id).await?.map(|list| ApiResponse {
+            status: "success".to_string(),
+            message: "Reading list updated successfully".to_string(),
+            data: list,
+        }))
+    }
+
+    async fn delete_reading_list(&self, id: i32, user_id: i32) -> Result<Option<ApiResponse<()>>, ErrorResponse> {
+        let deleted = self.repository.delete_reading_list(id, user_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        if !deleted {
+            return Ok(None);
+        }
+
+        Ok(Some(ApiResponse {
+            status: "success".to_string(),
+            message: "Reading list deleted successfully".to_string(),
+            data: (),
+        }))
+    }
+
+    async fn share_reading_list(&self, id: i32, user_id: i32, shared: bool) -> Result<Option<ApiResponse<ReadingListResponse>>, ErrorResponse> {
+        let token = shared.then(|| Uuid::new_v4().simple().to_string());
+
+        let updated = self.repository.set_share_token(id, user_id, token)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        if !updated {
+            return Ok(None);
+        }
+
+        Ok(self.find_reading_list(id, user_id).await?.map(|list| ApiResponse {
+            status: "success".to_string(),
+            message: if shared { "Reading list shared successfully" } else { "Reading list unshared successfully" }.to_string(),
+            data: list,
+        }))
+    }
+
+    async fn get_shared_reading_list(
+        &self,
+        token: &str,
+        pagination: &Pagination,
+        viewer: Option<i32>,
+    ) -> Result<Option<ApiResponse<SharedReadingListResponse>>, ErrorResponse> {
+        let list = self.repository.find_reading_list_by_token(token)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let list = match list {
+            Some(list) => list,
+            None => return Ok(None),
+        };
+
+        let scope = self.access.scope(&self.access.viewer(viewer).await?);
+
+        let (posts, total) = self.repository.find_reading_list_posts(list.id, &scope, pagination)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let posts = self.responses.build(posts, viewer).await?;
+
+        Ok(Some(ApiResponse {
+            status: "success".to_string(),
+            message: "Reading list retrieved successfully".to_string(),
+            data: SharedReadingListResponse {
+                name: list.name,
+                posts: PaginatedResponse::new(posts, pagination, total),
+            },
+        }))
+    }
+}
@@ -1,13 +1,29 @@
-use crate::{abstract_trait::{CategoryServiceTrait, DynCategoryRepository}, domain::{ApiResponse, CategoryResponse, CreateCategoryRequest, UpdateCategoryRequest, ErrorResponse}, utils::AppError};
+use crate::{abstract_trait::{CategoryServiceTrait, DynCategoryRepository, DynUserRepository}, domain::{ApiResponse, CategoryResponse, CreateCategoryRequest, UpdateCategoryRequest, ErrorResponse}, entities::sea_orm_active_enums::UserRole, utils::{validate_metadata_schema, AppError}};
 use async_trait::async_trait;
+use serde_json::Value;
 
 pub struct CategoryService {
-    repository: DynCategoryRepository
+    repository: DynCategoryRepository,
+    user_repository: DynUserRepository,
 }
 
 impl CategoryService {
-    pub fn new(repository: DynCategoryRepository) -> Self {
-        Self { repository }
+    pub fn new(repository: DynCategoryRepository, user_repository: DynUserRepository) -> Self {
+        Self { repository, user_repository }
+    }
+
+    async fn require_admin(&self, user_id: i32) -> Result<(), ErrorResponse> {
+        let role = self.user_repository.find_by_id(user_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .map(|user| user.role)
+            .unwrap_or_default();
+
+        if role != UserRole::Admin {
+            return Err(ErrorResponse::from(AppError::Forbidden("Only admins can change metadata schemas".to_string())));
+        }
+
+        Ok(())
     }
 }
 
@@ -16,7 +32,7 @@ impl CategoryServiceTrait for CategoryService {
     async fn get_categories(&self) -> Result<ApiResponse<Vec<CategoryResponse>>, ErrorResponse> {
         let categories = self.repository.find_all().await.map_err(AppError::from).map_err(ErrorResponse::from)?;
 
-        let category_responses: Vec<CategoryResponse> = categories.into_iter().map(|category| CategoryResponse::from(category)).collect();
+        let category_responses: Vec<CategoryResponse> = categories.into_iter().map(CategoryResponse::from).collect();
     
        
         Ok(ApiResponse {
@@ -60,8 +76,27 @@ impl CategoryServiceTrait for CategoryService {
         }))
     }
 
-    async fn delete_category(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
-        self.repository.delete(id).await.map_err(AppError::from).map_err(ErrorResponse::from)?;
+    async fn set_metadata_schema(&self, id: i32, schema: Option<&Value>, user_id: i32, expected_version: Option<i32>) -> Result<ApiResponse<CategoryResponse>, ErrorResponse> {
+        self.require_admin(user_id).await?;
+
+        if let Some(schema) = schema {
+            validate_metadata_schema(schema).map_err(ErrorResponse::from)?;
+        }
+
+        let category = self.repository.set_metadata_schema(id, schema, expected_version).await.map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: match schema {
+                Some(_) => "Metadata schema updated successfully".to_string(),
+                None => "Metadata schema removed successfully".to_string(),
+            },
+            data: CategoryResponse::from(category),
+        })
+    }
+
+    async fn delete_category(&self, id: i32, user_id: i32, expected_version: Option<i32>) -> Result<ApiResponse<()>, ErrorResponse> {
+        self.repository.delete(id, user_id, expected_version).await.map_err(AppError::from).map_err(ErrorResponse::from)?;
 
         Ok(ApiResponse {
             status: "success".to_string(),
@@ -1,24 +1,75 @@
-use crate::{abstract_trait::{CommentServiceTrait, DynCommentRepository, }, domain::{ApiResponse, CommentResponse, CreateCommentRequest, ErrorResponse, UpdateCommentRequest},  utils::AppError};
+use crate::{abstract_trait::{CommentServiceTrait, DynCommentRepository, DynPostsRepository, DynReactionRepository}, domain::{changed, ApiResponse, CommentDocument, CommentResponse, CreateCommentRequest, ErrorResponse, PatchCommentRequest, PatchDocument, UpdateCommentRequest}, entities::{comments, posts}, utils::AppError};
 use async_trait::async_trait;
 
+use super::{PostAccess, Viewer};
+
 pub struct CommentService {
     repository: DynCommentRepository,
+    reaction_repository: DynReactionRepository,
+    post_repository: DynPostsRepository,
+    access: PostAccess,
 }
 
 impl CommentService {
-    pub fn new(repository: DynCommentRepository) -> Self {
-        Self { repository }
+    pub fn new(repository: DynCommentRepository, reaction_repository: DynReactionRepository, post_repository: DynPostsRepository, access: PostAccess) -> Self {
+        Self { repository, reaction_repository, post_repository, access }
+    }
+
+    async fn find_comment(&self, id: i32) -> Result<comments::Model, ErrorResponse> {
+        self.repository.find_by_id(id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .ok_or_else(|| ErrorResponse::from(AppError::NotFound(format!("Comment with id {} not found", id))))
+    }
+
+    /// The post commented on; posts the viewer cannot read are reported as missing.
+    async fn find_post(&self, post_id: i32, viewer: &Viewer, token: Option<&str>) -> Result<posts::Model, ErrorResponse> {
+        let post = self.post_repository.get_post(post_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .ok_or_else(|| ErrorResponse::from(AppError::NotFound(format!("Posts with id {} not found", post_id))))?;
+        self.access.check(viewer, &post, token)?;
+
+        Ok(post)
+    }
+
+    /// Comments are moderated by the author of the post and editors.
+    async fn check_moderator(&self, comment: &comments::Model, viewer: Option<i32>, token: Option<&str>) -> Result<(), ErrorResponse> {
+        let viewer = self.access.viewer(viewer).await?;
+        let post = self.find_post(comment.id_post_comment, &viewer, token).await?;
+
+        self.access.check_write(&viewer, &post, token)
+    }
+
+    async fn to_responses(&self, comments: Vec<comments::Model>, viewer: Option<i32>) -> Result<Vec<CommentResponse>, ErrorResponse> {
+        let comment_ids: Vec<i32> = comments.iter().map(|comment| comment.id).collect();
+        let reactions = self.reaction_repository.find_for_comments(&comment_ids, viewer)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(comments.into_iter()
+            .zip(reactions)
+            .map(|(comment, reactions)| CommentResponse {
+                reactions,
+                ..CommentResponse::from(comment)
+            })
+            .collect())
+    }
+
+    async fn to_response(&self, comment: comments::Model, viewer: Option<i32>) -> Result<CommentResponse, ErrorResponse> {
+        let mut responses = self.to_responses(vec![comment], viewer).await?;
+
+        Ok(responses.remove(0))
     }
 }
 
 #[async_trait]
 impl CommentServiceTrait for CommentService {
-    async fn get_comments(&self) -> Result<ApiResponse<Vec<CommentResponse>>, ErrorResponse> {
-        let comments = self.repository.find_all().await .map_err(AppError::from).map_err(ErrorResponse::from)?;
+    async fn get_comments(&self, viewer: Option<i32>) -> Result<ApiResponse<Vec<CommentResponse>>, ErrorResponse> {
+        let scope = self.access.scope(&self.access.viewer(viewer).await?);
+        let comments = self.repository.find_all(&scope).await .map_err(AppError::from).map_err(ErrorResponse::from)?;
         
-        let response = comments.into_iter().map(|comment| {
-            CommentResponse::from(comment)
-        }).collect();
+        let response = self.to_responses(comments, viewer).await?;
         
         Ok(ApiResponse{
             status: "success".to_string(),
@@ -27,23 +78,32 @@ impl CommentServiceTrait for CommentService {
         })
     }
 
-    async fn get_comment(&self, id: i32) -> Result<Option<ApiResponse<CommentResponse>>, ErrorResponse> {
+    async fn get_comment(&self, id: i32, viewer: Option<i32>, token: Option<&str>) -> Result<Option<ApiResponse<CommentResponse>>, ErrorResponse> {
         let comment = self.repository.find_by_id(id).await .map_err(AppError::from).map_err(ErrorResponse::from)?;
 
         
         
         if let Some(comment) = comment{
+            // A comment is readable exactly when the post it belongs to is.
+            let post = self.post_repository.get_post(comment.id_post_comment)
+                .await
+                .map_err(AppError::from).map_err(ErrorResponse::from)?
+                .ok_or_else(|| ErrorResponse::from(AppError::NotFound(format!("Comment with id {} not found", id))))?;
+            self.access.check(&self.access.viewer(viewer).await?, &post, token)?;
+
             Ok(Some(ApiResponse{
                 status: "success".to_string(),
                 message: "Comment retrieved successfully".to_string(),
-                data: CommentResponse::from(comment),
+                data: self.to_response(comment, viewer).await?,
             }))
         }else{
             Err(ErrorResponse::from(AppError::NotFound(format!("Comment with id {} not found", id))))
         }
     }
 
-    async fn create_comment(&self, input: &CreateCommentRequest) -> Result<ApiResponse<CommentResponse>, ErrorResponse> {
+    async fn create_comment(&self, input: &CreateCommentRequest, viewer: Option<i32>, token: Option<&str>) -> Result<ApiResponse<CommentResponse>, ErrorResponse> {
+        self.find_post(input.id_post_comment, &self.access.viewer(viewer).await?, token).await?;
+
         let comment = self.repository.create(input).await .map_err(AppError::from).map_err(ErrorResponse::from)?;
         
         Ok(ApiResponse {
@@ -53,18 +113,49 @@ impl CommentServiceTrait for CommentService {
         })
     }
 
-    async fn update_comment(&self, input: &UpdateCommentRequest) -> Result<Option<ApiResponse<CommentResponse>>, ErrorResponse> {
+    async fn update_comment(&self, input: &UpdateCommentRequest, viewer: Option<i32>, token: Option<&str>) -> Result<Option<ApiResponse<CommentResponse>>, ErrorResponse> {
+        let id = input.id
+            .ok_or_else(|| ErrorResponse::from(AppError::ValidationError("Comment ID is required".to_string())))?;
+        self.check_moderator(&self.find_comment(id).await?, viewer, token).await?;
+
         let comment = self.repository.update(input).await.map_err(AppError::from).map_err(ErrorResponse::from)?;
         
         Ok(Some(ApiResponse {
             status: "success".to_string(),
             message: "Comment updated successfully".to_string(),
-            data: CommentResponse::from(comment),
+            data: self.to_response(comment, viewer).await?,
         }))
     }
 
-    async fn delete_comment(&self, id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
-        self.repository.delete(id).await.map_err(AppError::from).map_err(ErrorResponse::from)?;
+    async fn patch_comment(&self, id: i32, patch: &PatchDocument, expected_version: Option<i32>, viewer: Option<i32>, token: Option<&str>) -> Result<ApiResponse<CommentResponse>, ErrorResponse> {
+        let comment = self.find_comment(id).await?;
+        self.check_moderator(&comment, viewer, token).await?;
+
+        let current = CommentDocument::from(&comment);
+        let patched = patch.apply(&current)?;
+        patched.validate()?;
+
+        let comment = self.repository.patch(&PatchCommentRequest {
+            id,
+            user_name_comment: changed(&current.user_name_comment, &patched.user_name_comment),
+            comment: changed(&current.comment, &patched.comment),
+            // The patch was applied to the version just read.
+            version: Some(expected_version.unwrap_or(comment.version)),
+        })
+        .await
+        .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Comment updated successfully".to_string(),
+            data: self.to_response(comment, viewer).await?,
+        })
+    }
+
+    async fn delete_comment(&self, id: i32, user_id: i32, expected_version: Option<i32>, token: Option<&str>) -> Result<ApiResponse<()>, ErrorResponse> {
+        self.check_moderator(&self.find_comment(id).await?, Some(user_id), token).await?;
+
+        self.repository.delete(id, user_id, expected_version).await.map_err(AppError::from).map_err(ErrorResponse::from)?;
         
         Ok(ApiResponse {
             status: "success".to_string(),
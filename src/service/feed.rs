@@ -0,0 +1,177 @@
+use async_trait::async_trait;
+use atom_syndication::{Content, Entry, Feed, FixedDateTime, Link, Person, Text};
+use chrono::{DateTime, Utc};
+use rss::extension::dublincore::DublinCoreExtension;
+use rss::{Channel, Guid, Item};
+
+use crate::{
+    abstract_trait::{DynCategoryRepository, DynPostsRepository, DynUserRepository, FeedServiceTrait},
+    config::SiteConfig,
+    domain::{ErrorResponse, XmlDocument, FeedFormat, FeedScope, VisibilityScope},
+    entities::{posts, tags},
+    utils::AppError,
+};
+
+pub struct FeedService {
+    post_repository: DynPostsRepository,
+    category_repository: DynCategoryRepository,
+    user_repository: DynUserRepository,
+    config: SiteConfig,
+}
+
+impl FeedService {
+    pub fn new(
+        post_repository: DynPostsRepository,
+        category_repository: DynCategoryRepository,
+        user_repository: DynUserRepository,
+        config: SiteConfig,
+    ) -> Self {
+        Self { post_repository, category_repository, user_repository, config }
+    }
+
+    /// Title of the feed, or `None` when the scoped category or author does not exist.
+    async fn feed_title(&self, scope: FeedScope) -> Result<Option<String>, ErrorResponse> {
+        let subject = match scope {
+            FeedScope::All => return Ok(Some(self.config.title.clone())),
+            FeedScope::Category(id) => self.category_repository.find_by_id(id)
+                .await
+                .map_err(AppError::from).map_err(ErrorResponse::from)?
+                .map(|category| category.name),
+            FeedScope::Author(id) => self.user_repository.find_by_id(id)
+                .await
+                .map_err(AppError::from).map_err(ErrorResponse::from)?
+                .map(|user| format!("{} {}", user.firstname, user.lastname)),
+        };
+
+        Ok(subject.map(|subject| format!("{} - {}", self.config.title, subject)))
+    }
+
+    fn render_atom(&self, title: String, feed_url: String, updated: FixedDateTime, posts: &[(posts::Model, Vec<tags::Model>)]) -> String {
+        let entries = posts.iter()
+            .map(|(post, tags)| Entry {
+                title: Text::plain(post.title.clone()),
+                id: self.config.post_url(post.id),
+                updated: post.updated_at,
+                published: Some(post.created_at),
+                authors: vec![Person {
+                    name: post.user_name.clone(),
+                    ..Default::default()
+                }],
+                categories: tags.iter()
+                    .map(|tag| atom_syndication::Category {
+                        term: tag.slug.clone(),
+                        label: Some(tag.name.clone()),
+                        ..Default::default()
+                    })
+                    .collect(),
+                links: vec![Link {
+                    href: self.config.post_url(post.id),
+                    rel: "alternate".to_string(),
+                    ..Default::default()
+                }],
+                summary: Some(Text::plain(post.excerpt.clone())),
+                content: Some(Content {
+                    value: Some(post.body_html.clone()),
+                    content_type: Some("html".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .collect();
+
+        Feed {
+            title: Text::plain(title),
+            id: feed_url.clone(),
+            updated,
+            links: vec![
+                Link {
+                    href: feed_url,
+                    rel: "self".to_string(),
+                    mime_type: Some("application/atom+xml".to_string()),
+                    ..Default::default()
+                },
+                Link {
+                    href: self.config.url("/"),
+                    rel: "alternate".to_string(),
+                    ..Default::default()
+                },
+            ],
+            entries,
+            ..Default::default()
+        }
+        .to_string()
+    }
+
+    fn render_rss(&self, title: String, updated: FixedDateTime, posts: &[(posts::Model, Vec<tags::Model>)]) -> String {
+        let items = posts.iter()
+            .map(|(post, tags)| Item {
+                title: Some(post.title.clone()),
+                link: Some(self.config.post_url(post.id)),
+                description: Some(post.excerpt.clone()),
+                content: Some(post.body_html.clone()),
+                guid: Some(Guid {
+                    value: self.config.post_url(post.id),
+                    permalink: true,
+                }),
+                pub_date: Some(post.created_at.to_rfc2822()),
+                categories: tags.iter()
+                    .map(|tag| rss::Category {
+                        name: tag.name.clone(),
+                        domain: None,
+                    })
+                    .collect(),
+                // RSS `author` must be an email address, so the name goes in `dc:creator`.
+                dublin_core_ext: Some(DublinCoreExtension {
+                    creators: vec![post.user_name.clone()],
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .collect();
+
+        Channel {
+            title: title.clone(),
+            link: self.config.url("/"),
+            description: title,
+            last_build_date: Some(updated.to_rfc2822()),
+            items,
+            ..Default::default()
+        }
+        .to_string()
+    }
+}
+
+#[async_trait]
+impl FeedServiceTrait for FeedService {
+    async fn get_feed(&self, scope: FeedScope, format: FeedFormat) -> Result<Option<XmlDocument>, ErrorResponse> {
+        let title = match self.feed_title(scope).await? {
+            Some(title) => title,
+            None => return Ok(None),
+        };
+
+        // Feeds are read anonymously, so they only carry public posts.
+        let posts = self.post_repository.get_feed_posts(&scope, &VisibilityScope::public(), self.config.feed_limit)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let tags = self.post_repository.get_post_tags(&posts)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let last_modified = posts.iter().map(|post| post.updated_at).max();
+        let updated = last_modified.unwrap_or_else(|| DateTime::<Utc>::UNIX_EPOCH.into());
+        let posts: Vec<_> = posts.into_iter().zip(tags).collect();
+
+        let feed_url = self.config.url(&format!("{}.{}", scope.path(), format.extension()));
+        let body = match format {
+            FeedFormat::Atom => self.render_atom(title, feed_url, updated, &posts),
+            FeedFormat::Rss => self.render_rss(title, updated, &posts),
+        };
+
+        Ok(Some(XmlDocument::new(
+            body,
+            format.content_type(),
+            last_modified.map(|updated| updated.with_timezone(&Utc)),
+        )))
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    abstract_trait::{DynMediaRepository, DynStorage, MediaServiceTrait},
+    config::UploadConfig,
+    domain::{ApiResponse, CreateMediaRequest, ErrorResponse, MediaResponse, UploadMediaRequest},
+    utils::{sniff_image_type, AppError},
+};
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+
+pub struct MediaService {
+    repository: DynMediaRepository,
+    storage: DynStorage,
+    config: UploadConfig,
+}
+
+impl MediaService {
+    pub fn new(repository: DynMediaRepository, storage: DynStorage, config: UploadConfig) -> Self {
+        Self { repository, storage, config }
+    }
+}
+
+#[async_trait]
+impl MediaServiceTrait for MediaService {
+    async fn upload(&self, input: &UploadMediaRequest) -> Result<ApiResponse<MediaResponse>, ErrorResponse> {
+        if input.bytes.is_empty() {
+            return Err(ErrorResponse::from(AppError::ValidationError("File is empty".to_string())));
+        }
+
+        if input.bytes.len() > self.config.max_size {
+            return Err(ErrorResponse::from(AppError::PayloadTooLarge(format!(
+                "File exceeds the maximum size of {} bytes",
+                self.config.max_size
+            ))));
+        }
+
+        let (content_type, extension) = sniff_image_type(&input.bytes)
+            .ok_or_else(|| ErrorResponse::from(AppError::UnsupportedMediaType(
+                "Only JPEG, PNG, GIF and WebP images are allowed".to_string(),
+            )))?;
+
+        // The declared type has to agree with what the file actually contains
+        if let Some(declared) = &input.content_type {
+            if declared != content_type && declared != "application/octet-stream" {
+                return Err(ErrorResponse::from(AppError::UnsupportedMediaType(format!(
+                    "Declared content type {} does not match file content {}",
+                    declared, content_type
+                ))));
+            }
+        }
+
+        let key = format!("{:x}.{}", Sha256::digest(&input.bytes), extension);
+
+        let existing = self.repository.find_by_key(&key)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let media = match existing {
+            Some(media) => media,
+            None => {
+                if !self.storage.exists(&key).await.map_err(ErrorResponse::from)? {
+                    self.storage.put(&key, &input.bytes).await.map_err(ErrorResponse::from)?;
+                }
+
+                let request = CreateMediaRequest {
+                    key: key.clone(),
+                    content_type: content_type.to_string(),
+                    size: input.bytes.len() as i64,
+                    user_id: input.user_id,
+                };
+
+                self.repository.create(&request)
+                    .await
+                    .map_err(AppError::from).map_err(ErrorResponse::from)?
+            }
+        };
+
+        let url = self.storage.url(&media.key);
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Media uploaded successfully".to_string(),
+            data: MediaResponse::from_media(media, url),
+        })
+    }
+
+    fn max_upload_size(&self) -> usize {
+        self.config.max_size
+    }
+}
@@ -3,9 +3,39 @@ mod comment;
 mod posts;
 mod user;
 mod auth;
+mod tag;
+mod search;
+mod media;
+mod trash;
+mod views;
+mod reaction;
+mod post_response;
+mod post_detail;
+mod post_access;
+mod bookmark;
+mod series;
+mod feed;
+mod sitemap;
+mod ranking;
+mod post_lock;
 
 pub use self::category::CategoryService;
 pub use self::comment::CommentService;
 pub use self::posts::PostService;
 pub use self::user::UserService;
-pub use self::auth::AuthService;
\ No newline at end of file
+pub use self::auth::AuthService;
+pub use self::tag::TagService;
+pub use self::search::SearchService;
+pub use self::media::MediaService;
+pub use self::trash::TrashService;
+pub use self::views::ViewService;
+pub use self::reaction::ReactionService;
+pub use self::post_response::PostResponseBuilder;
+pub use self::post_detail::PostDetailBuilder;
+pub use self::post_access::{PostAccess, Viewer};
+pub use self::bookmark::BookmarkService;
+pub use self::series::SeriesService;
+pub use self::feed::FeedService;
+pub use self::sitemap::SitemapService;
+pub use self::ranking::RankingService;
+pub use self::post_lock::PostLockService;
\ No newline at end of file
@@ -0,0 +1,189 @@
+use crate::{
+    abstract_trait::DynUserRepository,
+    config::{Hashing, JwtConfig},
+    domain::{ErrorResponse, UnlockPostResponse, VisibilityScope},
+    entities::{posts, sea_orm_active_enums::{PostStatus, PostVisibility, UserRole}},
+    utils::AppError,
+};
+
+/// The user reading posts; `user_id` is `None` when unauthenticated.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Viewer {
+    pub user_id: Option<i32>,
+    pub role: UserRole,
+}
+
+impl Viewer {
+    pub fn is_editor(&self) -> bool {
+        self.user_id.is_some() && self.role.is_editor()
+    }
+
+    pub fn owns(&self, post: &posts::Model) -> bool {
+        self.user_id == Some(post.user_id)
+    }
+}
+
+/// Enforces post visibility for every service that returns posts.
+#[derive(Clone)]
+pub struct PostAccess {
+    user_repository: DynUserRepository,
+    hashing: Hashing,
+    jwt_config: JwtConfig,
+}
+
+impl PostAccess {
+    pub fn new(user_repository: DynUserRepository, hashing: Hashing, jwt_config: JwtConfig) -> Self {
+        Self { user_repository, hashing, jwt_config }
+    }
+
+    pub async fn viewer(&self, user_id: Option<i32>) -> Result<Viewer, ErrorResponse> {
+        let role = match user_id {
+            Some(user_id) => self.user_repository.find_by_id(user_id)
+                .await
+                .map_err(AppError::from).map_err(ErrorResponse::from)?
+                .map(|user| user.role)
+                .unwrap_or_default(),
+            None => UserRole::default(),
+        };
+
+        Ok(Viewer { user_id, role })
+    }
+
+    /// Fails unless the user is an editor or admin; `action` completes "Only editors can ...".
+    pub async fn require_editor(&self, user_id: i32, action: &str) -> Result<Viewer, ErrorResponse> {
+        let viewer = self.viewer(Some(user_id)).await?;
+
+        if !viewer.is_editor() {
+            return Err(ErrorResponse::from(AppError::Forbidden(format!("Only editors can {}", action))));
+        }
+
+        Ok(viewer)
+    }
+
+    /// Posts listed to the viewer. Drafts and posts in review are only listed to their authors and
+    /// editors, unlisted and password-protected posts are only reachable by ID, members-only posts
+    /// need a login, and authors always see their own posts.
+    pub fn scope(&self, viewer: &Viewer) -> VisibilityScope {
+        if viewer.is_editor() {
+            return VisibilityScope::unrestricted();
+        }
+
+        match viewer.user_id {
+            Some(user_id) => VisibilityScope {
+                visibilities: Some(vec![PostVisibility::Public, PostVisibility::Members]),
+                author_id: Some(user_id),
+            },
+            None => VisibilityScope::public(),
+        }
+    }
+
+    /// Checks that the viewer may read `post`; `token` comes from [`PostAccess::unlock`].
+    pub fn check(&self, viewer: &Viewer, post: &posts::Model, token: Option<&str>) -> Result<(), ErrorResponse> {
+        if viewer.is_editor() || viewer.owns(post) {
+            return Ok(());
+        }
+
+        // Unpublished posts are reported as missing, like private ones.
+        if post.status != PostStatus::Published {
+            return Err(ErrorResponse::from(AppError::NotFound(format!("Posts with id {} not found", post.id))));
+        }
+
+        let error = match post.visibility {
+            PostVisibility::Public | PostVisibility::Unlisted => return Ok(()),
+            PostVisibility::Members if viewer.user_id.is_some() => return Ok(()),
+            PostVisibility::Members => AppError::Unauthorized("Log in to read this post".to_string()),
+            // Reported as missing so that private posts do not reveal their existence.
+            PostVisibility::Private => AppError::NotFound(format!("Posts with id {} not found", post.id)),
+            PostVisibility::Password => {
+                let unlocked = match (token, post.password_hash.as_deref()) {
+                    (Some(token), Some(password_hash)) => {
+                        self.jwt_config.verify_post_token(token, post.id, password_hash)
+                    }
+                    _ => false,
+                };
+
+                if unlocked {
+                    return Ok(());
+                }
+
+                AppError::Forbidden("This post is password protected".to_string())
+            }
+        };
+
+        Err(ErrorResponse::from(error))
+    }
+
+    /// Checks that the viewer may change `post`, which only its author and editors may do. Posts
+    /// the viewer cannot read are reported like in [`PostAccess::check`].
+    pub fn check_write(&self, viewer: &Viewer, post: &posts::Model, token: Option<&str>) -> Result<(), ErrorResponse> {
+        self.check(viewer, post, token)?;
+
+        if !viewer.is_editor() && !viewer.owns(post) {
+            return Err(ErrorResponse::from(AppError::Forbidden(
+                "Only the author or an editor can change this post".to_string(),
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Hash to store for a post with `visibility`; `None` keeps the current hash.
+    pub async fn password_hash(
+        &self,
+        visibility: PostVisibility,
+        password: Option<&str>,
+        current: Option<&str>,
+    ) -> Result<Option<String>, ErrorResponse> {
+        let password = password.filter(|password| !password.trim().is_empty());
+
+        match password {
+            Some(password) if visibility == PostVisibility::Password => {
+                let hash = self.hashing.hash_password(password)
+                    .await
+                    .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+                Ok(Some(hash))
+            }
+            Some(_) => Err(ErrorResponse::from(AppError::ValidationError(
+                "password is only allowed for password protected posts".to_string(),
+            ))),
+            None if visibility == PostVisibility::Password && current.is_none() => {
+                Err(ErrorResponse::from(AppError::ValidationError(
+                    "password is required for password protected posts".to_string(),
+                )))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub async fn unlock(&self, viewer: &Viewer, post: &posts::Model, password: &str) -> Result<UnlockPostResponse, ErrorResponse> {
+        let password_hash = match (post.visibility, post.password_hash.as_deref()) {
+            (PostVisibility::Password, Some(password_hash)) => password_hash,
+            _ => {
+                // Private posts stay hidden from anyone who cannot read them.
+                self.check(viewer, post, None)?;
+
+                return Err(ErrorResponse::from(AppError::ValidationError(
+                    "Post is not password protected".to_string(),
+                )));
+            }
+        };
+
+        self.hashing.compare_password(password_hash, password)
+            .await
+            .map_err(|e| match e {
+                AppError::HashingError(_) => AppError::InvalidCredentials,
+                other => other,
+            })
+            .map_err(ErrorResponse::from)?;
+
+        let (token, expires_at) = self.jwt_config.generate_post_token(post.id, password_hash)
+            .map_err(ErrorResponse::from)?;
+
+        Ok(UnlockPostResponse {
+            post_id: post.id,
+            token,
+            expires_at: expires_at.to_rfc3339(),
+        })
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{
+    abstract_trait::{DynCategoryRepository, DynCommentRepository, DynReactionRepository, DynUserRepository},
+    domain::{
+        CategoryResponse, CommentResponse, ErrorResponse, PaginatedResponse, Pagination,
+        PostDetailResponse, PostIncludes, PostResponse, UserResponse,
+    },
+    utils::AppError,
+};
+
+/// Embeds the author, category and a page of comments in a post, one query per included resource.
+#[derive(Clone)]
+pub struct PostDetailBuilder {
+    user_repository: DynUserRepository,
+    category_repository: DynCategoryRepository,
+    comment_repository: DynCommentRepository,
+    reaction_repository: DynReactionRepository,
+}
+
+impl PostDetailBuilder {
+    pub fn new(
+        user_repository: DynUserRepository,
+        category_repository: DynCategoryRepository,
+        comment_repository: DynCommentRepository,
+        reaction_repository: DynReactionRepository,
+    ) -> Self {
+        Self { user_repository, category_repository, comment_repository, reaction_repository }
+    }
+
+    pub async fn build(
+        &self,
+        post: PostResponse,
+        includes: &PostIncludes,
+        comments: &Pagination,
+        viewer: Option<i32>,
+    ) -> Result<PostDetailResponse, ErrorResponse> {
+        let author = if includes.author {
+            self.user_repository.find_by_id(post.user_id)
+                .await
+                .map_err(AppError::from).map_err(ErrorResponse::from)?
+                .map(UserResponse::from)
+        } else {
+            None
+        };
+
+        let category = if includes.category {
+            self.category_repository.find_by_id(post.category_id)
+                .await
+                .map_err(AppError::from).map_err(ErrorResponse::from)?
+                .map(CategoryResponse::from)
+        } else {
+            None
+        };
+
+        let comments = if includes.comments {
+            let (items, total) = self.comment_repository.find_by_post(post.id, comments)
+                .await
+                .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+            let comment_ids: Vec<i32> = items.iter().map(|comment| comment.id).collect();
+            let reactions = self.reaction_repository.find_for_comments(&comment_ids, viewer)
+                .await
+                .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+            let items = items.into_iter()
+                .zip(reactions)
+                .map(|(comment, reactions)| CommentResponse {
+                    reactions,
+                    ..CommentResponse::from(comment)
+                })
+                .collect();
+
+            Some(PaginatedResponse::new(items, comments, total))
+        } else {
+            None
+        };
+
+        Ok(PostDetailResponse { post, author, category, comments, translations: Vec::new() })
+    }
+}
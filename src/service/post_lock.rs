@@ -0,0 +1,162 @@
+use async_trait::async_trait;
+
+use crate::{
+    abstract_trait::{DynPostLockRepository, DynPostsRepository, PostLockServiceTrait},
+    config::PostLockConfig,
+    domain::{ApiResponse, ErrorResponse, PostLockResponse},
+    entities::{post_locks, posts, sea_orm_active_enums::UserRole},
+    utils::AppError,
+};
+
+use super::{PostAccess, Viewer};
+
+pub struct PostLockService {
+    repository: DynPostLockRepository,
+    post_repository: DynPostsRepository,
+    access: PostAccess,
+    config: PostLockConfig,
+}
+
+impl PostLockService {
+    pub fn new(
+        repository: DynPostLockRepository,
+        post_repository: DynPostsRepository,
+        access: PostAccess,
+        config: PostLockConfig,
+    ) -> Self {
+        Self { repository, post_repository, access, config }
+    }
+
+    async fn find_post(&self, post_id: i32) -> Result<posts::Model, ErrorResponse> {
+        self.post_repository.get_post(post_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .ok_or_else(|| ErrorResponse::from(AppError::NotFound(format!("Posts with id {} not found", post_id))))
+    }
+
+    /// Only the author of a post and editors may lock it for editing.
+    async fn ensure_editor_of(&self, post_id: i32, user_id: i32) -> Result<Viewer, ErrorResponse> {
+        let post = self.find_post(post_id).await?;
+
+        let viewer = self.access.viewer(Some(user_id)).await?;
+        if !viewer.owns(&post) && !viewer.is_editor() {
+            return Err(ErrorResponse::from(AppError::Forbidden("Only the author or an editor can lock this post".to_string())));
+        }
+
+        Ok(viewer)
+    }
+}
+
+/// Why a save or lock of a post that someone else is editing was refused.
+pub(crate) fn locked_message(post_id: i32, lock: Option<&post_locks::Model>) -> String {
+    match lock {
+        Some(lock) => format!(
+            "Post {} is being edited by user {} until {}",
+            post_id,
+            lock.user_id,
+            lock.expires_at.to_rfc3339()
+        ),
+        None => format!("Post {} is being edited by another user", post_id),
+    }
+}
+
+/// The error returned to users saving or locking a post that someone else is editing.
+pub(crate) fn locked_error(post_id: i32, lock: Option<&post_locks::Model>) -> AppError {
+    AppError::Locked(locked_message(post_id, lock))
+}
+
+#[async_trait]
+impl PostLockServiceTrait for PostLockService {
+    async fn get_lock(&self, post_id: i32, user_id: i32, token: Option<&str>) -> Result<ApiResponse<Option<PostLockResponse>>, ErrorResponse> {
+        let post = self.find_post(post_id).await?;
+        self.access.check(&self.access.viewer(Some(user_id)).await?, &post, token)?;
+
+        let lock = self.repository.find_lock(post_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Edit lock retrieved successfully".to_string(),
+            data: lock.map(PostLockResponse::from),
+        })
+    }
+
+    async fn acquire(&self, post_id: i32, user_id: i32) -> Result<ApiResponse<PostLockResponse>, ErrorResponse> {
+        self.ensure_editor_of(post_id, user_id).await?;
+
+        let lock = self.repository.acquire_lock(post_id, user_id, self.config.ttl, false)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let lock = match lock {
+            Some(lock) => lock,
+            None => {
+                let holder = self.repository.find_lock(post_id)
+                    .await
+                    .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+                return Err(ErrorResponse::from(locked_error(post_id, holder.as_ref())));
+            }
+        };
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Edit lock acquired".to_string(),
+            data: PostLockResponse::from(lock),
+        })
+    }
+
+    async fn heartbeat(&self, post_id: i32, user_id: i32) -> Result<ApiResponse<PostLockResponse>, ErrorResponse> {
+        self.ensure_editor_of(post_id, user_id).await?;
+
+        let lock = self.repository.refresh_lock(post_id, user_id, self.config.ttl)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .ok_or_else(|| ErrorResponse::from(AppError::Conflict(
+                "You do not hold the edit lock on this post, acquire it again".to_string(),
+            )))?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Edit lock renewed".to_string(),
+            data: PostLockResponse::from(lock),
+        })
+    }
+
+    async fn release(&self, post_id: i32, user_id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
+        let released = self.repository.release_lock(post_id, user_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        if !released {
+            return Err(ErrorResponse::from(AppError::Conflict(
+                "You do not hold the edit lock on this post".to_string(),
+            )));
+        }
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Edit lock released".to_string(),
+            data: (),
+        })
+    }
+
+    async fn steal(&self, post_id: i32, user_id: i32) -> Result<ApiResponse<PostLockResponse>, ErrorResponse> {
+        if self.access.viewer(Some(user_id)).await?.role != UserRole::Admin {
+            return Err(ErrorResponse::from(AppError::Forbidden("Only admins can take over edit locks".to_string())));
+        }
+        self.find_post(post_id).await?;
+
+        let lock = self.repository.acquire_lock(post_id, user_id, self.config.ttl, true)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .ok_or_else(|| ErrorResponse::from(AppError::NotFound(format!("Posts with id {} not found", post_id))))?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Edit lock taken over".to_string(),
+            data: PostLockResponse::from(lock),
+        })
+    }
+}
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use crate::{
+    abstract_trait::{DynBookmarkRepository, DynPostsRepository, DynReactionRepository, DynSeriesRepository},
+    domain::{ErrorResponse, PostResponse, SeriesNavigationResponse},
+    entities::posts,
+    utils::AppError,
+};
+
+use super::PostAccess;
+
+/// Builds `PostResponse`s, loading tags, reactions, bookmarks and series for all posts in batched queries.
+#[derive(Clone)]
+pub struct PostResponseBuilder {
+    repository: DynPostsRepository,
+    reaction_repository: DynReactionRepository,
+    bookmark_repository: DynBookmarkRepository,
+    series_repository: DynSeriesRepository,
+    access: PostAccess,
+}
+
+impl PostResponseBuilder {
+    pub fn new(
+        repository: DynPostsRepository,
+        reaction_repository: DynReactionRepository,
+        bookmark_repository: DynBookmarkRepository,
+        series_repository: DynSeriesRepository,
+        access: PostAccess,
+    ) -> Self {
+        Self { repository, reaction_repository, bookmark_repository, series_repository, access }
+    }
+
+    /// `viewer` is the authenticated user, used to flag their own reactions and bookmarks.
+    pub async fn build(&self, posts: Vec<posts::Model>, viewer: Option<i32>) -> Result<Vec<PostResponse>, ErrorResponse> {
+        let tags = self.repository.get_post_tags(&posts)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let post_ids: Vec<i32> = posts.iter().map(|post| post.id).collect();
+        let reactions = self.reaction_repository.find_for_posts(&post_ids, viewer)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let bookmarked = match viewer {
+            Some(user_id) => self.bookmark_repository.find_bookmarked(user_id, &post_ids)
+                .await
+                .map_err(AppError::from).map_err(ErrorResponse::from)?,
+            None => Vec::new(),
+        };
+
+        // Series navigation only links to parts the viewer could open from a listing.
+        let scope = self.access.scope(&self.access.viewer(viewer).await?);
+        let mut navigation: HashMap<i32, SeriesNavigationResponse> = self.series_repository.find_for_posts(&post_ids, &scope)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .iter()
+            .flat_map(|(series, parts)| SeriesNavigationResponse::for_parts(series, parts))
+            .collect();
+
+        Ok(posts.into_iter()
+            .zip(tags)
+            .zip(reactions)
+            .map(|((post, tags), reactions)| PostResponse {
+                reactions,
+                bookmarked: bookmarked.contains(&post.id),
+                series: navigation.remove(&post.id),
+                ..PostResponse::from_post_and_tags(post, tags)
+            })
+            .collect())
+    }
+
+    pub async fn build_one(&self, post: posts::Model, viewer: Option<i32>) -> Result<PostResponse, ErrorResponse> {
+        let mut responses = self.build(vec![post], viewer).await?;
+
+        Ok(responses.remove(0))
+    }
+}
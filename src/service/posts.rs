@@ -1,25 +1,109 @@
-use crate::{abstract_trait::{DynPostsRepository, PostsServiceTrait}, domain::{ApiResponse, CreatePostRequest, ErrorResponse, PostRelationResponse, PostResponse, UpdatePostRequest}, utils::AppError};
+use crate::{abstract_trait::{DynCategoryRepository, DynMediaRepository, DynPostsRepository, DynStorage, PostsServiceTrait}, domain::{changed, ApiResponse, ArchivePeriod, ArchiveYearResponse, BulkPostOperation, BulkPostOutcome, BulkPostRequest, BulkPostResponse, BulkPostResult, CreatePostRequest, CreateTranslationRequest, ErrorResponse, FeaturePostRequest, FeaturedPostResponse, PatchDocument, PatchPostRequest, PaginatedResponse, Pagination, PostDetailResponse, PostDocument, PostFields, PostIncludes, PostQuery, PostResponse, PostTransitionResponse, PostTranslationResponse, PostWrite, ReviewAction, ReviewRequest, UnlockPostRequest, UnlockPostResponse, UpdatePostRequest, VisibilityScope}, entities::{posts, sea_orm_active_enums::{PostStatus, PostVisibility}}, utils::{negotiate_locale, normalize_locale, validate_metadata, AppError, DEFAULT_LOCALE}};
 use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::ActiveEnum;
+use serde_json::{json, Value};
+
+use super::{post_lock::{locked_error, locked_message}, PostAccess, PostDetailBuilder, PostResponseBuilder, Viewer};
 
 pub struct PostService {
     repository: DynPostsRepository,
+    category_repository: DynCategoryRepository,
+    media_repository: DynMediaRepository,
+    storage: DynStorage,
+    responses: PostResponseBuilder,
+    details: PostDetailBuilder,
+    access: PostAccess,
 }
 
 impl PostService {
-    pub fn new(repository: DynPostsRepository) -> Self {
-        Self { repository }
+    pub fn new(repository: DynPostsRepository, category_repository: DynCategoryRepository, media_repository: DynMediaRepository, storage: DynStorage, responses: PostResponseBuilder, details: PostDetailBuilder, access: PostAccess) -> Self {
+        Self { repository, category_repository, media_repository, storage, responses, details, access }
+    }
+
+    async fn find_post(&self, post_id: i32) -> Result<posts::Model, ErrorResponse> {
+        self.repository.get_post(post_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .ok_or_else(|| ErrorResponse::from(AppError::NotFound(format!("Posts with id {} not found", post_id))))
+    }
+
+    /// Checks post metadata against the schema of the category the post is saved in.
+    async fn validate_metadata(&self, category_id: i32, metadata: &Value) -> Result<(), ErrorResponse> {
+        let category = self.category_repository.find_by_id(category_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .ok_or_else(|| ErrorResponse::from(AppError::ValidationError(format!("Category with id {} not found", category_id))))?;
+
+        validate_metadata(category.metadata_schema.as_ref(), metadata).map_err(ErrorResponse::from)
+    }
+
+    /// Fails when another live post of the translation group is already in `locale`.
+    async fn ensure_locale_free(&self, post: &posts::Model, locale: &str) -> Result<(), ErrorResponse> {
+        let group = match post.translation_group_id {
+            Some(group_id) => self.repository.get_translations(group_id, &VisibilityScope::unrestricted())
+                .await
+                .map_err(AppError::from).map_err(ErrorResponse::from)?,
+            None => vec![post.clone()],
+        };
+
+        if group.iter().any(|other| other.locale == locale) {
+            return Err(ErrorResponse::from(AppError::Conflict(format!("A {} translation of this post already exists", locale))));
+        }
+
+        Ok(())
+    }
+
+    /// Resolves the cover image of a post from either an uploaded media ID or a URL.
+    async fn resolve_image(&self, media_id: Option<i32>, img: &str) -> Result<String, ErrorResponse> {
+        if let Some(media_id) = media_id {
+            let media = self.media_repository.find_by_id(media_id)
+                .await
+                .map_err(AppError::from).map_err(ErrorResponse::from)?
+                .ok_or_else(|| ErrorResponse::from(AppError::ValidationError(format!("Media with id {} not found", media_id))))?;
+
+            return Ok(self.storage.url(&media.key));
+        }
+
+        let img = img.trim();
+        let is_url = img.is_empty()
+            || img.starts_with("http://")
+            || img.starts_with("https://")
+            || img.starts_with('/');
+
+        if !is_url {
+            return Err(ErrorResponse::from(AppError::ValidationError("img must be an http(s) URL".to_string())));
+        }
+
+        Ok(img.to_string())
     }
 }
 
 #[async_trait]
 impl PostsServiceTrait for PostService {
-    async fn get_all_posts(&self) -> Result<ApiResponse<Vec<PostResponse>>, ErrorResponse> {
-        let posts = self.repository.get_all_posts()
+    async fn get_all_posts(&self, query: &PostQuery, fields: &PostFields, viewer: Option<i32>) -> Result<ApiResponse<Vec<PostResponse>>, ErrorResponse> {
+        let scope = self.access.scope(&self.access.viewer(viewer).await?);
+
+        let locale = match &query.locale {
+            Some(locale) => Some(normalize_locale(locale).map_err(ErrorResponse::from)?),
+            None if query.accepted_locales.is_empty() => None,
+            None => {
+                let available = self.repository.get_locales()
+                    .await
+                    .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+                negotiate_locale(&query.accepted_locales, &available)
+            }
+        };
+        let query = PostQuery { locale, ..query.clone() };
+
+        let posts = self.repository.get_all_posts(&query, &scope)
             .await
             .map_err(AppError::from).map_err(ErrorResponse::from)?;
 
-        let responses = posts.into_iter()
-            .map(|post| PostResponse::from(post))
+        let responses = self.responses.build(posts, viewer).await?
+            .into_iter()
+            .map(|response| response.with_fields(fields))
             .collect();
 
         Ok(ApiResponse {
@@ -29,7 +113,109 @@ impl PostsServiceTrait for PostService {
         })
     }
 
-    async fn get_post(&self, post_id: i32) -> Result<Option<ApiResponse<PostResponse>>, ErrorResponse> {
+    async fn get_featured_posts(&self, viewer: Option<i32>) -> Result<ApiResponse<Vec<FeaturedPostResponse>>, ErrorResponse> {
+        let scope = self.access.scope(&self.access.viewer(viewer).await?);
+
+        let (featured, posts): (Vec<_>, Vec<_>) = self.repository.get_featured_posts(&scope)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .into_iter()
+            .unzip();
+
+        let posts = self.responses.build(posts, viewer).await?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Featured posts retrieved successfully".to_string(),
+            data: featured.into_iter().zip(posts).map(|(featured, post)| FeaturedPostResponse::new(featured, post)).collect(),
+        })
+    }
+
+    async fn get_archive(&self, viewer: Option<i32>) -> Result<ApiResponse<Vec<ArchiveYearResponse>>, ErrorResponse> {
+        let scope = self.access.scope(&self.access.viewer(viewer).await?);
+
+        let months = self.repository.get_archive(&scope)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Archive retrieved successfully".to_string(),
+            data: ArchiveYearResponse::group(months),
+        })
+    }
+
+    async fn get_archive_posts(&self, period: &ArchivePeriod, pagination: &Pagination, viewer: Option<i32>) -> Result<ApiResponse<PaginatedResponse<PostResponse>>, ErrorResponse> {
+        let (start, end) = period.range()?;
+        let scope = self.access.scope(&self.access.viewer(viewer).await?);
+
+        let (posts, total) = self.repository.get_archive_posts(start, end, &scope, pagination)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let items = self.responses.build(posts, viewer).await?
+            .into_iter()
+            .map(|post| post.with_fields(&PostFields::default()))
+            .collect();
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Archive posts retrieved successfully".to_string(),
+            data: PaginatedResponse::new(items, pagination, total),
+        })
+    }
+
+    async fn feature_post(&self, post_id: i32, input: &FeaturePostRequest, user_id: i32) -> Result<ApiResponse<FeaturedPostResponse>, ErrorResponse> {
+        self.access.require_editor(user_id, "feature posts").await?;
+        input.validate(Utc::now().into())?;
+
+        let (featured, post) = self.repository.feature_post(post_id, input)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Post featured successfully".to_string(),
+            data: FeaturedPostResponse::new(featured, self.responses.build_one(post, Some(user_id)).await?),
+        })
+    }
+
+    async fn unfeature_post(&self, post_id: i32, user_id: i32) -> Result<Option<ApiResponse<()>>, ErrorResponse> {
+        self.access.require_editor(user_id, "feature posts").await?;
+
+        let removed = self.repository.unfeature_post(post_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(removed.then(|| ApiResponse {
+            status: "success".to_string(),
+            message: "Post unfeatured successfully".to_string(),
+            data: (),
+        }))
+    }
+
+    async fn pin_post(&self, post_id: i32, pinned: bool, user_id: i32) -> Result<ApiResponse<PostResponse>, ErrorResponse> {
+        self.access.require_editor(user_id, "pin posts").await?;
+
+        let post = self.repository.set_pinned(post_id, pinned)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: if pinned { "Post pinned successfully" } else { "Post unpinned successfully" }.to_string(),
+            data: self.responses.build_one(post, Some(user_id)).await?,
+        })
+    }
+
+    async fn get_post(
+        &self,
+        post_id: i32,
+        includes: &PostIncludes,
+        comments: &Pagination,
+        viewer: Option<i32>,
+        token: Option<&str>
+    ) -> Result<Option<ApiResponse<PostDetailResponse>>, ErrorResponse> {
         let post = self.repository.get_post(post_id)
             .await
             .map_err(AppError::from).map_err(ErrorResponse::from)?;
@@ -37,65 +223,259 @@ impl PostsServiceTrait for PostService {
         
         
         if let Some(post) = post{
+            let access = self.access.viewer(viewer).await?;
+            self.access.check(&access, &post, token)?;
+
+            let translations = match post.translation_group_id {
+                Some(group_id) => self.repository.get_translations(group_id, &self.access.scope(&access))
+                    .await
+                    .map_err(AppError::from).map_err(ErrorResponse::from)?
+                    .into_iter()
+                    .filter(|translation| translation.id != post.id)
+                    .map(PostTranslationResponse::from)
+                    .collect(),
+                None => Vec::new(),
+            };
+
+            let mut detail = self.details.build(self.responses.build_one(post, viewer).await?, includes, comments, viewer).await?;
+            detail.translations = translations;
+
             Ok(Some(ApiResponse{
                 status: "success".to_string(),
                 message: "Post retrieved successfully".to_string(),
-                data: PostResponse::from(post),
+                data: detail,
             }))
         }else{
             Err(ErrorResponse::from(AppError::NotFound(format!("Posts with id {} not found", post_id))))
         }
     }
 
-    async fn get_post_relation(&self, post_id: i32) -> Result<ApiResponse<PostRelationResponse>, ErrorResponse> {
-        let relations = self.repository.get_post_relation(post_id)
-            .await
-            .map_err(AppError::from).map_err(ErrorResponse::from)?;
-
-        let first_relation = relations.into_iter()
-            .next()
-            .ok_or_else(|| AppError::NotFound("Post relation not found".to_string()))?;
+    async fn unlock_post(&self, post_id: i32, input: &UnlockPostRequest, viewer: Option<i32>) -> Result<ApiResponse<UnlockPostResponse>, ErrorResponse> {
+        let post = self.find_post(post_id).await?;
+        let viewer = self.access.viewer(viewer).await?;
 
         Ok(ApiResponse {
             status: "success".to_string(),
-            message: "Post relation retrieved successfully".to_string(),
-            data: first_relation,
+            message: "Post unlocked successfully".to_string(),
+            data: self.access.unlock(&viewer, &post, &input.password).await?,
         })
     }
 
     async fn create_post(
         &self,
-        input: &CreatePostRequest
+        input: &CreatePostRequest,
+        user_id: i32
     ) -> Result<ApiResponse<PostResponse>, ErrorResponse> {
-        let post = self.repository.create_post(input)
+        if input.status == PostStatus::Published {
+            self.access.require_editor(user_id, "publish posts, submit the post for review instead").await?;
+        }
+
+        let metadata = input.metadata.clone().unwrap_or_else(|| json!({}));
+        self.validate_metadata(input.category_id, &metadata).await?;
+
+        let input = CreatePostRequest {
+            user_id,
+            img: self.resolve_image(input.media_id, &input.img).await?,
+            password_hash: self.access.password_hash(input.visibility, input.password.as_deref(), None).await?,
+            metadata: Some(metadata),
+            locale: Some(normalize_locale(input.locale.as_deref().unwrap_or(DEFAULT_LOCALE)).map_err(ErrorResponse::from)?),
+            ..input.clone()
+        };
+
+        let post = self.repository.create_post(&input)
             .await
             .map_err(AppError::from).map_err(ErrorResponse::from)?;
 
         Ok(ApiResponse {
             status: "success".to_string(),
             message: "Post created successfully".to_string(),
-            data: PostResponse::from(post)
+            data: self.responses.build_one(post, None).await?
+        })
+    }
+
+    async fn create_translation(&self, post_id: i32, input: &CreateTranslationRequest, user_id: i32) -> Result<ApiResponse<PostResponse>, ErrorResponse> {
+        self.access.require_editor(user_id, "create translations").await?;
+
+        let locale = normalize_locale(&input.locale).map_err(ErrorResponse::from)?;
+        let source = self.find_post(post_id).await?;
+
+        let title = match &input.title {
+            Some(title) if title.trim().is_empty() => {
+                return Err(ErrorResponse::from(AppError::ValidationError("title must not be empty".to_string())));
+            }
+            Some(title) => title.clone(),
+            None => source.title.clone(),
+        };
+
+        let tags = self.repository.get_post_tags(std::slice::from_ref(&source))
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .remove(0);
+
+        // The author and settings carry over; the translator replaces the copied body. The password
+        // does not: a translation of a password-protected post stays private until it gets its own.
+        let visibility = match source.visibility {
+            PostVisibility::Password => PostVisibility::Private,
+            visibility => visibility,
+        };
+
+        let translation = CreatePostRequest {
+            title,
+            body: source.body.clone(),
+            body_format: source.body_format,
+            img: source.img.clone(),
+            media_id: None,
+            category_id: source.category_id,
+            user_id: source.user_id,
+            user_name: source.user_name.clone(),
+            tags: tags.into_iter().map(|tag| tag.name).collect(),
+            status: PostStatus::Draft,
+            visibility,
+            password: None,
+            password_hash: None,
+            metadata: Some(source.metadata.clone()),
+            locale: Some(locale.clone()),
+        };
+
+        let post = self.repository.create_translation(source.id, &translation)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .ok_or_else(|| ErrorResponse::from(AppError::Conflict(format!("A {} translation of this post already exists", locale))))?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Translation created successfully".to_string(),
+            data: self.responses.build_one(post, Some(user_id)).await?,
         })
     }
 
     async fn update_post(
         &self,
-        input: &UpdatePostRequest
+        input: &UpdatePostRequest,
+        viewer: Option<i32>,
+        token: Option<&str>
     ) -> Result<ApiResponse<PostResponse>, ErrorResponse> {
-        let post = self.repository.update_post(input)
+        let post_id = input.post_id
+            .ok_or_else(|| ErrorResponse::from(AppError::ValidationError("Post ID is required".to_string())))?;
+        let current = self.find_post(post_id).await?;
+        let access = self.access.viewer(viewer).await?;
+        self.access.check_write(&access, &current, token)?;
+        if let Some(user_id) = input.user_id {
+            check_author(&access, &current, user_id)?;
+        }
+        let visibility = input.visibility.unwrap_or(current.visibility);
+
+        // A status change is checked against the status just read.
+        let version = match input.status.filter(|status| *status != current.status) {
+            Some(status) => {
+                check_transition(&access, &current, status)?;
+                Some(input.version.unwrap_or(current.version))
+            }
+            None => input.version,
+        };
+        self.validate_metadata(input.category_id, input.metadata.as_ref().unwrap_or(&current.metadata)).await?;
+
+        let locale = match &input.locale {
+            Some(locale) => Some(normalize_locale(locale).map_err(ErrorResponse::from)?),
+            None => None,
+        };
+        if let Some(locale) = locale.as_deref().filter(|locale| *locale != current.locale) {
+            self.ensure_locale_free(&current, locale).await?;
+        }
+
+        let input = UpdatePostRequest {
+            locale,
+            version,
+            actor_id: viewer,
+            img: self.resolve_image(input.media_id, &input.img).await?,
+            password_hash: self.access.password_hash(visibility, input.password.as_deref(), current.password_hash.as_deref()).await?,
+            ..input.clone()
+        };
+
+        let post = self.repository.update_post(&input)
             .await.map_err(AppError::from).map_err(ErrorResponse::from)?;
+        let post = saved(post_id, post)?;
 
         Ok(ApiResponse {
             status: "success".to_string(),
             message: "Post updated successfully".to_string(),
-            data: PostResponse::from(post),
+            data: self.responses.build_one(post, viewer).await?,
         })
     }
 
-    async fn delete_post(&self, post_id: i32) -> Result<ApiResponse<()>, ErrorResponse> {
-        self.repository.delete_post(post_id)
+    async fn patch_post(
+        &self,
+        post_id: i32,
+        patch: &PatchDocument,
+        expected_version: Option<i32>,
+        viewer: Option<i32>,
+        token: Option<&str>
+    ) -> Result<ApiResponse<PostResponse>, ErrorResponse> {
+        let post = self.find_post(post_id).await?;
+        let access = self.access.viewer(viewer).await?;
+        self.access.check_write(&access, &post, token)?;
+
+        let tags = self.repository.get_post_tags(std::slice::from_ref(&post))
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .remove(0);
+
+        let current = PostDocument::from_post_and_tags(&post, tags);
+        let patched = patch.apply(&current)?;
+        patched.validate()?;
+        self.validate_metadata(patched.category_id, &patched.metadata).await?;
+        check_transition(&access, &post, patched.status)?;
+        check_author(&access, &post, patched.user_id)?;
+
+        let locale = normalize_locale(&patched.locale).map_err(ErrorResponse::from)?;
+        if locale != current.locale {
+            self.ensure_locale_free(&post, &locale).await?;
+        }
+
+        let img = self.resolve_image(patched.media_id, &patched.img).await?;
+        let password_hash = self.access
+            .password_hash(patched.visibility, patched.password.as_deref(), post.password_hash.as_deref())
+            .await?;
+
+        let input = PatchPostRequest {
+            post_id,
+            title: changed(&current.title, &patched.title),
+            body: changed(&current.body, &patched.body),
+            body_format: changed(&current.body_format, &patched.body_format),
+            img: changed(&current.img, &img),
+            category_id: changed(&current.category_id, &patched.category_id),
+            user_id: changed(&current.user_id, &patched.user_id),
+            user_name: changed(&current.user_name, &patched.user_name),
+            tags: changed(&current.tags, &patched.tags),
+            status: changed(&current.status, &patched.status),
+            visibility: changed(&current.visibility, &patched.visibility),
+            metadata: changed(&current.metadata, &patched.metadata),
+            locale: changed(&current.locale, &locale),
+            password_hash,
+            // The patch was applied to the version just read.
+            version: Some(expected_version.unwrap_or(post.version)),
+            actor_id: viewer,
+        };
+
+        let post = self.repository.patch_post(&input)
+            .await.map_err(AppError::from).map_err(ErrorResponse::from)?;
+        let post = saved(post_id, post)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Post updated successfully".to_string(),
+            data: self.responses.build_one(post, viewer).await?,
+        })
+    }
+
+    async fn delete_post(&self, post_id: i32, user_id: i32, expected_version: Option<i32>, token: Option<&str>) -> Result<ApiResponse<()>, ErrorResponse> {
+        let post = self.find_post(post_id).await?;
+        self.access.check_write(&self.access.viewer(Some(user_id)).await?, &post, token)?;
+
+        let deleted = self.repository.delete_post(post_id, user_id, expected_version)
             .await
             .map_err(AppError::from).map_err(ErrorResponse::from)?;
+        saved(post_id, deleted)?;
 
         Ok(ApiResponse {
             status: "success".to_string(),
@@ -103,4 +483,157 @@ impl PostsServiceTrait for PostService {
             data: (),
         })
     }
+
+    async fn bulk_update_posts(&self, user_id: i32, input: &BulkPostRequest) -> Result<ApiResponse<BulkPostResponse>, ErrorResponse> {
+        input.validate()?;
+        let viewer = match input.operation {
+            BulkPostOperation::Publish => self.access.require_editor(user_id, "publish posts, submit them for review instead").await?,
+            _ => self.access.viewer(Some(user_id)).await?,
+        };
+
+        let outcomes = self.repository.bulk_update(user_id, viewer.is_editor(), input)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let results = outcomes.into_iter()
+            .map(|(post_id, outcome)| match outcome {
+                BulkPostOutcome::Updated => BulkPostResult::success(post_id),
+                BulkPostOutcome::NotFound => BulkPostResult::failure(post_id, "Post not found"),
+                BulkPostOutcome::Forbidden => BulkPostResult::failure(post_id, "Not allowed to modify this post"),
+                BulkPostOutcome::Locked(lock) => BulkPostResult::failure(post_id, &locked_message(post_id, Some(&lock))),
+                BulkPostOutcome::Invalid(message) => BulkPostResult::failure(post_id, &message),
+            })
+            .collect();
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Bulk operation completed".to_string(),
+            data: BulkPostResponse::new(results),
+        })
+    }
+
+    async fn review_post(&self, post_id: i32, action: ReviewAction, input: &ReviewRequest, user_id: i32) -> Result<ApiResponse<PostResponse>, ErrorResponse> {
+        let (from, to, message) = match action {
+            ReviewAction::Submit => (PostStatus::Draft, PostStatus::PendingReview, "Post submitted for review"),
+            ReviewAction::Approve => (PostStatus::PendingReview, PostStatus::Published, "Post approved and published"),
+            ReviewAction::RequestChanges => (PostStatus::PendingReview, PostStatus::Draft, "Changes requested"),
+        };
+
+        let viewer = match action {
+            ReviewAction::Submit => self.access.viewer(Some(user_id)).await?,
+            ReviewAction::Approve => self.access.require_editor(user_id, "approve posts").await?,
+            ReviewAction::RequestChanges => self.access.require_editor(user_id, "request changes").await?,
+        };
+        let note = input.note(action == ReviewAction::RequestChanges)?;
+
+        let post = self.find_post(post_id).await?;
+        if post.status != from {
+            return Err(ErrorResponse::from(AppError::Conflict(format!(
+                "Post {} is {}, expected {}",
+                post_id,
+                post.status.to_value(),
+                from.to_value()
+            ))));
+        }
+        check_transition(&viewer, &post, to)?;
+
+        let post = self.repository.transition_post(post_id, from, to, user_id, note)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: message.to_string(),
+            data: self.responses.build_one(post, Some(user_id)).await?,
+        })
+    }
+
+    async fn get_review_queue(&self, pagination: &Pagination, user_id: i32) -> Result<ApiResponse<PaginatedResponse<PostResponse>>, ErrorResponse> {
+        self.access.require_editor(user_id, "review posts").await?;
+
+        let (posts, total) = self.repository.get_review_queue(pagination)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let items = self.responses.build(posts, Some(user_id)).await?
+            .into_iter()
+            .map(|post| post.with_fields(&PostFields::default()))
+            .collect();
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Review queue retrieved successfully".to_string(),
+            data: PaginatedResponse::new(items, pagination, total),
+        })
+    }
+
+    async fn get_transitions(&self, post_id: i32, user_id: i32) -> Result<ApiResponse<Vec<PostTransitionResponse>>, ErrorResponse> {
+        let viewer = self.access.viewer(Some(user_id)).await?;
+        let post = self.find_post(post_id).await?;
+
+        if !viewer.is_editor() && !viewer.owns(&post) {
+            return Err(ErrorResponse::from(AppError::Forbidden(
+                "Only the author and editors can view the review history".to_string(),
+            )));
+        }
+
+        let transitions = self.repository.get_transitions(post_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Post transitions retrieved successfully".to_string(),
+            data: transitions.into_iter().map(PostTransitionResponse::from).collect(),
+        })
+    }
+}
+
+/// Saves refused because another user holds the edit lock fail with `423 Locked`.
+fn saved<T>(post_id: i32, write: PostWrite<T>) -> Result<T, ErrorResponse> {
+    match write {
+        PostWrite::Saved(value) => Ok(value),
+        PostWrite::Locked(lock) => Err(ErrorResponse::from(locked_error(post_id, Some(&lock)))),
+    }
+}
+
+/// Only editors may hand a post over to another author.
+fn check_author(viewer: &Viewer, post: &posts::Model, user_id: i32) -> Result<(), ErrorResponse> {
+    if user_id != post.user_id && !viewer.is_editor() {
+        return Err(ErrorResponse::from(AppError::Forbidden(
+            "Only editors can change the author of a post".to_string(),
+        )));
+    }
+
+    Ok(())
+}
+
+/// The review workflow: authors submit their drafts for review, withdraw them and unpublish
+/// their posts, while only editors publish, directly or by approving a submitted post.
+fn check_transition(viewer: &Viewer, post: &posts::Model, to: PostStatus) -> Result<(), ErrorResponse> {
+    let allowed = match (post.status, to) {
+        (from, to) if from == to => return Ok(()),
+        (PostStatus::Draft, PostStatus::PendingReview)
+        | (PostStatus::PendingReview, PostStatus::Draft)
+        | (PostStatus::Published, PostStatus::Draft) => viewer.owns(post) || viewer.is_editor(),
+        (PostStatus::Draft | PostStatus::PendingReview, PostStatus::Published) => viewer.is_editor(),
+        (from, to) => {
+            return Err(ErrorResponse::from(AppError::Conflict(format!(
+                "A {} post cannot be moved to {}",
+                from.to_value(),
+                to.to_value()
+            ))));
+        }
+    };
+
+    if allowed {
+        return Ok(());
+    }
+
+    let message = match to {
+        PostStatus::Published => "Only editors can publish posts, submit the post for review instead",
+        _ => "Not allowed to change the status of this post",
+    };
+
+    Err(ErrorResponse::from(AppError::Forbidden(message.to_string())))
 }
@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+
+use crate::{
+    abstract_trait::{DynPostsRepository, DynRankingRepository, RankingServiceTrait},
+    config::RankingConfig,
+    domain::{ApiResponse, ErrorResponse, PaginatedResponse, Pagination, PostFields, RankedPostResponse},
+    utils::AppError,
+};
+
+use super::{PostAccess, PostResponseBuilder};
+
+pub struct RankingService {
+    repository: DynRankingRepository,
+    post_repository: DynPostsRepository,
+    responses: PostResponseBuilder,
+    access: PostAccess,
+    config: RankingConfig,
+}
+
+impl RankingService {
+    pub fn new(
+        repository: DynRankingRepository,
+        post_repository: DynPostsRepository,
+        responses: PostResponseBuilder,
+        access: PostAccess,
+        config: RankingConfig,
+    ) -> Self {
+        Self { repository, post_repository, responses, access, config }
+    }
+}
+
+#[async_trait]
+impl RankingServiceTrait for RankingService {
+    async fn refresh(&self) -> Result<(u64, u64), ErrorResponse> {
+        let trending = self.repository.refresh_trending(self.config.trending_window, self.config.trending_half_life)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let related = self.repository.refresh_related(self.config.related_limit)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok((trending, related))
+    }
+
+    async fn get_trending(&self, pagination: &Pagination, viewer: Option<i32>) -> Result<ApiResponse<PaginatedResponse<RankedPostResponse>>, ErrorResponse> {
+        let scope = self.access.scope(&self.access.viewer(viewer).await?);
+
+        let (trending, total) = self.repository.find_trending(&scope, pagination)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let (scores, posts): (Vec<_>, Vec<_>) = trending.into_iter()
+            .map(|(trending, post)| (trending.score, post))
+            .unzip();
+
+        let items = self.responses.build(posts, viewer).await?
+            .into_iter()
+            .zip(scores)
+            .map(|(post, score)| RankedPostResponse { post: post.with_fields(&PostFields::default()), score })
+            .collect();
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Trending posts retrieved successfully".to_string(),
+            data: PaginatedResponse::new(items, pagination, total),
+        })
+    }
+
+    async fn get_related(&self, post_id: i32, viewer: Option<i32>, token: Option<&str>) -> Result<ApiResponse<Vec<RankedPostResponse>>, ErrorResponse> {
+        let post = self.post_repository.get_post(post_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .ok_or_else(|| ErrorResponse::from(AppError::NotFound(format!("Posts with id {} not found", post_id))))?;
+
+        let access = self.access.viewer(viewer).await?;
+        self.access.check(&access, &post, token)?;
+
+        let (scores, posts): (Vec<_>, Vec<_>) = self.repository.find_related(post_id, &self.access.scope(&access))
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .into_iter()
+            .map(|(related, post)| (related.score, post))
+            .unzip();
+
+        let related = self.responses.build(posts, viewer).await?
+            .into_iter()
+            .zip(scores)
+            .map(|(post, score)| RankedPostResponse { post: post.with_fields(&PostFields::default()), score })
+            .collect();
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Related posts retrieved successfully".to_string(),
+            data: related,
+        })
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{
+    abstract_trait::{DynCommentRepository, DynPostsRepository, DynReactionRepository, ReactionServiceTrait},
+    config::ReactionConfig,
+    domain::{ApiResponse, ErrorResponse, ReactionResponse},
+    utils::AppError,
+};
+use async_trait::async_trait;
+
+use super::PostAccess;
+
+pub struct ReactionService {
+    repository: DynReactionRepository,
+    post_repository: DynPostsRepository,
+    comment_repository: DynCommentRepository,
+    access: PostAccess,
+    config: ReactionConfig,
+}
+
+impl ReactionService {
+    pub fn new(
+        repository: DynReactionRepository,
+        post_repository: DynPostsRepository,
+        comment_repository: DynCommentRepository,
+        access: PostAccess,
+        config: ReactionConfig,
+    ) -> Self {
+        Self { repository, post_repository, comment_repository, access, config }
+    }
+
+    /// Fails unless the user can read the post; `token` unlocks a password-protected post.
+    async fn check_post(&self, post_id: i32, user_id: i32, token: Option<&str>) -> Result<(), ErrorResponse> {
+        let post = self.post_repository.get_post(post_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .ok_or_else(|| ErrorResponse::from(AppError::NotFound(format!("Posts with id {} not found", post_id))))?;
+
+        self.access.check(&self.access.viewer(Some(user_id)).await?, &post, token)
+    }
+
+    fn check_kind(&self, kind: &str) -> Result<(), ErrorResponse> {
+        if !self.config.is_allowed(kind) {
+            return Err(ErrorResponse::from(AppError::ValidationError(format!(
+                "Unknown reaction {}, expected one of: {}",
+                kind,
+                self.config.kinds.join(", ")
+            ))));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ReactionServiceTrait for ReactionService {
+    async fn toggle_post_reaction(&self, post_id: i32, user_id: i32, kind: &str, token: Option<&str>) -> Result<ApiResponse<Vec<ReactionResponse>>, ErrorResponse> {
+        self.check_kind(kind)?;
+        self.check_post(post_id, user_id, token).await?;
+
+        let added = self.repository.toggle_post_reaction(post_id, user_id, kind)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let reactions = self.repository.find_for_posts(&[post_id], Some(user_id))
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .remove(0);
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: if added { "Reaction added" } else { "Reaction removed" }.to_string(),
+            data: reactions,
+        })
+    }
+
+    async fn toggle_comment_reaction(&self, comment_id: i32, user_id: i32, kind: &str, token: Option<&str>) -> Result<ApiResponse<Vec<ReactionResponse>>, ErrorResponse> {
+        self.check_kind(kind)?;
+
+        // Comments can be reacted to exactly when the post they belong to can be read.
+        let comment = self.comment_repository.find_by_id(comment_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .ok_or_else(|| ErrorResponse::from(AppError::NotFound(format!("Comment with id {} not found", comment_id))))?;
+        self.check_post(comment.id_post_comment, user_id, token).await?;
+
+        let added = self.repository.toggle_comment_reaction(comment_id, user_id, kind)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let reactions = self.repository.find_for_comments(&[comment_id], Some(user_id))
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .remove(0);
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: if added { "Reaction added" } else { "Reaction removed" }.to_string(),
+            data: reactions,
+        })
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{
+    abstract_trait::{DynSearchRepository, SearchServiceTrait},
+    domain::{ApiResponse, ErrorResponse, PaginatedResponse, Pagination, SearchHitResponse, SearchQuery},
+    utils::AppError,
+};
+use async_trait::async_trait;
+
+use super::PostAccess;
+
+pub struct SearchService {
+    repository: DynSearchRepository,
+    access: PostAccess,
+}
+
+impl SearchService {
+    pub fn new(repository: DynSearchRepository, access: PostAccess) -> Self {
+        Self { repository, access }
+    }
+}
+
+#[async_trait]
+impl SearchServiceTrait for SearchService {
+    async fn search(
+        &self,
+        query: &SearchQuery,
+        pagination: &Pagination,
+        viewer: Option<i32>,
+    ) -> Result<ApiResponse<PaginatedResponse<SearchHitResponse>>, ErrorResponse> {
+        let viewer = self.access.viewer(viewer).await?;
+        let scope = self.access.scope(&viewer);
+
+        let (hits, total) = match query.to_tsquery() {
+            Some(tsquery) => self.repository.search(&tsquery, query.comments, &scope, pagination)
+                .await
+                .map_err(AppError::from).map_err(ErrorResponse::from)?,
+            None => (Vec::new(), 0),
+        };
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Search completed successfully".to_string(),
+            data: PaginatedResponse::new(hits, pagination, total),
+        })
+    }
+}
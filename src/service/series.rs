@@ -0,0 +1,113 @@
+use crate::{
+    abstract_trait::{DynSeriesRepository, SeriesServiceTrait},
+    domain::{
+        ApiResponse, CreateSeriesRequest, ErrorResponse, PaginatedResponse, Pagination,
+        ReorderSeriesRequest, SeriesResponse,
+    },
+    utils::AppError,
+};
+use async_trait::async_trait;
+
+use super::PostAccess;
+
+pub struct SeriesService {
+    repository: DynSeriesRepository,
+    access: PostAccess,
+}
+
+impl SeriesService {
+    pub fn new(repository: DynSeriesRepository, access: PostAccess) -> Self {
+        Self { repository, access }
+    }
+
+    async fn find_series(&self, id: i32, viewer: Option<i32>) -> Result<Option<SeriesResponse>, ErrorResponse> {
+        let scope = self.access.scope(&self.access.viewer(viewer).await?);
+
+        let series = self.repository.find_series(id, &scope)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(series.map(|(series, parts)| SeriesResponse::new(series, parts)))
+    }
+}
+
+#[async_trait]
+impl SeriesServiceTrait for SeriesService {
+    async fn create_series(&self, user_id: i32, input: &CreateSeriesRequest) -> Result<ApiResponse<SeriesResponse>, ErrorResponse> {
+        input.validate()?;
+
+        let series = self.repository.create_series(user_id, input)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let series = self.find_series(series.id, Some(user_id))
+            .await?
+            .ok_or_else(|| ErrorResponse::from(AppError::NotFound(format!("Series with id {} not found", series.id))))?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Series created successfully".to_string(),
+            data: series,
+        })
+    }
+
+    async fn get_series(&self, id: i32, viewer: Option<i32>) -> Result<Option<ApiResponse<SeriesResponse>>, ErrorResponse> {
+        Ok(self.find_series(id, viewer).await?.map(|series| ApiResponse {
+            status: "success".to_string(),
+            message: "Series retrieved successfully".to_string(),
+            data: series,
+        }))
+    }
+
+    async fn get_all_series(&self, pagination: &Pagination, viewer: Option<i32>) -> Result<ApiResponse<PaginatedResponse<SeriesResponse>>, ErrorResponse> {
+        let scope = self.access.scope(&self.access.viewer(viewer).await?);
+
+        let (series, total) = self.repository.find_all_series(&scope, pagination)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let items = series.into_iter()
+            .map(|(series, parts)| SeriesResponse::new(series, parts))
+            .collect();
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Series retrieved successfully".to_string(),
+            data: PaginatedResponse::new(items, pagination, total),
+        })
+    }
+
+    async fn reorder_series(&self, id: i32, user_id: i32, input: &ReorderSeriesRequest) -> Result<Option<ApiResponse<SeriesResponse>>, ErrorResponse> {
+        input.validate()?;
+
+        let updated = self.repository.set_parts(id, user_id, &input.post_ids)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        if !updated {
+            return Ok(None);
+        }
+
+        Ok(self.find_series(id, Some(user_id)).await?.map(|series| ApiResponse {
+            status: "success".to_string(),
+            message: "Series reordered successfully".to_string(),
+            data: series,
+        }))
+    }
+
+    async fn delete_series(&self, id: i32, user_id: i32) -> Result<Option<ApiResponse<()>>, ErrorResponse> {
+        let deleted = self.repository.delete_series(id, user_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        if !deleted {
+            return Ok(None);
+        }
+
+        Ok(Some(ApiResponse {
+            status: "success".to_string(),
+            message: "Series deleted successfully".to_string(),
+            data: (),
+        }))
+    }
+}
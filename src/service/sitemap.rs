@@ -0,0 +1,154 @@
+use async_trait::async_trait;
+use chrono::{DateTime, SecondsFormat, Utc};
+use tokio::sync::Mutex;
+
+use crate::{
+    abstract_trait::{DynSitemapRepository, SitemapServiceTrait},
+    config::SiteConfig,
+    domain::{ErrorResponse, SitemapEntry, SitemapFingerprint, XmlDocument},
+    utils::AppError,
+};
+
+/// The sitemap protocol's limit on URLs per sitemap file.
+const MAX_URLS_PER_SITEMAP: usize = 50_000;
+const CONTENT_TYPE: &str = "application/xml; charset=utf-8";
+
+struct SitemapCache {
+    fingerprint: SitemapFingerprint,
+    pages: Vec<XmlDocument>,
+    /// Only built when there is more than one page.
+    index: Option<XmlDocument>,
+}
+
+pub struct SitemapService {
+    repository: DynSitemapRepository,
+    config: SiteConfig,
+    cache: Mutex<Option<SitemapCache>>,
+}
+
+impl SitemapService {
+    pub fn new(repository: DynSitemapRepository, config: SiteConfig) -> Self {
+        Self { repository, config, cache: Mutex::new(None) }
+    }
+
+    /// Runs `read` against the cached sitemap, rebuilding it first if the content has changed since.
+    async fn with_cache<T>(&self, read: impl FnOnce(&SitemapCache) -> T) -> Result<T, ErrorResponse> {
+        let fingerprint = self.repository.fingerprint()
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        // Holding the lock while rebuilding keeps concurrent requests from rebuilding it too.
+        let mut cache = self.cache.lock().await;
+
+        if let Some(cache) = cache.as_ref().filter(|cache| cache.fingerprint == fingerprint) {
+            return Ok(read(cache));
+        }
+
+        let rebuilt = self.build(fingerprint).await?;
+        let value = read(&rebuilt);
+        *cache = Some(rebuilt);
+
+        Ok(value)
+    }
+
+    async fn build(&self, fingerprint: SitemapFingerprint) -> Result<SitemapCache, ErrorResponse> {
+        let posts = self.repository.find_posts()
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+        let categories = self.repository.find_categories()
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+        let authors = self.repository.find_authors()
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let urls: Vec<(String, DateTime<Utc>)> = posts.iter()
+            .map(|entry| (self.config.post_url(entry.id), last_modified(entry)))
+            .chain(categories.iter().map(|entry| (self.config.url(&format!("/categories/{}", entry.id)), last_modified(entry))))
+            .chain(authors.iter().map(|entry| (self.config.url(&format!("/authors/{}", entry.id)), last_modified(entry))))
+            .collect();
+
+        let mut pages: Vec<XmlDocument> = urls
+            .chunks(MAX_URLS_PER_SITEMAP)
+            .map(render_urlset)
+            .collect();
+
+        if pages.is_empty() {
+            pages.push(render_urlset(&[]));
+        }
+
+        let index = (pages.len() > 1).then(|| {
+            let sitemaps: Vec<(String, Option<DateTime<Utc>>)> = pages.iter()
+                .enumerate()
+                .map(|(index, page)| (self.config.url(&format!("/sitemaps/{}.xml", index + 1)), page.last_modified))
+                .collect();
+            render_index(&sitemaps)
+        });
+
+        Ok(SitemapCache { fingerprint, pages, index })
+    }
+}
+
+fn last_modified(entry: &SitemapEntry) -> DateTime<Utc> {
+    entry.last_modified.with_timezone(&Utc)
+}
+
+fn w3c_datetime(datetime: DateTime<Utc>) -> String {
+    datetime.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn render_urlset(urls: &[(String, DateTime<Utc>)]) -> XmlDocument {
+    let mut body = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+
+    for (loc, lastmod) in urls {
+        body.push_str(&format!(
+            "  <url><loc>{}</loc><lastmod>{}</lastmod></url>\n",
+            escape_xml(loc),
+            w3c_datetime(*lastmod)
+        ));
+    }
+    body.push_str("</urlset>\n");
+
+    XmlDocument::new(body, CONTENT_TYPE, urls.iter().map(|(_, lastmod)| *lastmod).max())
+}
+
+fn render_index(sitemaps: &[(String, Option<DateTime<Utc>>)]) -> XmlDocument {
+    let mut body = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<sitemapindex xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+
+    for (loc, lastmod) in sitemaps {
+        body.push_str(&format!("  <sitemap><loc>{}</loc>", escape_xml(loc)));
+        if let Some(lastmod) = lastmod {
+            body.push_str(&format!("<lastmod>{}</lastmod>", w3c_datetime(*lastmod)));
+        }
+        body.push_str("</sitemap>\n");
+    }
+    body.push_str("</sitemapindex>\n");
+
+    XmlDocument::new(body, CONTENT_TYPE, sitemaps.iter().filter_map(|(_, lastmod)| *lastmod).max())
+}
+
+#[async_trait]
+impl SitemapServiceTrait for SitemapService {
+    async fn get_sitemap(&self) -> Result<XmlDocument, ErrorResponse> {
+        self.with_cache(|cache| cache.index.clone().unwrap_or_else(|| cache.pages[0].clone()))
+            .await
+    }
+
+    async fn get_sitemap_page(&self, page: usize) -> Result<Option<XmlDocument>, ErrorResponse> {
+        self.with_cache(|cache| page.checked_sub(1).and_then(|index| cache.pages.get(index)).cloned())
+            .await
+    }
+}
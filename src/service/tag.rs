@@ -0,0 +1,66 @@
+use crate::{
+    abstract_trait::{DynPostsRepository, DynTagRepository, TagServiceTrait},
+    domain::{ApiResponse, ErrorResponse, PostQuery, PostResponse, TagResponse},
+    service::{PostAccess, PostResponseBuilder},
+    utils::AppError,
+};
+use async_trait::async_trait;
+
+pub struct TagService {
+    repository: DynTagRepository,
+    post_repository: DynPostsRepository,
+    responses: PostResponseBuilder,
+    access: PostAccess,
+}
+
+impl TagService {
+    pub fn new(repository: DynTagRepository, post_repository: DynPostsRepository, responses: PostResponseBuilder, access: PostAccess) -> Self {
+        Self { repository, post_repository, responses, access }
+    }
+}
+
+#[async_trait]
+impl TagServiceTrait for TagService {
+    async fn get_tags(&self, viewer: Option<i32>) -> Result<ApiResponse<Vec<TagResponse>>, ErrorResponse> {
+        let scope = self.access.scope(&self.access.viewer(viewer).await?);
+        let tags = self.repository.find_all_with_post_count(&scope)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Tags retrieved successfully".to_string(),
+            data: tags,
+        })
+    }
+
+    async fn get_tag_posts(&self, slug: &str, viewer: Option<i32>) -> Result<Option<ApiResponse<Vec<PostResponse>>>, ErrorResponse> {
+        let tag = self.repository.find_by_slug(slug)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let tag = match tag {
+            Some(tag) => tag,
+            None => return Ok(None),
+        };
+
+        let query = PostQuery {
+            tag: Some(tag.slug),
+            ..Default::default()
+        };
+
+        let scope = self.access.scope(&self.access.viewer(viewer).await?);
+
+        let posts = self.post_repository.get_all_posts(&query, &scope)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        let responses = self.responses.build(posts, viewer).await?;
+
+        Ok(Some(ApiResponse {
+            status: "success".to_string(),
+            message: "Posts retrieved successfully".to_string(),
+            data: responses,
+        }))
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    abstract_trait::{DynTrashRepository, TrashServiceTrait},
+    config::TrashConfig,
+    domain::{ApiResponse, ErrorResponse, TrashItemResponse, TrashKind},
+    utils::AppError,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+
+pub struct TrashService {
+    repository: DynTrashRepository,
+    config: TrashConfig,
+}
+
+impl TrashService {
+    pub fn new(repository: DynTrashRepository, config: TrashConfig) -> Self {
+        Self { repository, config }
+    }
+}
+
+#[async_trait]
+impl TrashServiceTrait for TrashService {
+    async fn get_trash(&self, user_id: i32) -> Result<ApiResponse<Vec<TrashItemResponse>>, ErrorResponse> {
+        let items = self.repository.find_deleted_by(user_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Trash retrieved successfully".to_string(),
+            data: items,
+        })
+    }
+
+    async fn restore(&self, kind: TrashKind, id: i32, user_id: i32) -> Result<Option<ApiResponse<()>>, ErrorResponse> {
+        let restored = self.repository.restore(kind, id, user_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        if !restored {
+            return Ok(None);
+        }
+
+        Ok(Some(ApiResponse {
+            status: "success".to_string(),
+            message: "Item restored successfully".to_string(),
+            data: (),
+        }))
+    }
+
+    async fn purge_expired(&self) -> Result<u64, ErrorResponse> {
+        // A retention too long to subtract from the current time keeps everything.
+        let cutoff = chrono::Duration::from_std(self.config.retention)
+            .ok()
+            .and_then(|retention| Utc::now().checked_sub_signed(retention));
+        let cutoff = match cutoff {
+            Some(cutoff) => cutoff,
+            None => return Ok(0),
+        };
+
+        self.repository.purge(cutoff.into())
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     abstract_trait::{DynUserRepository, UserServiceTrait},
-    domain::{ApiResponse, CreateUserRequest, ErrorResponse, UpdateUserRequest, UserResponse},
+    domain::{changed, ApiResponse, CreateUserRequest, ErrorResponse, PatchDocument, UpdateUserRequest, UserDocument, UserResponse},
+    entities::sea_orm_active_enums::UserRole,
     utils::AppError,
 };
 use async_trait::async_trait;
@@ -97,6 +98,50 @@ impl UserServiceTrait for UserService {
         }))
     }
 
+    async fn patch_user(&self, id: i32, patch: &PatchDocument, expected_version: Option<i32>, actor_id: i32) -> Result<ApiResponse<UserResponse>, ErrorResponse> {
+        if actor_id != id {
+            let actor = self.repository.find_by_id(actor_id).await.map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+            if actor.map(|actor| actor.role) != Some(UserRole::Admin) {
+                return Err(ErrorResponse::from(AppError::Forbidden("Only admins can change other users".to_string())));
+            }
+        }
+
+        let user = self.repository.find_by_id(id).await.map_err(AppError::from).map_err(ErrorResponse::from)?
+            .ok_or_else(|| ErrorResponse::from(AppError::NotFound(format!("User with id {} not found", id))))?;
+
+        let current = UserDocument::from(&user);
+        let patched = patch.apply(&current)?;
+        patched.validate()?;
+
+        let email = changed(&current.email, &patched.email);
+
+        if let Some(email) = &email {
+            let exists = self.repository.find_by_email_exists(email).await.map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+            if exists {
+                return Err(ErrorResponse::from(AppError::EmailAlreadyExists));
+            }
+        }
+
+        let user = self.repository.update_user(&UpdateUserRequest {
+            id: Some(id),
+            firstname: changed(&current.firstname, &patched.firstname),
+            lastname: changed(&current.lastname, &patched.lastname),
+            email,
+            password: None,
+            version: Some(expected_version.unwrap_or(user.version)),
+        })
+        .await
+        .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "User updated successfully".to_string(),
+            data: UserResponse::from(user),
+        })
+    }
+
     async fn delete_user(&self, email: &str) -> Result<ApiResponse<()>, ErrorResponse> {
         self.repository.delete_user(email).await.map_err(AppError::from).map_err(ErrorResponse::from)?;
         
@@ -0,0 +1,136 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+    time::Instant,
+};
+
+use crate::{
+    abstract_trait::{DynPostsRepository, DynViewRepository, ViewServiceTrait},
+    config::ViewConfig,
+    domain::{ApiResponse, ErrorResponse, ViewStatsQuery, ViewStatsResponse},
+    utils::AppError,
+};
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+
+use super::PostAccess;
+
+#[derive(Default)]
+struct ViewBuffer {
+    /// When each visitor's last counted view of a post happened.
+    seen: HashMap<(i32, String), Instant>,
+    /// Views not yet written to the database, per post and day.
+    pending: HashMap<(i32, NaiveDate), i64>,
+}
+
+pub struct ViewService {
+    repository: DynViewRepository,
+    post_repository: DynPostsRepository,
+    access: PostAccess,
+    config: ViewConfig,
+    buffer: Mutex<ViewBuffer>,
+}
+
+impl ViewService {
+    pub fn new(repository: DynViewRepository, post_repository: DynPostsRepository, access: PostAccess, config: ViewConfig) -> Self {
+        Self { repository, post_repository, access, config, buffer: Mutex::new(ViewBuffer::default()) }
+    }
+}
+
+fn stats_forbidden() -> ErrorResponse {
+    ErrorResponse::from(AppError::Forbidden("Only the author or an editor can see view statistics".to_string()))
+}
+
+#[async_trait]
+impl ViewServiceTrait for ViewService {
+    fn record_view(&self, post_id: i32, visitor: &str) {
+        let now = Instant::now();
+        let mut buffer = self.buffer.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let key = (post_id, visitor.to_string());
+        if let Some(last) = buffer.seen.get(&key) {
+            if now.duration_since(*last) < self.config.dedupe_window {
+                return;
+            }
+        }
+
+        buffer.seen.insert(key, now);
+        *buffer.pending.entry((post_id, Utc::now().date_naive())).or_default() += 1;
+    }
+
+    async fn flush(&self) -> Result<i64, ErrorResponse> {
+        let pending = {
+            let now = Instant::now();
+            let mut buffer = self.buffer.lock().unwrap_or_else(PoisonError::into_inner);
+
+            let window = self.config.dedupe_window;
+            buffer.seen.retain(|_, last| now.duration_since(*last) < window);
+
+            std::mem::take(&mut buffer.pending)
+        };
+
+        if pending.is_empty() {
+            return Ok(0);
+        }
+
+        let counts: Vec<(i32, NaiveDate, i64)> = pending
+            .into_iter()
+            .map(|((post_id, day), views)| (post_id, day, views))
+            .collect();
+
+        if let Err(e) = self.repository.add_views(&counts).await {
+            // Keep the counts for the next flush instead of losing them.
+            let mut buffer = self.buffer.lock().unwrap_or_else(PoisonError::into_inner);
+            for (post_id, day, views) in counts {
+                *buffer.pending.entry((post_id, day)).or_default() += views;
+            }
+
+            return Err(ErrorResponse::from(AppError::from(e)));
+        }
+
+        Ok(counts.iter().map(|(_, _, views)| views).sum())
+    }
+
+    async fn get_post_views(&self, post_id: i32, query: &ViewStatsQuery, user_id: i32) -> Result<ApiResponse<ViewStatsResponse>, ErrorResponse> {
+        let (from, to) = query.range(Utc::now().date_naive())?;
+
+        let post = self.post_repository.get_post(post_id)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?
+            .ok_or_else(|| ErrorResponse::from(AppError::NotFound(format!("Posts with id {} not found", post_id))))?;
+
+        let viewer = self.access.viewer(Some(user_id)).await?;
+        if !viewer.owns(&post) && !viewer.is_editor() {
+            return Err(stats_forbidden());
+        }
+
+        let days = self.repository.find_by_post(post_id, from, to)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Post views retrieved successfully".to_string(),
+            data: ViewStatsResponse::new(from, to, days),
+        })
+    }
+
+    async fn get_author_views(&self, user_id: i32, query: &ViewStatsQuery, viewer_id: i32) -> Result<ApiResponse<ViewStatsResponse>, ErrorResponse> {
+        let (from, to) = query.range(Utc::now().date_naive())?;
+
+        let viewer = self.access.viewer(Some(viewer_id)).await?;
+        if viewer.user_id != Some(user_id) && !viewer.is_editor() {
+            return Err(stats_forbidden());
+        }
+
+        let days = self.repository.find_by_author(user_id, from, to)
+            .await
+            .map_err(AppError::from).map_err(ErrorResponse::from)?;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Author views retrieved successfully".to_string(),
+            data: ViewStatsResponse::new(from, to, days),
+        })
+    }
+}
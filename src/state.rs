@@ -1,6 +1,6 @@
 use sea_orm::DatabaseConnection;
 
-use crate::{config::{Hashing, JwtConfig}, utils::DependenciesInject};
+use crate::{config::{Hashing, JwtConfig, PostLockConfig, RankingConfig, TrashConfig, ReactionConfig, SiteConfig, UploadConfig, ViewConfig}, utils::DependenciesInject};
 
 #[derive(Clone)]
 pub struct AppState {
@@ -9,11 +9,12 @@ pub struct AppState {
 }
 
 impl AppState {
-    pub fn new(pool: DatabaseConnection, jwt_secret: &str) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(pool: DatabaseConnection, jwt_secret: &str, upload_config: UploadConfig, trash_config: TrashConfig, view_config: ViewConfig, reaction_config: ReactionConfig, site_config: SiteConfig, ranking_config: RankingConfig, post_lock_config: PostLockConfig) -> Self {
         let jwt_config = JwtConfig::new(jwt_secret);
         let hashing = Hashing::new();
 
-        let di_container = DependenciesInject::new(pool, hashing, jwt_config.clone());
+        let di_container = DependenciesInject::new(pool, hashing, jwt_config.clone(), upload_config, trash_config, view_config, reaction_config, site_config, ranking_config, post_lock_config);
         
         Self { di_container, jwt_config }
     }
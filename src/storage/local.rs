@@ -0,0 +1,67 @@
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use tokio::fs;
+use uuid::Uuid;
+
+use crate::{abstract_trait::StorageTrait, config::UploadConfig, utils::AppError};
+
+pub struct LocalStorage {
+    root: PathBuf,
+    public_path: String,
+}
+
+impl LocalStorage {
+    pub fn new(config: &UploadConfig) -> Self {
+        Self {
+            root: PathBuf::from(&config.dir),
+            public_path: config.public_path.clone(),
+        }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.root.join(key)
+    }
+}
+
+#[async_trait]
+impl StorageTrait for LocalStorage {
+    async fn put(&self, key: &str, bytes: &[u8]) -> Result<(), AppError> {
+        fs::create_dir_all(&self.root)
+            .await
+            .map_err(|e| AppError::StorageError(e.to_string()))?;
+
+        // Write to a temporary file first so a reader never sees a partial upload; the random
+        // suffix keeps concurrent writers of the same key apart
+        let tmp_path = self.path(&format!("{}.{}.tmp", key, Uuid::new_v4()));
+        if let Err(e) = fs::write(&tmp_path, bytes).await {
+            let _ = fs::remove_file(&tmp_path).await;
+            return Err(AppError::StorageError(e.to_string()));
+        }
+
+        if let Err(e) = fs::rename(&tmp_path, self.path(key)).await {
+            let _ = fs::remove_file(&tmp_path).await;
+            return Err(AppError::StorageError(e.to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool, AppError> {
+        fs::try_exists(self.path(key))
+            .await
+            .map_err(|e| AppError::StorageError(e.to_string()))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), AppError> {
+        match fs::remove_file(self.path(key)).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(AppError::StorageError(e.to_string())),
+        }
+    }
+
+    fn url(&self, key: &str) -> String {
+        format!("{}/{}", self.public_path, key)
+    }
+}
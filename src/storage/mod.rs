@@ -0,0 +1,3 @@
+mod local;
+
+pub use self::local::LocalStorage;
\ No newline at end of file
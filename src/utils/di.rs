@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use sea_orm::DatabaseConnection;
 
-use crate::{abstract_trait::{DynAuthService, DynCategoryRepository, DynCategoryService, DynCommentRepository, DynCommentService, DynPostsRepository, DynPostsService, DynUserRepository, DynUserService}, config::{Hashing, JwtConfig}, repository::{CategoryRepository, CommentRepository, PostRepository, UserRepository}, service::{AuthService, CategoryService, CommentService, PostService, UserService}};
+use crate::{abstract_trait::{DynAuthService, DynMediaRepository, DynMediaService, DynStorage, DynCategoryRepository, DynCategoryService, DynCommentRepository, DynCommentService, DynPostsRepository, DynPostsService, DynSearchRepository, DynSearchService, DynTagRepository, DynTagService, DynTrashRepository, DynTrashService, DynUserRepository, DynUserService, DynViewRepository, DynViewService, DynReactionRepository, DynReactionService, DynBookmarkRepository, DynBookmarkService, DynSeriesRepository, DynSeriesService, DynFeedService, DynSitemapRepository, DynSitemapService, DynRankingRepository, DynRankingService, DynPostLockRepository, DynPostLockService}, config::{Hashing, JwtConfig, PostLockConfig, RankingConfig, ReactionConfig, SiteConfig, TrashConfig, UploadConfig, ViewConfig}, repository::{CategoryRepository, CommentRepository, MediaRepository, PostRepository, SearchRepository, TagRepository, TrashRepository, UserRepository, ViewRepository, ReactionRepository, BookmarkRepository, SeriesRepository, SitemapRepository, RankingRepository, PostLockRepository}, service::{PostAccess, AuthService, CategoryService, CommentService, MediaService, PostService, SearchService, TagService, TrashService, UserService, ViewService, ReactionService, PostResponseBuilder, PostDetailBuilder, BookmarkService, SeriesService, FeedService, SitemapService, RankingService, PostLockService}, storage::LocalStorage};
 
 
 
@@ -13,33 +13,109 @@ pub struct DependenciesInject{
     pub comment_service: DynCommentService,
     pub user_service: DynUserService,
     pub auth_service: DynAuthService,
+    pub tag_service: DynTagService,
+    pub search_service: DynSearchService,
+    pub media_service: DynMediaService,
+    pub trash_service: DynTrashService,
+    pub view_service: DynViewService,
+    pub reaction_service: DynReactionService,
+    pub bookmark_service: DynBookmarkService,
+    pub series_service: DynSeriesService,
+    pub feed_service: DynFeedService,
+    pub sitemap_service: DynSitemapService,
+    pub ranking_service: DynRankingService,
+    pub post_lock_service: DynPostLockService,
 }
 
 impl DependenciesInject{
-    pub fn new(pool: DatabaseConnection, hashing: Hashing, jwt_config: JwtConfig) -> Self{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(pool: DatabaseConnection, hashing: Hashing, jwt_config: JwtConfig, upload_config: UploadConfig, trash_config: TrashConfig, view_config: ViewConfig, reaction_config: ReactionConfig, site_config: SiteConfig, ranking_config: RankingConfig, post_lock_config: PostLockConfig) -> Self{
         let category_repository =
             Arc::new(CategoryRepository::new(pool.clone())) as DynCategoryRepository;
 
+        let user_repository = Arc::new(UserRepository::new(pool.clone())) as DynUserRepository;
+
         let category_service =
-            Arc::new(CategoryService::new(category_repository)) as DynCategoryService;
+            Arc::new(CategoryService::new(category_repository.clone(), user_repository.clone())) as DynCategoryService;
 
         let post_repository = Arc::new(PostRepository::new(pool.clone())) as DynPostsRepository;
 
-        let post_service = Arc::new(PostService::new(post_repository.clone())) as DynPostsService;
+        let storage = Arc::new(LocalStorage::new(&upload_config)) as DynStorage;
+
+        let media_repository = Arc::new(MediaRepository::new(pool.clone())) as DynMediaRepository;
+
+        let media_service =
+            Arc::new(MediaService::new(media_repository.clone(), storage.clone(), upload_config)) as DynMediaService;
+
+        let reaction_repository = Arc::new(ReactionRepository::new(pool.clone())) as DynReactionRepository;
+
+        let bookmark_repository = Arc::new(BookmarkRepository::new(pool.clone())) as DynBookmarkRepository;
+
+        let post_access = PostAccess::new(user_repository.clone(), hashing.clone(), jwt_config.clone());
+
+        let series_repository = Arc::new(SeriesRepository::new(pool.clone())) as DynSeriesRepository;
+
+        let series_service = Arc::new(SeriesService::new(series_repository.clone(), post_access.clone())) as DynSeriesService;
+
+        let post_responses = PostResponseBuilder::new(post_repository.clone(), reaction_repository.clone(), bookmark_repository.clone(), series_repository, post_access.clone());
+
+        let bookmark_service =
+            Arc::new(BookmarkService::new(bookmark_repository, post_repository.clone(), post_responses.clone(), post_access.clone())) as DynBookmarkService;
 
         let comment_repository =
             Arc::new(CommentRepository::new(pool.clone())) as DynCommentRepository;
-        let comment_service =
-            Arc::new(CommentService::new(comment_repository)) as DynCommentService;
 
+        let reaction_service =
+            Arc::new(ReactionService::new(reaction_repository.clone(), post_repository.clone(), comment_repository.clone(), post_access.clone(), reaction_config)) as DynReactionService;
 
-        let user_repository = Arc::new(UserRepository::new(pool.clone())) as DynUserRepository;
+        let post_details = PostDetailBuilder::new(user_repository.clone(), category_repository.clone(), comment_repository.clone(), reaction_repository.clone());
+
+        let post_lock_repository = Arc::new(PostLockRepository::new(pool.clone())) as DynPostLockRepository;
+
+        let post_lock_service =
+            Arc::new(PostLockService::new(post_lock_repository, post_repository.clone(), post_access.clone(), post_lock_config)) as DynPostLockService;
+
+        let post_service =
+            Arc::new(PostService::new(post_repository.clone(), category_repository.clone(), media_repository, storage, post_responses.clone(), post_details, post_access.clone())) as DynPostsService;
+
+        let tag_repository = Arc::new(TagRepository::new(pool.clone())) as DynTagRepository;
+
+        let tag_service =
+            Arc::new(TagService::new(tag_repository, post_repository.clone(), post_responses.clone(), post_access.clone())) as DynTagService;
+
+        let search_repository =
+            Arc::new(SearchRepository::new(pool.clone())) as DynSearchRepository;
+
+        let search_service = Arc::new(SearchService::new(search_repository, post_access.clone())) as DynSearchService;
+
+        let trash_repository = Arc::new(TrashRepository::new(pool.clone())) as DynTrashRepository;
+
+        let trash_service = Arc::new(TrashService::new(trash_repository, trash_config)) as DynTrashService;
+
+        let view_repository = Arc::new(ViewRepository::new(pool.clone())) as DynViewRepository;
+
+        let view_service = Arc::new(ViewService::new(view_repository, post_repository.clone(), post_access.clone(), view_config)) as DynViewService;
+
+        let comment_service =
+            Arc::new(CommentService::new(comment_repository, reaction_repository, post_repository.clone(), post_access.clone())) as DynCommentService;
 
         let user_service = Arc::new(UserService::new(user_repository.clone())) as DynUserService;
 
         let auth_service = Arc::new(AuthService::new(user_repository.clone(), hashing, jwt_config));
 
+        let ranking_repository = Arc::new(RankingRepository::new(pool.clone())) as DynRankingRepository;
+
+        let ranking_service =
+            Arc::new(RankingService::new(ranking_repository, post_repository.clone(), post_responses, post_access, ranking_config)) as DynRankingService;
+
+        let feed_service =
+            Arc::new(FeedService::new(post_repository, category_repository, user_repository, site_config.clone())) as DynFeedService;
+
+        let sitemap_repository = Arc::new(SitemapRepository::new(pool.clone())) as DynSitemapRepository;
+
+        let sitemap_service = Arc::new(SitemapService::new(sitemap_repository, site_config)) as DynSitemapService;
+
 
-        Self { category_service, post_service, comment_service, user_service, auth_service }
+        Self { category_service, post_service, comment_service, user_service, auth_service, tag_service, search_service, media_service, trash_service, view_service, reaction_service, bookmark_service, series_service, feed_service, sitemap_service, ranking_service, post_lock_service }
     }
 }
\ No newline at end of file
@@ -30,11 +30,38 @@ pub enum AppError {
     #[error("Bcrypt error: {0}")]
     BcryptError(String),
 
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
     #[error("Invalid credentials")]
     InvalidCredentials,
 
     #[error("Email already exists")]
     EmailAlreadyExists,
+
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+
+    #[error("Payload too large: {0}")]
+    PayloadTooLarge(String),
+
+    #[error("Unsupported media type: {0}")]
+    UnsupportedMediaType(String),
+
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    #[error("Precondition failed: {0}")]
+    PreconditionFailed(String),
+
+    #[error("Locked: {0}")]
+    Locked(String),
+
+    #[error("Storage error: {0}")]
+    StorageError(String),
 }
 
 impl Serialize for AppError {
@@ -0,0 +1,87 @@
+use std::time::SystemTime;
+
+use actix_web::http::header::{self, HeaderMap, HttpDate};
+use chrono::{DateTime, Utc};
+
+use crate::utils::AppError;
+
+/// Formats a row version as a strong entity tag.
+pub fn etag(version: i32) -> String {
+    format!("\"{}\"", version)
+}
+
+/// Parses an `If-Match` header value.
+///
+/// Returns `Ok(None)` for `*`, which matches any version. `If-Match` uses strong
+/// comparison, so weak tags never match and fail the precondition.
+pub fn parse_if_match(value: &str) -> Result<Option<i32>, AppError> {
+    let value = value.trim();
+
+    if value == "*" {
+        return Ok(None);
+    }
+
+    if value.starts_with("W/") {
+        return Err(AppError::PreconditionFailed(format!("Weak entity tag {} cannot be used with If-Match", value)));
+    }
+
+    value
+        .trim_matches('"')
+        .parse::<i32>()
+        .map(Some)
+        .map_err(|_| AppError::PreconditionFailed(format!("Unknown entity tag {}", value)))
+}
+
+/// Whether a conditional GET can be answered with `304 Not Modified`.
+///
+/// `If-None-Match` takes precedence over `If-Modified-Since`, as in RFC 9110.
+pub fn is_not_modified(headers: &HeaderMap, etag: &str, last_modified: Option<DateTime<Utc>>) -> bool {
+    if let Some(value) = headers.get(header::IF_NONE_MATCH).and_then(|value| value.to_str().ok()) {
+        return value
+            .split(',')
+            .map(|tag| tag.trim().trim_start_matches("W/"))
+            .any(|tag| tag == "*" || tag == etag.trim_start_matches("W/"));
+    }
+
+    let since = headers
+        .get(header::IF_MODIFIED_SINCE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<HttpDate>().ok());
+
+    match (since, last_modified) {
+        // HTTP dates have second precision.
+        (Some(since), Some(last_modified)) => {
+            let since: SystemTime = since.into();
+            let last_modified: SystemTime = DateTime::<Utc>::from_timestamp(last_modified.timestamp(), 0)
+                .unwrap_or(last_modified)
+                .into();
+            last_modified <= since
+        }
+        _ => false,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_strong_tags() {
+        assert!(matches!(parse_if_match(r#""3""#), Ok(Some(3))));
+        assert!(matches!(parse_if_match(r#" "12" "#), Ok(Some(12))));
+        assert_eq!(etag(7), r#""7""#);
+    }
+
+    #[test]
+    fn star_matches_any_version() {
+        assert!(matches!(parse_if_match("*"), Ok(None)));
+    }
+
+    #[test]
+    fn rejects_weak_and_unknown_tags() {
+        assert!(matches!(parse_if_match(r#"W/"3""#), Err(AppError::PreconditionFailed(_))));
+        assert!(matches!(parse_if_match(r#""abc""#), Err(AppError::PreconditionFailed(_))));
+        assert!(matches!(parse_if_match(""), Err(AppError::PreconditionFailed(_))));
+    }
+}
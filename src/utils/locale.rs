@@ -0,0 +1,71 @@
+use super::AppError;
+
+/// Locale of posts created without one.
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// Normalizes a BCP 47 language tag, e.g. `pt-br` -> `pt-BR` and `zh-hant` -> `zh-Hant`.
+pub fn normalize_locale(locale: &str) -> Result<String, AppError> {
+    let invalid = || AppError::ValidationError(format!("Invalid locale {}", locale));
+
+    let mut subtags = locale.trim().split(['-', '_']);
+    let language = subtags.next().unwrap_or_default();
+
+    if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err(invalid());
+    }
+
+    let mut normalized = language.to_ascii_lowercase();
+
+    for subtag in subtags {
+        if subtag.is_empty() || subtag.len() > 8 || !subtag.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(invalid());
+        }
+
+        normalized.push('-');
+        match subtag.len() {
+            2 if subtag.chars().all(|c| c.is_ascii_alphabetic()) => normalized.push_str(&subtag.to_ascii_uppercase()),
+            4 if subtag.chars().all(|c| c.is_ascii_alphabetic()) => {
+                normalized.push_str(&subtag[..1].to_ascii_uppercase());
+                normalized.push_str(&subtag[1..].to_ascii_lowercase());
+            }
+            _ => normalized.push_str(&subtag.to_ascii_lowercase()),
+        }
+    }
+
+    Ok(normalized)
+}
+
+/// Locales of an `Accept-Language` header, most preferred first. Wildcards,
+/// `q=0` entries and malformed tags are skipped.
+pub fn accepted_locales(header: &str) -> Vec<String> {
+    let mut weighted: Vec<(String, f32)> = header
+        .split(',')
+        .filter_map(|entry| {
+            let mut parts = entry.split(';').map(str::trim);
+            let locale = normalize_locale(parts.next()?).ok()?;
+            let quality = parts
+                .find_map(|param| param.strip_prefix("q="))
+                .map_or(Some(1.0), |q| q.parse::<f32>().ok())?;
+
+            (quality > 0.0).then_some((locale, quality))
+        })
+        .collect();
+
+    // Stable, so equally weighted locales keep the order of the header.
+    weighted.sort_by(|a, b| b.1.total_cmp(&a.1));
+    weighted.into_iter().map(|(locale, _)| locale).collect()
+}
+
+/// Picks the first accepted locale that is available, falling back to a locale
+/// with the same language, e.g. `fr-CA` matches `fr`.
+pub fn negotiate_locale(accepted: &[String], available: &[String]) -> Option<String> {
+    let language = |locale: &str| locale.split('-').next().unwrap_or_default().to_string();
+
+    accepted.iter().find_map(|wanted| {
+        available
+            .iter()
+            .find(|locale| *locale == wanted)
+            .or_else(|| available.iter().find(|locale| language(locale) == language(wanted)))
+            .cloned()
+    })
+}
@@ -0,0 +1,185 @@
+use std::sync::LazyLock;
+
+use ammonia::Builder;
+use pulldown_cmark::{html, Options, Parser};
+
+use crate::entities::sea_orm_active_enums::BodyFormat;
+
+static SANITIZER: LazyLock<Builder<'static>> = LazyLock::new(|| {
+    let mut builder = Builder::default();
+
+    builder
+        .add_tag_attributes("code", &["class"])
+        .add_tag_attributes("div", &["id"])
+        .add_tag_attributes("th", &["style"])
+        .add_tag_attributes("td", &["style"])
+        .add_allowed_classes("sup", &["footnote-reference", "footnote-definition-label"])
+        .add_allowed_classes("div", &["footnote-definition"])
+        .attribute_filter(|element, attribute, value| match (element, attribute) {
+            // Only keep the `language-*` class used by syntax highlighters
+            ("code", "class") => value
+                .split_whitespace()
+                .find(|class| {
+                    class.strip_prefix("language-").is_some_and(|lang| {
+                        !lang.is_empty()
+                            && lang.chars().all(|c| c.is_ascii_alphanumeric() || "+#-_".contains(c))
+                    })
+                })
+                .map(|class| class.to_string().into()),
+            ("div", "id") => value
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+                .then(|| value.into()),
+            ("th" | "td", "style") => matches!(
+                value,
+                "text-align: left" | "text-align: center" | "text-align: right"
+            )
+            .then(|| value.into()),
+            _ => Some(value.into()),
+        });
+
+    builder
+});
+
+/// Renders a post body to HTML and sanitizes the result against the allowlist.
+pub fn render_body(body: &str, format: BodyFormat) -> String {
+    let html = match format {
+        BodyFormat::Markdown => {
+            let options = Options::ENABLE_TABLES
+                | Options::ENABLE_FOOTNOTES
+                | Options::ENABLE_STRIKETHROUGH;
+
+            let mut html = String::with_capacity(body.len() * 3 / 2);
+            html::push_html(&mut html, Parser::new_ext(body, options));
+            html
+        }
+        BodyFormat::Html => body.to_string(),
+    };
+
+    SANITIZER.clean(&html).to_string()
+}
+
+const EXCERPT_LENGTH: usize = 280;
+const WORDS_PER_MINUTE: usize = 200;
+
+/// Summary of a rendered body, stored with the post so lists need not load the body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadingStats {
+    pub excerpt: String,
+    pub word_count: i32,
+    pub reading_time_minutes: i32,
+}
+
+impl ReadingStats {
+    pub fn from_html(html: &str) -> Self {
+        let text = plain_text(html);
+        let words = text.split_whitespace().count();
+
+        ReadingStats {
+            excerpt: truncate_words(&text, EXCERPT_LENGTH),
+            word_count: words.try_into().unwrap_or(i32::MAX),
+            reading_time_minutes: words.div_ceil(WORDS_PER_MINUTE).try_into().unwrap_or(i32::MAX),
+        }
+    }
+}
+
+/// Text content of rendered HTML with whitespace collapsed.
+fn plain_text(html: &str) -> String {
+    const INLINE_TAGS: [&str; 14] = [
+        "a", "abbr", "b", "code", "del", "em", "i", "mark", "s", "small", "span", "strong", "sub", "sup",
+    ];
+
+    let mut text = String::with_capacity(html.len());
+    let mut tag: Option<String> = None;
+
+    for c in html.chars() {
+        match (&mut tag, c) {
+            (None, '<') => tag = Some(String::new()),
+            (Some(name), '>') => {
+                let name = name
+                    .trim_start_matches('/')
+                    .split(|c: char| c.is_whitespace() || c == '/')
+                    .next()
+                    .unwrap_or_default()
+                    .to_ascii_lowercase();
+                // Block tags separate words, e.g. `</p><p>`.
+                if !INLINE_TAGS.contains(&name.as_str()) {
+                    text.push(' ');
+                }
+                tag = None;
+            }
+            (Some(name), _) => name.push(c),
+            (None, _) => text.push(c),
+        }
+    }
+
+    let text = text
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Cuts `text` at a word boundary after at most `max_chars` characters.
+fn truncate_words(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+
+    let cut: String = text.chars().take(max_chars).collect();
+    let cut = match cut.rfind(' ') {
+        Some(index) if index > 0 => &cut[..index],
+        _ => &cut,
+    };
+
+    format!("{}…", cut.trim_end_matches(|c: char| c.is_ascii_punctuation()))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_markdown() {
+        let html = render_body("# Title\n\nSome **bold** text", BodyFormat::Markdown);
+
+        assert!(html.contains("<h1>Title</h1>"));
+        assert!(html.contains("<strong>bold</strong>"));
+    }
+
+    #[test]
+    fn strips_scripts_and_event_handlers() {
+        let html = render_body(r#"<p onclick="alert(1)">hi</p><script>alert(1)</script>"#, BodyFormat::Html);
+
+        assert_eq!(html, "<p>hi</p>");
+    }
+
+    #[test]
+    fn strips_javascript_links() {
+        let html = render_body("[click](javascript:alert(1))", BodyFormat::Markdown);
+
+        assert!(!html.contains("javascript:"));
+    }
+
+    #[test]
+    fn keeps_only_language_classes_on_code() {
+        let html = render_body("```rust\nfn main() {}\n```", BodyFormat::Markdown);
+        assert!(html.contains(r#"<code class="language-rust">"#));
+
+        let html = render_body(r#"<code class="evil language-">x</code>"#, BodyFormat::Html);
+        assert_eq!(html, "<code>x</code>");
+    }
+
+    #[test]
+    fn keeps_table_alignment_only() {
+        let html = render_body("| a |\n|:-:|\n| b |", BodyFormat::Markdown);
+        assert!(html.contains(r#"style="text-align: center""#));
+
+        let html = render_body(r#"<table><tr><td style="color: red">x</td></tr></table>"#, BodyFormat::Html);
+        assert!(!html.contains("style"));
+    }
+}
@@ -0,0 +1,43 @@
+use serde_json::Value;
+
+use super::AppError;
+
+/// Checks that `schema` is a valid JSON Schema for category metadata.
+pub fn validate_metadata_schema(schema: &Value) -> Result<(), AppError> {
+    if !schema.is_object() {
+        return Err(AppError::ValidationError("metadata schema must be a JSON object".to_string()));
+    }
+
+    jsonschema::validator_for(schema)
+        .map(|_| ())
+        .map_err(|e| AppError::ValidationError(format!("Invalid metadata schema: {}", e)))
+}
+
+/// Checks post metadata against the schema of its category, listing every violation.
+pub fn validate_metadata(schema: Option<&Value>, metadata: &Value) -> Result<(), AppError> {
+    if !metadata.is_object() {
+        return Err(AppError::ValidationError("metadata must be a JSON object".to_string()));
+    }
+
+    let schema = match schema {
+        Some(schema) => schema,
+        None => return Ok(()),
+    };
+
+    let validator = jsonschema::validator_for(schema)
+        .map_err(|e| AppError::ValidationError(format!("Invalid metadata schema: {}", e)))?;
+
+    let errors: Vec<String> = validator
+        .iter_errors(metadata)
+        .map(|error| match error.instance_path.to_string() {
+            path if path.is_empty() => error.to_string(),
+            path => format!("{} at {}", error, path),
+        })
+        .collect();
+
+    if !errors.is_empty() {
+        return Err(AppError::ValidationError(format!("Invalid metadata: {}", errors.join("; "))));
+    }
+
+    Ok(())
+}
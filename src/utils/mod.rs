@@ -2,8 +2,18 @@ mod errors;
 mod di;
 mod log;
 mod slug;
+mod image;
+mod markdown;
+mod etag;
+mod metadata;
+mod locale;
 
 pub use self::errors::AppError;
 pub use self::di::DependenciesInject;
 pub use self::log::tracing;
-pub use self::slug::generate_slug;
\ No newline at end of file
+pub use self::slug::generate_slug;
+pub use self::image::sniff_image_type;
+pub use self::markdown::{render_body, ReadingStats};
+pub use self::etag::{etag, is_not_modified, parse_if_match};
+pub use self::metadata::{validate_metadata, validate_metadata_schema};
+pub use self::locale::{accepted_locales, negotiate_locale, normalize_locale, DEFAULT_LOCALE};
\ No newline at end of file